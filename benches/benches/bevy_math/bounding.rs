@@ -0,0 +1,100 @@
+use benches::bench;
+use bevy_math::{
+    bounding::{Aabb2d, BoundingCircle, Interval1d, RayCast2d},
+    ops,
+    prelude::*,
+};
+use core::hint::black_box;
+use criterion::{criterion_group, BenchmarkId, Criterion};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+criterion_group!(benches, ray_cast_2d_circle, ray_cast_2d_aabb);
+
+fn ray_cast_2d_circle(c: &mut Criterion) {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    // Circles scattered in front of the ray, mostly beyond its max distance
+    let circles: Vec<BoundingCircle> = (0..1000)
+        .map(|_| {
+            let center = Vec2::new(rng.gen_range(0.0..100.0), rng.gen_range(-2.0..2.0));
+            BoundingCircle::new(center, rng.gen_range(0.5..2.0))
+        })
+        .collect();
+
+    let mut group = c.benchmark_group(bench!("ray_cast_2d_circle"));
+    for max in [10.0, 100.0] {
+        let ray_cast = RayCast2d::new(Vec2::ZERO, Dir2::X, max);
+        // Taking a square root for every circle that the ray line hits
+        group.bench_with_input(BenchmarkId::new("sqrt", max), &ray_cast, |b, ray_cast| {
+            b.iter(|| {
+                for circle in &circles {
+                    black_box(circle_intersection_at_sqrt(ray_cast, black_box(circle)));
+                }
+            });
+        });
+        // Rejecting circles beyond the max distance before taking the square root
+        group.bench_with_input(
+            BenchmarkId::new("early_reject", max),
+            &ray_cast,
+            |b, ray_cast| {
+                b.iter(|| {
+                    for circle in &circles {
+                        black_box(ray_cast.circle_intersection_at(black_box(circle)));
+                    }
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The circle test of [`RayCast2d::circle_intersection_at`] for solid ray casts,
+/// without the early reject of circles beyond the max distance.
+fn circle_intersection_at_sqrt(ray_cast: &RayCast2d, circle: &BoundingCircle) -> Option<f32> {
+    let direction = *ray_cast.ray.direction;
+    let offset = ray_cast.ray.origin - circle.center;
+    let projected = offset.dot(direction);
+    let closest_point = offset - projected * direction;
+    let distance_squared = circle.radius() * circle.radius() - closest_point.length_squared();
+    if distance_squared < 0. || ops::copysign(projected * projected, -projected) < -distance_squared
+    {
+        return None;
+    }
+
+    let half_chord = ops::sqrt(distance_squared);
+    let inside = Interval1d::new(-projected - half_chord, -projected + half_chord);
+    let clamped = inside.clamp_to(0., ray_cast.max);
+    (!clamped.is_empty()).then_some(clamped.min)
+}
+
+fn ray_cast_2d_aabb(c: &mut Criterion) {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    // AABBs scattered in front of the ray, about half of which are hit
+    let aabbs: Vec<Aabb2d> = (0..1000)
+        .map(|_| {
+            let center = Vec2::new(rng.gen_range(0.0..100.0), rng.gen_range(-4.0..4.0));
+            Aabb2d::new(center, Vec2::splat(rng.gen_range(0.5..2.0)))
+        })
+        .collect();
+    let ray = Ray2d::new(Vec2::ZERO, Dir2::from_xy(1.0, 0.01).unwrap());
+
+    let mut group = c.benchmark_group(bench!("ray_cast_2d_aabb"));
+    // Reusing the ray cast, with its inverse direction computed once
+    group.bench_function("cached", |b| {
+        let ray_cast = RayCast2d::from_ray(ray, 100.0);
+        b.iter(|| {
+            for aabb in &aabbs {
+                black_box(ray_cast.aabb_intersection_at(black_box(aabb)));
+            }
+        });
+    });
+    // Computing the inverse direction for every cast
+    group.bench_function("one_shot", |b| {
+        b.iter(|| {
+            for aabb in &aabbs {
+                black_box(black_box(aabb).cast_ray(black_box(ray), 100.0, true));
+            }
+        });
+    });
+    group.finish();
+}
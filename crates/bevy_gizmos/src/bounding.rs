@@ -0,0 +1,295 @@
+//! Additional [`GizmoBuffer`] Functions -- Bounding volumes
+//!
+//! Includes the implementation of [`GizmoBuffer::aabb_2d`], [`GizmoBuffer::obb_2d`], [`GizmoBuffer::bounding_circle_2d`],
+//! [`GizmoBuffer::aabb_3d`], [`GizmoBuffer::bounding_sphere`], [`GizmoBuffer::ray_cast_2d`],
+//! [`GizmoBuffer::ray_cast_3d`] and [`GizmoBuffer::field_of_view_2d`], and assorted support items.
+
+use crate::{
+    circles::{Ellipse2dBuilder, SphereBuilder},
+    gizmos::GizmoBuffer,
+    prelude::{GizmoConfigGroup, GizmoPrimitive2d},
+};
+use bevy_color::Color;
+use bevy_math::{
+    bounding::{
+        Aabb2d, Aabb3d, BoundingCircle, BoundingSphere, BoundingVolume, FieldOfView2d, Obb2d,
+        RayCast2d, RayCast3d,
+    },
+    Vec3, Vec3Swizzles,
+};
+use bevy_transform::components::Transform;
+
+impl<Config, Clear> GizmoBuffer<Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draw a wireframe rectangle in 2D outlining the given [`Aabb2d`].
+    ///
+    /// This should be called for each frame the AABB needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::{bounding::Aabb2d, prelude::*};
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.aabb_2d(&Aabb2d::new(Vec2::ZERO, Vec2::ONE), GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn aabb_2d(&mut self, aabb: &Aabb2d, color: impl Into<Color>) {
+        self.rect_2d(aabb.center(), aabb.half_size() * 2., color);
+    }
+
+    /// Draw a wireframe rectangle in 2D outlining the given [`Obb2d`], following its rotation.
+    ///
+    /// This should be called for each frame the OBB needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::{bounding::Obb2d, prelude::*};
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.obb_2d(&Obb2d::new(Vec2::ZERO, Vec2::ONE, Rot2::degrees(30.)), GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn obb_2d(&mut self, obb: &Obb2d, color: impl Into<Color>) {
+        if !self.enabled {
+            return;
+        }
+        let [bl, br, tr, tl] = obb.corners();
+        self.linestrip_2d([tl, tr, br, bl, tl], color);
+    }
+
+    /// Draw a circle in 2D outlining the given [`BoundingCircle`].
+    ///
+    /// This should be called for each frame the bounding circle needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::{bounding::BoundingCircle, prelude::*};
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.bounding_circle_2d(&BoundingCircle::new(Vec2::ZERO, 1.), GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn bounding_circle_2d(
+        &mut self,
+        circle: &BoundingCircle,
+        color: impl Into<Color>,
+    ) -> Ellipse2dBuilder<'_, Config, Clear> {
+        self.circle_2d(circle.center, circle.radius(), color)
+    }
+
+    /// Draw the outline of the given [`FieldOfView2d`], as two lines from its origin and the arc between them.
+    ///
+    /// This should be called for each frame the field of view needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::{bounding::FieldOfView2d, prelude::*};
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     let view = FieldOfView2d::new(Vec2::ZERO, Dir2::X, 0.5, 100.);
+    ///     gizmos.field_of_view_2d(&view, GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn field_of_view_2d(&mut self, field_of_view: &FieldOfView2d, color: impl Into<Color>) {
+        self.primitive_2d(&field_of_view.sector(), field_of_view.isometry(), color);
+    }
+
+    /// Draw a wireframe box in 3D outlining the given [`Aabb3d`].
+    ///
+    /// This should be called for each frame the AABB needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::{bounding::Aabb3d, prelude::*};
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.aabb_3d(&Aabb3d::new(Vec3::ZERO, Vec3::ONE), GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn aabb_3d(&mut self, aabb: &Aabb3d, color: impl Into<Color>) {
+        let transform = Transform::from_translation(Vec3::from(aabb.center()))
+            .with_scale(Vec3::from(aabb.half_size() * 2.));
+        self.cuboid(transform, color);
+    }
+
+    /// Draw a wireframe sphere in 3D outlining the given [`BoundingSphere`].
+    ///
+    /// This should be called for each frame the bounding sphere needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::{bounding::BoundingSphere, prelude::*};
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.bounding_sphere(&BoundingSphere::new(Vec3::ZERO, 1.), GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn bounding_sphere(
+        &mut self,
+        sphere: &BoundingSphere,
+        color: impl Into<Color>,
+    ) -> SphereBuilder<'_, Config, Clear> {
+        self.sphere(sphere.center, sphere.radius(), color)
+    }
+
+    /// Draw a [`RayCast2d`] as a line from its origin to its max distance, with a circle marking the origin.
+    ///
+    /// The max distance of the ray cast must be finite.
+    ///
+    /// This should be called for each frame the ray cast needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::{bounding::RayCast2d, prelude::*};
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     let ray_cast = RayCast2d::new(Vec2::ZERO, Dir2::X, 100.);
+    ///     gizmos.ray_cast_2d(&ray_cast, GREEN);
+    ///
+    ///     // Add an arrowhead to show the direction of the ray
+    ///     gizmos.ray_cast_2d(&ray_cast, GREEN).with_arrowhead();
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn ray_cast_2d(
+        &mut self,
+        ray_cast: &RayCast2d,
+        color: impl Into<Color>,
+    ) -> RayCastBuilder<'_, Config, Clear> {
+        let origin = ray_cast.ray.origin;
+        let end = ray_cast.ray.get_point(ray_cast.max);
+        RayCastBuilder {
+            gizmos: self,
+            origin: origin.extend(0.),
+            end: end.extend(0.),
+            color: color.into(),
+            origin_radius: ray_cast.max / 40.,
+            arrowhead: false,
+            is_2d: true,
+        }
+    }
+
+    /// Draw a [`RayCast3d`] as a line from its origin to its max distance, with a sphere marking the origin.
+    ///
+    /// The max distance of the ray cast must be finite.
+    ///
+    /// This should be called for each frame the ray cast needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::{bounding::RayCast3d, prelude::*};
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     let ray_cast = RayCast3d::new(Vec3::ZERO, Dir3::X, 100.);
+    ///     gizmos.ray_cast_3d(&ray_cast, GREEN).with_arrowhead();
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn ray_cast_3d(
+        &mut self,
+        ray_cast: &RayCast3d,
+        color: impl Into<Color>,
+    ) -> RayCastBuilder<'_, Config, Clear> {
+        let origin = ray_cast.origin;
+        let end = ray_cast.origin + *ray_cast.direction * ray_cast.max;
+        RayCastBuilder {
+            gizmos: self,
+            origin: origin.into(),
+            end: end.into(),
+            color: color.into(),
+            origin_radius: ray_cast.max / 40.,
+            arrowhead: false,
+            is_2d: false,
+        }
+    }
+}
+
+/// A builder returned by [`GizmoBuffer::ray_cast_2d`] and [`GizmoBuffer::ray_cast_3d`]
+pub struct RayCastBuilder<'a, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut GizmoBuffer<Config, Clear>,
+    origin: Vec3,
+    end: Vec3,
+    color: Color,
+    origin_radius: f32,
+    arrowhead: bool,
+    is_2d: bool,
+}
+
+impl<Config, Clear> RayCastBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Change the radius of the marker at the origin of the ray cast to be `radius`.
+    /// The default radius is [max distance of the ray cast]/40.
+    pub fn with_origin_radius(mut self, radius: f32) -> Self {
+        self.origin_radius = radius;
+        self
+    }
+
+    /// Adds an arrowhead at the max distance of the ray cast, to show its direction.
+    pub fn with_arrowhead(mut self) -> Self {
+        self.arrowhead = true;
+        self
+    }
+}
+
+impl<Config, Clear> Drop for RayCastBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draws the ray cast, by drawing lines with the stored [`GizmoBuffer`]
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+        if self.is_2d {
+            let (origin, end) = (self.origin.xy(), self.end.xy());
+            if self.arrowhead {
+                self.gizmos.arrow_2d(origin, end, self.color);
+            } else {
+                self.gizmos.line_2d(origin, end, self.color);
+            }
+            self.gizmos
+                .circle_2d(origin, self.origin_radius, self.color);
+        } else {
+            if self.arrowhead {
+                self.gizmos.arrow(self.origin, self.end, self.color);
+            } else {
+                self.gizmos.line(self.origin, self.end, self.color);
+            }
+            self.gizmos
+                .sphere(self.origin, self.origin_radius, self.color);
+        }
+    }
+}
@@ -41,6 +41,7 @@ pub type GizmoRenderSystem = GizmoRenderSystems;
 pub mod aabb;
 pub mod arcs;
 pub mod arrows;
+pub mod bounding;
 pub mod circles;
 pub mod config;
 pub mod cross;
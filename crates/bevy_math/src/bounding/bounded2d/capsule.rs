@@ -0,0 +1,479 @@
+use super::{Aabb2d, BoundingCircle, ContainsPoint2d};
+use crate::{
+    bounding::{BoundingVolume, IntersectsVolume, Interval1d},
+    ops,
+    primitives::{Capsule2d, Segment2d},
+    FloatPow, Isometry2d, Rot2, Vec2,
+};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Checks if the `segment` passes through the `aabb`.
+pub(super) fn segment_intersects_aabb(segment: &Segment2d, aabb: &Aabb2d) -> bool {
+    let start = segment.point1();
+    let direction = segment.scaled_direction();
+    let mut inside = Interval1d::new(0.0, 1.0);
+
+    for axis in 0..2 {
+        if direction[axis] == 0.0 {
+            // The segment is parallel to the slab, so it needs to start within it
+            if start[axis] < aabb.min[axis] || start[axis] > aabb.max[axis] {
+                return false;
+            }
+        } else {
+            let t1 = (aabb.min[axis] - start[axis]) / direction[axis];
+            let t2 = (aabb.max[axis] - start[axis]) / direction[axis];
+            inside = inside.intersect(Interval1d::new(t1.min(t2), t1.max(t2)));
+        }
+    }
+
+    !inside.is_empty()
+}
+
+/// Computes the squared distance between the closest points of a segment and an AABB.
+fn segment_aabb_distance_squared(segment: &Segment2d, aabb: &Aabb2d) -> f32 {
+    if segment_intersects_aabb(segment, aabb) {
+        return 0.0;
+    }
+
+    // When the segment doesn't pass through the AABB, the closest points
+    // always include an endpoint of the segment or a corner of the AABB.
+    let endpoints = segment
+        .vertices
+        .map(|point| point.distance_squared(aabb.closest_point(point)));
+    let corners = [
+        aabb.min,
+        Vec2::new(aabb.max.x, aabb.min.y),
+        aabb.max,
+        Vec2::new(aabb.min.x, aabb.max.y),
+    ]
+    .map(|corner| corner.distance_squared(segment.closest_point(corner)));
+
+    endpoints
+        .into_iter()
+        .chain(corners)
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// A bounding capsule, the set of points within a given radius of a line segment
+///
+/// This is a much tighter bound than an [`Aabb2d`] or a [`BoundingCircle`]
+/// for elongated shapes like a [`Capsule2d`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct BoundingCapsule2d {
+    /// The line segment at the core of the capsule
+    pub segment: Segment2d,
+    /// The radius of the capsule
+    pub radius: f32,
+}
+
+impl BoundingCapsule2d {
+    /// Constructs a bounding capsule from the endpoints of its line segment and its radius.
+    #[inline(always)]
+    pub fn new(start: Vec2, end: Vec2, radius: f32) -> Self {
+        debug_assert!(radius >= 0.);
+        Self {
+            segment: Segment2d::new(start, end),
+            radius,
+        }
+    }
+
+    /// Computes the [`BoundingCapsule2d`] that exactly matches the given [`Capsule2d`],
+    /// transformed by the rotation and translation of the given isometry.
+    #[inline(always)]
+    pub fn from_capsule(capsule: Capsule2d, isometry: impl Into<Isometry2d>) -> Self {
+        let isometry = isometry.into();
+        let half_length = Vec2::new(0.0, capsule.half_length);
+        Self::new(
+            isometry.transform_point(-half_length),
+            isometry.transform_point(half_length),
+            capsule.radius,
+        )
+    }
+
+    /// Computes the smallest [`Aabb2d`] containing this [`BoundingCapsule2d`].
+    #[inline(always)]
+    pub fn aabb_2d(&self) -> Aabb2d {
+        let [start, end] = self.segment.vertices;
+        Aabb2d {
+            min: start.min(end) - Vec2::splat(self.radius),
+            max: start.max(end) + Vec2::splat(self.radius),
+        }
+    }
+
+    /// Computes the smallest [`BoundingCircle`] containing this [`BoundingCapsule2d`].
+    #[inline(always)]
+    pub fn bounding_circle(&self) -> BoundingCircle {
+        BoundingCircle::new(
+            self.segment.center(),
+            self.segment.length() / 2.0 + self.radius,
+        )
+    }
+
+    /// Finds the point on the bounding capsule that is closest to the given `point`.
+    ///
+    /// If the point is outside the capsule, the returned point will be on the perimeter of the capsule.
+    /// Otherwise, it will be inside or on the perimeter of the capsule and returned as is.
+    #[inline(always)]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let on_segment = self.segment.closest_point(point);
+        let offset = point - on_segment;
+        let distance_squared = offset.length_squared();
+
+        if distance_squared <= self.radius.squared() {
+            point
+        } else {
+            on_segment + self.radius * (offset / ops::sqrt(distance_squared))
+        }
+    }
+}
+
+impl BoundingVolume for BoundingCapsule2d {
+    type Translation = Vec2;
+    type Rotation = Rot2;
+    type HalfSize = f32;
+
+    #[inline(always)]
+    fn center(&self) -> Self::Translation {
+        self.segment.center()
+    }
+
+    /// Returns the radius of the capsule, which is half its thickness.
+    #[inline(always)]
+    fn half_size(&self) -> Self::HalfSize {
+        self.radius
+    }
+
+    #[inline(always)]
+    fn visible_area(&self) -> f32 {
+        core::f32::consts::PI * self.radius.squared() + 2.0 * self.radius * self.segment.length()
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &Self) -> bool {
+        // The other capsule is contained if its segment is contained in this capsule shrunk by
+        // its radius. Because capsules are convex, it is enough to check the endpoints.
+        let allowed_distance = self.radius - other.radius;
+        allowed_distance >= 0.0
+            && other.segment.vertices.iter().all(|&point| {
+                point.distance_squared(self.segment.closest_point(point))
+                    <= allowed_distance.squared()
+            })
+    }
+
+    /// Computes a [`BoundingCapsule2d`] that contains both `self` and `other`.
+    ///
+    /// The capsule is not guaranteed to be the smallest possible.
+    #[inline(always)]
+    fn merge(&self, other: &Self) -> Self {
+        if self.contains(other) {
+            return *self;
+        }
+        if other.contains(self) {
+            return *other;
+        }
+
+        let [a, b] = self.segment.vertices;
+        let [c, d] = other.segment.vertices;
+        let points = [
+            (a, self.radius),
+            (b, self.radius),
+            (c, other.radius),
+            (d, other.radius),
+        ];
+
+        // Span the new segment between the two endpoints that are the farthest apart,
+        // accounting for their radius.
+        let mut extremes = (a, b);
+        let mut extent = f32::NEG_INFINITY;
+        for (i, &(p, rp)) in points.iter().enumerate() {
+            for &(q, rq) in &points[i + 1..] {
+                let candidate = p.distance(q) + rp + rq;
+                if candidate > extent {
+                    extent = candidate;
+                    extremes = (p, q);
+                }
+            }
+        }
+
+        let segment = Segment2d::new(extremes.0, extremes.1);
+        let radius = points.iter().fold(0.0_f32, |radius, &(point, r)| {
+            radius.max(point.distance(segment.closest_point(point)) + r)
+        });
+
+        Self { segment, radius }
+    }
+
+    #[inline(always)]
+    fn grow(&self, amount: impl Into<Self::HalfSize>) -> Self {
+        let amount = amount.into();
+        debug_assert!(amount >= 0.);
+        Self {
+            segment: self.segment,
+            radius: self.radius + amount,
+        }
+    }
+
+    #[inline(always)]
+    fn shrink(&self, amount: impl Into<Self::HalfSize>) -> Self {
+        let amount = amount.into();
+        debug_assert!(amount >= 0.);
+        debug_assert!(self.radius >= amount);
+        Self {
+            segment: self.segment,
+            radius: self.radius - amount,
+        }
+    }
+
+    /// Scales both the length and the radius of the capsule around its center.
+    #[inline(always)]
+    fn scale_around_center(&self, scale: impl Into<Self::HalfSize>) -> Self {
+        let scale = scale.into();
+        debug_assert!(scale >= 0.);
+        let center = self.center();
+        let [start, end] = self.segment.vertices;
+        Self::new(
+            center + (start - center) * scale,
+            center + (end - center) * scale,
+            self.radius * scale,
+        )
+    }
+
+    #[inline(always)]
+    fn translate_by(&mut self, translation: impl Into<Self::Translation>) {
+        self.segment = self.segment.translated(translation.into());
+    }
+
+    #[inline(always)]
+    fn rotate_by(&mut self, rotation: impl Into<Self::Rotation>) {
+        self.segment = self.segment.rotated(rotation.into());
+    }
+}
+
+impl ContainsPoint2d for BoundingCapsule2d {
+    #[inline(always)]
+    fn contains_point(&self, point: Vec2) -> bool {
+        point.distance_squared(self.segment.closest_point(point)) <= self.radius.squared()
+    }
+}
+
+impl IntersectsVolume<Self> for BoundingCapsule2d {
+    #[inline(always)]
+    fn intersects(&self, other: &Self) -> bool {
+        let (a, b) = self.segment.closest_points(&other.segment);
+        a.distance_squared(b) <= (self.radius + other.radius).squared()
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for BoundingCapsule2d {
+    #[inline(always)]
+    fn intersects(&self, circle: &BoundingCircle) -> bool {
+        let closest_point = self.segment.closest_point(circle.center);
+        let distance_squared = circle.center.distance_squared(closest_point);
+        distance_squared <= (self.radius + circle.radius()).squared()
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for BoundingCapsule2d {
+    #[inline(always)]
+    fn intersects(&self, aabb: &Aabb2d) -> bool {
+        segment_aabb_distance_squared(&self.segment, aabb) <= self.radius.squared()
+    }
+}
+
+impl IntersectsVolume<BoundingCapsule2d> for BoundingCircle {
+    #[inline(always)]
+    fn intersects(&self, capsule: &BoundingCapsule2d) -> bool {
+        capsule.intersects(self)
+    }
+}
+
+impl IntersectsVolume<BoundingCapsule2d> for Aabb2d {
+    #[inline(always)]
+    fn intersects(&self, capsule: &BoundingCapsule2d) -> bool {
+        capsule.intersects(self)
+    }
+}
+
+#[cfg(test)]
+mod bounding_capsule_tests {
+    use approx::assert_relative_eq;
+
+    use super::BoundingCapsule2d;
+    use crate::{
+        bounding::{Aabb2d, BoundingCircle, BoundingVolume, IntersectsVolume},
+        ops,
+        primitives::Capsule2d,
+        Isometry2d, Rot2, Vec2,
+    };
+
+    #[test]
+    fn from_capsule() {
+        let capsule = Capsule2d::new(0.5, 2.0);
+        let bounding = BoundingCapsule2d::from_capsule(
+            capsule,
+            Isometry2d::new(Vec2::new(1.0, 2.0), Rot2::FRAC_PI_2),
+        );
+        assert_relative_eq!(bounding.segment.point1(), Vec2::new(2.0, 2.0));
+        assert_relative_eq!(bounding.segment.point2(), Vec2::new(0.0, 2.0));
+        assert_eq!(bounding.radius, 0.5);
+    }
+
+    #[test]
+    fn area() {
+        let capsule = BoundingCapsule2d::new(Vec2::ZERO, Vec2::X * 2.0, 1.0);
+        assert!(ops::abs(capsule.visible_area() - (core::f32::consts::PI + 4.0)) < 0.001);
+    }
+
+    #[test]
+    fn contains() {
+        let a = BoundingCapsule2d::new(Vec2::NEG_X * 2.0, Vec2::X * 2.0, 1.0);
+        let b = BoundingCapsule2d::new(Vec2::new(-1.0, 0.25), Vec2::new(1.0, -0.25), 0.5);
+        assert!(a.contains(&b));
+        assert!(!b.contains(&a));
+        assert!(a.contains(&a));
+
+        let b = BoundingCapsule2d::new(Vec2::new(-1.0, 0.75), Vec2::new(1.0, 0.0), 0.5);
+        assert!(!a.contains(&b));
+    }
+
+    #[test]
+    fn merge() {
+        let a = BoundingCapsule2d::new(Vec2::ZERO, Vec2::X * 2.0, 1.0);
+        let b = BoundingCapsule2d::new(Vec2::new(3.0, 1.0), Vec2::new(5.0, 1.0), 0.5);
+        let merged = a.merge(&b);
+        assert!(merged.contains(&a));
+        assert!(merged.contains(&b));
+
+        // Merging with a contained capsule returns the containing capsule
+        let c = BoundingCapsule2d::new(Vec2::X, Vec2::X * 1.5, 0.25);
+        assert_eq!(a.merge(&c), a);
+        assert_eq!(c.merge(&a), a);
+    }
+
+    #[test]
+    fn grow_shrink() {
+        let a = BoundingCapsule2d::new(Vec2::ZERO, Vec2::X * 2.0, 1.0);
+        let padded = a.grow(0.5);
+        assert_eq!(padded.radius, 1.5);
+        assert!(padded.contains(&a));
+        let shrunk = a.shrink(0.5);
+        assert_eq!(shrunk.radius, 0.5);
+        assert!(a.contains(&shrunk));
+    }
+
+    #[test]
+    fn scale_around_center() {
+        let a = BoundingCapsule2d::new(Vec2::ZERO, Vec2::X * 2.0, 1.0);
+        let scaled = a.scale_around_center(2.0);
+        assert_eq!(scaled.segment.point1(), Vec2::NEG_X);
+        assert_eq!(scaled.segment.point2(), Vec2::X * 3.0);
+        assert_eq!(scaled.radius, 2.0);
+    }
+
+    #[test]
+    fn transform() {
+        let a = BoundingCapsule2d::new(Vec2::ZERO, Vec2::X * 2.0, 1.0);
+        let transformed = a.transformed_by(Vec2::new(1.0, 1.0), Rot2::FRAC_PI_2);
+        assert_relative_eq!(transformed.segment.point1(), Vec2::new(1.0, 1.0));
+        assert_relative_eq!(transformed.segment.point2(), Vec2::new(1.0, 3.0));
+        assert_eq!(transformed.radius, 1.0);
+    }
+
+    #[test]
+    fn bounds() {
+        let a = BoundingCapsule2d::new(Vec2::ZERO, Vec2::new(2.0, 1.0), 0.5);
+        let aabb = a.aabb_2d();
+        assert_eq!(aabb.min, Vec2::splat(-0.5));
+        assert_eq!(aabb.max, Vec2::new(2.5, 1.5));
+        let circle = a.bounding_circle();
+        assert_eq!(circle.center, Vec2::new(1.0, 0.5));
+        assert!(ops::abs(circle.radius() - (ops::hypot(2.0, 1.0) / 2.0 + 0.5)) < 0.001);
+    }
+
+    #[test]
+    fn closest_point() {
+        let capsule = BoundingCapsule2d::new(Vec2::ZERO, Vec2::X * 2.0, 1.0);
+        assert_eq!(
+            capsule.closest_point(Vec2::new(1.0, 5.0)),
+            Vec2::new(1.0, 1.0)
+        );
+        assert_eq!(capsule.closest_point(Vec2::X * 5.0), Vec2::X * 3.0);
+        assert_eq!(
+            capsule.closest_point(Vec2::new(1.5, 0.5)),
+            Vec2::new(1.5, 0.5)
+        );
+    }
+
+    #[test]
+    fn intersect_capsule() {
+        let capsule = BoundingCapsule2d::new(Vec2::ZERO, Vec2::X * 2.0, 0.5);
+        assert!(capsule.intersects(&capsule));
+        // Crossing segments
+        assert!(capsule.intersects(&BoundingCapsule2d::new(
+            Vec2::new(1.0, -1.0),
+            Vec2::new(1.0, 1.0),
+            0.1
+        )));
+        // Parallel segments within reach
+        assert!(capsule.intersects(&BoundingCapsule2d::new(
+            Vec2::new(1.0, 0.9),
+            Vec2::new(4.0, 0.9),
+            0.5
+        )));
+        // Parallel segments out of reach
+        assert!(!capsule.intersects(&BoundingCapsule2d::new(
+            Vec2::new(1.0, 1.1),
+            Vec2::new(4.0, 1.1),
+            0.5
+        )));
+        // End caps close to each other
+        assert!(capsule.intersects(&BoundingCapsule2d::new(
+            Vec2::new(2.5, 0.5),
+            Vec2::new(4.0, 2.0),
+            0.25
+        )));
+        assert!(!capsule.intersects(&BoundingCapsule2d::new(
+            Vec2::new(2.6, 0.6),
+            Vec2::new(4.0, 2.0),
+            0.25
+        )));
+    }
+
+    #[test]
+    fn intersect_bounding_circle() {
+        let capsule = BoundingCapsule2d::new(Vec2::ZERO, Vec2::X * 2.0, 0.5);
+        assert!(capsule.intersects(&BoundingCircle::new(Vec2::new(1.0, 1.0), 0.5)));
+        assert!(capsule.intersects(&BoundingCircle::new(Vec2::new(3.0, 0.0), 0.5)));
+        assert!(!capsule.intersects(&BoundingCircle::new(Vec2::new(1.0, 1.1), 0.5)));
+        assert!(!BoundingCircle::new(Vec2::new(2.8, 0.8), 0.5).intersects(&capsule));
+    }
+
+    #[test]
+    fn intersect_aabb() {
+        let capsule = BoundingCapsule2d::new(Vec2::new(-2.0, -2.0), Vec2::new(2.0, 2.0), 0.25);
+        // The segment passes through the box
+        assert!(capsule.intersects(&Aabb2d::new(Vec2::ZERO, Vec2::splat(0.5))));
+        // The box is close to the side of the capsule
+        assert!(capsule.intersects(&Aabb2d::new(Vec2::new(1.0, -0.25), Vec2::splat(0.5))));
+        assert!(!capsule.intersects(&Aabb2d::new(Vec2::new(1.5, -1.0), Vec2::splat(0.5))));
+        // The box is close to the end cap of the capsule
+        assert!(Aabb2d::new(Vec2::new(2.5, 2.5), Vec2::splat(0.35)).intersects(&capsule));
+        assert!(!Aabb2d::new(Vec2::new(2.6, 2.6), Vec2::splat(0.35)).intersects(&capsule));
+    }
+}
@@ -0,0 +1,293 @@
+use super::{Aabb2d, BoundingCircle, ContainsPoint2d, Obb2d};
+use crate::{
+    bounding::{BoundingVolume, IntersectsVolume},
+    Vec2,
+};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A single bounding volume that is part of a [`CompoundVolume2d`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum VolumePart2d {
+    /// An axis-aligned bounding box
+    Aabb(Aabb2d),
+    /// A bounding circle
+    Circle(BoundingCircle),
+    /// An oriented bounding box
+    Obb(Obb2d),
+}
+
+impl VolumePart2d {
+    /// Computes the smallest [`Aabb2d`] containing this part.
+    #[inline]
+    pub fn aabb_2d(&self) -> Aabb2d {
+        match self {
+            VolumePart2d::Aabb(aabb) => *aabb,
+            VolumePart2d::Circle(circle) => circle.aabb_2d(),
+            VolumePart2d::Obb(obb) => obb.aabb_2d(),
+        }
+    }
+}
+
+impl From<Aabb2d> for VolumePart2d {
+    #[inline]
+    fn from(aabb: Aabb2d) -> Self {
+        VolumePart2d::Aabb(aabb)
+    }
+}
+
+impl From<BoundingCircle> for VolumePart2d {
+    #[inline]
+    fn from(circle: BoundingCircle) -> Self {
+        VolumePart2d::Circle(circle)
+    }
+}
+
+impl From<Obb2d> for VolumePart2d {
+    #[inline]
+    fn from(obb: Obb2d) -> Self {
+        VolumePart2d::Obb(obb)
+    }
+}
+
+impl ContainsPoint2d for VolumePart2d {
+    #[inline]
+    fn contains_point(&self, point: Vec2) -> bool {
+        match self {
+            VolumePart2d::Aabb(aabb) => aabb.contains_point(point),
+            VolumePart2d::Circle(circle) => circle.contains_point(point),
+            VolumePart2d::Obb(obb) => obb.contains_point(point),
+        }
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for VolumePart2d {
+    #[inline]
+    fn intersects(&self, volume: &Aabb2d) -> bool {
+        match self {
+            VolumePart2d::Aabb(aabb) => aabb.intersects(volume),
+            VolumePart2d::Circle(circle) => circle.intersects(volume),
+            VolumePart2d::Obb(obb) => obb.intersects(volume),
+        }
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for VolumePart2d {
+    #[inline]
+    fn intersects(&self, volume: &BoundingCircle) -> bool {
+        match self {
+            VolumePart2d::Aabb(aabb) => aabb.intersects(volume),
+            VolumePart2d::Circle(circle) => circle.intersects(volume),
+            VolumePart2d::Obb(obb) => obb.intersects(volume),
+        }
+    }
+}
+
+/// A bounding volume made of several parts, for bounding shapes like an L-shaped
+/// or multi-part actor more tightly than a single volume could.
+///
+/// A volume intersects the compound volume if it intersects any of its parts.
+/// The parts are tested in order, so putting the largest parts first
+/// makes the tests return early more often.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_math::{bounding::{Aabb2d, BoundingCircle, CompoundVolume2d, IntersectsVolume}, Vec2};
+/// // An L shape made of two boxes
+/// let compound = CompoundVolume2d::new([
+///     Aabb2d::new(Vec2::new(0.0, 2.0), Vec2::new(0.5, 2.5)),
+///     Aabb2d::new(Vec2::new(2.0, 0.0), Vec2::new(2.5, 0.5)),
+/// ]);
+/// assert_eq!(
+///     compound.aabb_2d(),
+///     Some(Aabb2d::new(Vec2::new(2.0, 2.0), Vec2::splat(2.5)))
+/// );
+///
+/// // The inner corner of the L is inside the merged AABB, but not inside any part
+/// assert!(!compound.intersects(&BoundingCircle::new(Vec2::splat(3.0), 1.0)));
+/// assert!(compound.intersects(&BoundingCircle::new(Vec2::new(0.0, 4.0), 1.0)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CompoundVolume2d {
+    /// The parts making up the volume
+    pub parts: Vec<VolumePart2d>,
+}
+
+impl CompoundVolume2d {
+    /// Constructs a compound volume from the given parts.
+    #[inline]
+    pub fn new(parts: impl IntoIterator<Item = impl Into<VolumePart2d>>) -> Self {
+        Self {
+            parts: parts.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Adds a part to the volume.
+    #[inline]
+    pub fn push(&mut self, part: impl Into<VolumePart2d>) {
+        self.parts.push(part.into());
+    }
+
+    /// Computes the smallest [`Aabb2d`] containing every part of the volume.
+    ///
+    /// Returns `None` if the volume has no parts.
+    #[inline]
+    pub fn aabb_2d(&self) -> Option<Aabb2d> {
+        Aabb2d::from_volumes(self.parts.iter().map(VolumePart2d::aabb_2d))
+    }
+
+    /// Checks if any part of the volume intersects the given volume.
+    #[inline(always)]
+    fn any_part_intersects<T: BoundingVolume>(&self, volume: &T) -> bool
+    where
+        VolumePart2d: IntersectsVolume<T>,
+    {
+        self.parts.iter().any(|part| part.intersects(volume))
+    }
+}
+
+impl ContainsPoint2d for CompoundVolume2d {
+    #[inline]
+    fn contains_point(&self, point: Vec2) -> bool {
+        self.parts.iter().any(|part| part.contains_point(point))
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for CompoundVolume2d {
+    #[inline]
+    fn intersects(&self, volume: &Aabb2d) -> bool {
+        self.any_part_intersects(volume)
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for CompoundVolume2d {
+    #[inline]
+    fn intersects(&self, volume: &BoundingCircle) -> bool {
+        self.any_part_intersects(volume)
+    }
+}
+
+#[cfg(test)]
+mod compound_volume_tests {
+    use super::{CompoundVolume2d, VolumePart2d};
+    use crate::{
+        bounding::{Aabb2d, BoundingCircle, ContainsPoint2d, IntersectsVolume, Obb2d},
+        Rot2, Vec2,
+    };
+
+    /// An L shape made of a vertical box, a horizontal box, and a circle at the end.
+    fn l_shape() -> CompoundVolume2d {
+        let mut compound = CompoundVolume2d::new([
+            Aabb2d::new(Vec2::new(0.0, 2.0), Vec2::new(0.5, 2.5)),
+            Aabb2d::new(Vec2::new(2.0, 0.0), Vec2::new(2.5, 0.5)),
+        ]);
+        compound.push(BoundingCircle::new(Vec2::new(5.0, 0.0), 1.0));
+        compound
+    }
+
+    #[test]
+    fn aabb_2d() {
+        let aabb = l_shape().aabb_2d().unwrap();
+        assert_eq!(aabb.min, Vec2::new(-0.5, -1.0));
+        assert_eq!(aabb.max, Vec2::new(6.0, 4.5));
+
+        let rotated =
+            CompoundVolume2d::new([Obb2d::new(Vec2::ZERO, Vec2::new(2.0, 1.0), Rot2::FRAC_PI_2)]);
+        let aabb = rotated.aabb_2d().unwrap();
+        assert!(aabb.min.abs_diff_eq(Vec2::new(-1.0, -2.0), 1e-6));
+        assert!(aabb.max.abs_diff_eq(Vec2::new(1.0, 2.0), 1e-6));
+
+        assert_eq!(CompoundVolume2d::default().aabb_2d(), None);
+    }
+
+    #[test]
+    fn intersects() {
+        let compound = l_shape();
+        for (test, volume, expected) in &[
+            (
+                "the vertical part",
+                Aabb2d::new(Vec2::new(0.0, 4.0), Vec2::splat(0.5)),
+                true,
+            ),
+            (
+                "the horizontal part",
+                Aabb2d::new(Vec2::new(3.0, -0.5), Vec2::splat(0.5)),
+                true,
+            ),
+            (
+                "the circle",
+                Aabb2d::new(Vec2::new(6.5, 0.0), Vec2::splat(0.6)),
+                true,
+            ),
+            (
+                "the inner corner",
+                Aabb2d::new(Vec2::new(3.0, 3.0), Vec2::splat(1.0)),
+                false,
+            ),
+            (
+                "outside the merged AABB",
+                Aabb2d::new(Vec2::new(-3.0, 0.0), Vec2::splat(1.0)),
+                false,
+            ),
+        ] {
+            assert_eq!(
+                compound.intersects(volume),
+                *expected,
+                "Case:\n  Test: {test:?}",
+            );
+        }
+
+        // An empty compound volume intersects nothing
+        assert!(!CompoundVolume2d::default().intersects(&BoundingCircle::new(Vec2::ZERO, 1e6)));
+    }
+
+    #[test]
+    fn parts() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        let circle = BoundingCircle::new(Vec2::ONE, 2.0);
+        let obb = Obb2d::from(aabb);
+        assert_eq!(VolumePart2d::from(aabb), VolumePart2d::Aabb(aabb));
+        assert_eq!(VolumePart2d::from(circle).aabb_2d(), circle.aabb_2d());
+        assert_eq!(VolumePart2d::from(obb).aabb_2d(), aabb);
+        assert!(VolumePart2d::from(obb).intersects(&circle));
+    }
+
+    #[test]
+    fn contains_point() {
+        let compound = l_shape();
+        assert!(compound.contains_point(Vec2::new(0.0, 4.0)));
+        assert!(compound.contains_point(Vec2::new(4.5, 0.0)));
+        assert!(compound.contains_point(Vec2::new(6.0, 0.0)));
+        // The inner corner of the L is inside the merged AABB, but not inside any part
+        assert!(!compound.contains_point(Vec2::new(2.0, 2.0)));
+        assert!(!CompoundVolume2d::default().contains_point(Vec2::ZERO));
+    }
+}
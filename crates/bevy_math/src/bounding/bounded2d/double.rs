@@ -0,0 +1,1041 @@
+use super::gcd;
+use crate::{
+    bounding::{BoundingVolume, IntersectsVolume, SignedDistance2d},
+    prelude::{Mat2, Rot2},
+    DVec2, Isometry2d, Vec2,
+};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Transforms a double-precision point by the given isometry.
+///
+/// The isometry itself is single-precision, but the point is kept in double precision throughout.
+#[inline(always)]
+fn transform_point(isometry: Isometry2d, point: DVec2) -> DVec2 {
+    rotate_point(isometry.rotation, point) + isometry.translation.as_dvec2()
+}
+
+/// Rotates a double-precision point by the given rotation.
+#[inline(always)]
+fn rotate_point(rotation: Rot2, point: DVec2) -> DVec2 {
+    let (cos, sin) = (rotation.cos as f64, rotation.sin as f64);
+    DVec2::new(point.x * cos - point.y * sin, point.x * sin + point.y * cos)
+}
+
+/// A 2D axis-aligned bounding box, or bounding rectangle, using double precision.
+///
+/// This is the `f64` counterpart of [`Aabb2d`](super::Aabb2d). It implements the [`BoundingVolume`]
+/// and [`IntersectsVolume`] traits, and has the inherent methods of [`Aabb2d`](super::Aabb2d) for
+/// construction ([`new`](Self::new), [`from_min_max`](Self::from_min_max), [`from_point_cloud`](Self::from_point_cloud),
+/// [`from_volumes`](Self::from_volumes)), queries ([`is_valid`](Self::is_valid), [`area`](Self::area),
+/// [`perimeter`](Self::perimeter), [`corners`](Self::corners), [`closest_point`](Self::closest_point),
+/// [`clamp_point`](Self::clamp_point), [`signed_distance`](Self::signed_distance)), intersections
+/// ([`intersects_aabb`](Self::intersects_aabb), [`intersection`](Self::intersection), [`overlap_mtv`](Self::overlap_mtv)),
+/// and transformations ([`inflate`](Self::inflate), [`deflate`](Self::deflate), [`expand_to_include`](Self::expand_to_include),
+/// [`transformed_by_isometry`](Self::transformed_by_isometry), [`relative_to`](Self::relative_to),
+/// [`bounding_circle`](Self::bounding_circle)). Code limited to these can switch between the two using a type alias.
+///
+/// [`SignedDistance2d`] takes and returns `f32`s, so it is also available as the inherent
+/// [`DAabb2d::signed_distance`], which keeps double precision. The other methods of [`Aabb2d`](super::Aabb2d),
+/// like [`overlap_info`](super::Aabb2d::overlap_info), [`wrap_point`](super::Aabb2d::wrap_point), sweeps,
+/// and ray casts other than [`DRayCast2d`](crate::bounding::DRayCast2d), are only available in single precision.
+#[doc(alias = "DBoundingRectangle")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DAabb2d {
+    /// The minimum, conventionally bottom-left, point of the box
+    pub min: DVec2,
+    /// The maximum, conventionally top-right, point of the box
+    pub max: DVec2,
+}
+
+impl DAabb2d {
+    /// Constructs an AABB from its center and half-size.
+    #[inline(always)]
+    pub fn new(center: DVec2, half_size: DVec2) -> Self {
+        debug_assert!(half_size.x >= 0.0 && half_size.y >= 0.0);
+        Self {
+            min: center - half_size,
+            max: center + half_size,
+        }
+    }
+
+    /// Checks if the AABB is well-formed, meaning that its bounds are finite,
+    /// and that its minimum is not greater than its maximum on any axis.
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        self.min.is_finite() && self.max.is_finite() && self.min.cmple(self.max).all()
+    }
+
+    /// Constructs an AABB from two opposite corners, such as its minimum and maximum.
+    ///
+    /// The corners may be given in any order, like for [`Aabb2d::from_min_max`](super::Aabb2d::from_min_max).
+    #[inline(always)]
+    pub const fn from_min_max(min: DVec2, max: DVec2) -> Self {
+        let (min_x, max_x) = if min.x <= max.x {
+            (min.x, max.x)
+        } else {
+            (max.x, min.x)
+        };
+        let (min_y, max_y) = if min.y <= max.y {
+            (min.y, max.y)
+        } else {
+            (max.y, min.y)
+        };
+        Self {
+            min: DVec2::new(min_x, min_y),
+            max: DVec2::new(max_x, max_y),
+        }
+    }
+
+    /// Computes the smallest [`DAabb2d`] containing the given set of points,
+    /// transformed by the rotation and translation of the given isometry.
+    ///
+    /// Note that the isometry is single-precision. To get the full benefit of double precision
+    /// far away from the origin, transform the points beforehand and pass [`Isometry2d::IDENTITY`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given set of points is empty.
+    #[inline(always)]
+    pub fn from_point_cloud(isometry: impl Into<Isometry2d>, points: &[DVec2]) -> DAabb2d {
+        let isometry = isometry.into();
+
+        // Transform all points by rotation
+        let mut iter = points
+            .iter()
+            .map(|point| rotate_point(isometry.rotation, *point));
+
+        let first = iter
+            .next()
+            .expect("point cloud must contain at least one point for DAabb2d construction");
+
+        let (min, max) = iter.fold((first, first), |(prev_min, prev_max), point| {
+            (point.min(prev_min), point.max(prev_max))
+        });
+
+        let translation = isometry.translation.as_dvec2();
+        DAabb2d {
+            min: min + translation,
+            max: max + translation,
+        }
+    }
+
+    /// Computes the smallest [`DAabb2d`] containing all of the given AABBs.
+    ///
+    /// Returns `None` if the iterator is empty.
+    #[inline(always)]
+    pub fn from_volumes(volumes: impl IntoIterator<Item = DAabb2d>) -> Option<DAabb2d> {
+        volumes.into_iter().reduce(|acc, aabb| acc.merge(&aabb))
+    }
+
+    /// Computes the smallest [`DBoundingCircle`] containing this [`DAabb2d`].
+    #[inline(always)]
+    pub fn bounding_circle(&self) -> DBoundingCircle {
+        let radius = self.min.distance(self.max) / 2.0;
+        DBoundingCircle::new(self.center(), radius)
+    }
+
+    /// Transforms this AABB by the given isometry, first rotating it around the origin
+    /// and then translating it.
+    ///
+    /// This is equivalent to [`BoundingVolume::transformed_by`] with the translation and rotation
+    /// of the isometry, so the same caveats about the result growing with rotations apply.
+    #[inline(always)]
+    pub fn transformed_by_isometry(&self, isometry: impl Into<Isometry2d>) -> DAabb2d {
+        let isometry = isometry.into();
+        self.transformed_by(isometry.translation.as_dvec2(), isometry.rotation)
+    }
+
+    /// Expresses this AABB in the local frame of the given `origin`,
+    /// by transforming it by the inverse of the isometry.
+    ///
+    /// If `origin` is the identity, the AABB is returned unchanged.
+    #[inline(always)]
+    pub fn relative_to(&self, origin: &Isometry2d) -> DAabb2d {
+        if *origin == Isometry2d::IDENTITY {
+            return *self;
+        }
+        self.transformed_by_isometry(origin.inverse())
+    }
+
+    /// Checks if this AABB intersects the given AABB, including if they are only touching.
+    ///
+    /// This is the same test as the [`IntersectsVolume<DAabb2d>`] implementation,
+    /// but can be called in `const` contexts and without going through the trait.
+    #[inline(always)]
+    pub const fn intersects_aabb(&self, other: &DAabb2d) -> bool {
+        let x_overlaps = self.min.x <= other.max.x && self.max.x >= other.min.x;
+        let y_overlaps = self.min.y <= other.max.y && self.max.y >= other.min.y;
+        x_overlaps && y_overlaps
+    }
+
+    /// Computes the AABB of the region where this AABB and the given AABB overlap.
+    ///
+    /// Returns `None` if the AABBs don't intersect. AABBs that are only touching produce
+    /// a degenerate AABB with zero area along the shared edge or corner.
+    #[inline(always)]
+    pub fn intersection(&self, other: &DAabb2d) -> Option<DAabb2d> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        min.cmple(max).all().then_some(DAabb2d { min, max })
+    }
+
+    /// Expands this AABB in place so that it contains the given `point`.
+    #[inline(always)]
+    pub fn expand_to_include(&mut self, point: DVec2) {
+        self.min = self.min.min(point);
+        self.max = self.max.max(point);
+    }
+
+    /// Returns the four corners of the AABB in counterclockwise order, starting from [`DAabb2d::min`].
+    #[inline(always)]
+    pub fn corners(&self) -> [DVec2; 4] {
+        [
+            self.min,
+            DVec2::new(self.max.x, self.min.y),
+            self.max,
+            DVec2::new(self.min.x, self.max.y),
+        ]
+    }
+
+    /// Computes the area of the AABB.
+    #[inline(always)]
+    pub fn area(&self) -> f64 {
+        let size = self.max - self.min;
+        size.x * size.y
+    }
+
+    /// Computes the perimeter of the AABB.
+    #[inline(always)]
+    pub fn perimeter(&self) -> f64 {
+        let size = self.max - self.min;
+        2.0 * (size.x + size.y)
+    }
+
+    /// Returns a copy of this AABB with `margin` added to each half-extent.
+    #[inline(always)]
+    pub fn inflate(&self, margin: f64) -> DAabb2d {
+        debug_assert!(margin >= 0.0);
+        DAabb2d {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// Returns a copy of this AABB with `margin` subtracted from each half-extent.
+    ///
+    /// The half-extents are clamped at zero, so deflating past zero on an axis yields
+    /// a degenerate AABB that is flat on that axis, centered at the original center.
+    #[inline(always)]
+    pub fn deflate(&self, margin: f64) -> DAabb2d {
+        debug_assert!(margin >= 0.0);
+        let half_size = (self.half_size() - margin).max(DVec2::ZERO);
+        DAabb2d::new(self.center(), half_size)
+    }
+
+    /// Finds the point on the AABB that is closest to the given `point`.
+    ///
+    /// If the point is outside the AABB, the returned point will be on the perimeter of the AABB.
+    /// Otherwise, it will be inside or on the perimeter of the AABB and returned as is.
+    #[inline(always)]
+    pub fn closest_point(&self, point: DVec2) -> DVec2 {
+        // Clamp point coordinates to the AABB
+        point.clamp(self.min, self.max)
+    }
+
+    /// Clamps the given `point` to the AABB, returning the nearest point inside or on the AABB.
+    ///
+    /// This is the same as [`closest_point`](Self::closest_point).
+    #[inline(always)]
+    pub fn clamp_point(&self, point: DVec2) -> DVec2 {
+        self.closest_point(point)
+    }
+
+    /// Computes the minimum translation vector that pushes `self` out of `other`,
+    /// along the axis of least penetration.
+    ///
+    /// Returns `None` if the AABBs don't intersect. AABBs that are merely touching
+    /// produce a zero-length vector.
+    ///
+    /// If the penetration depth is equal on both axes, the X axis is used.
+    /// If the centers coincide on the chosen axis, `self` is pushed in the positive direction.
+    #[inline(always)]
+    pub fn overlap_mtv(&self, other: &DAabb2d) -> Option<DVec2> {
+        let offset = self.center() - other.center();
+        let penetration = (self.half_size() + other.half_size()) - offset.abs();
+
+        if penetration.x < 0.0 || penetration.y < 0.0 {
+            return None;
+        }
+
+        let index = if penetration.x <= penetration.y { 0 } else { 1 };
+        let depth = penetration[index];
+        let mut mtv = DVec2::ZERO;
+        mtv[index] = if offset[index] < 0.0 { -depth } else { depth };
+        Some(mtv)
+    }
+
+    /// Computes the exact Euclidean signed distance from the given `point` to the boundary of the AABB.
+    ///
+    /// This is the double-precision version of [`SignedDistance2d::signed_distance`].
+    #[inline(always)]
+    pub fn signed_distance(&self, point: DVec2) -> f64 {
+        let d = (point - self.center()).abs() - self.half_size();
+        // The distance to the perimeter from the outside, and the (negative) distance from the inside.
+        let outside = d.max(DVec2::ZERO).length();
+        let inside = d.max_element().min(0.0);
+        outside + inside
+    }
+}
+
+impl SignedDistance2d for DAabb2d {
+    #[inline(always)]
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        DAabb2d::signed_distance(self, point.as_dvec2()) as f32
+    }
+}
+
+impl BoundingVolume for DAabb2d {
+    type Translation = DVec2;
+    type Rotation = Rot2;
+    type HalfSize = DVec2;
+
+    #[inline(always)]
+    fn center(&self) -> Self::Translation {
+        (self.min + self.max) / 2.
+    }
+
+    #[inline(always)]
+    fn half_size(&self) -> Self::HalfSize {
+        (self.max - self.min) / 2.
+    }
+
+    /// Computes the area of the AABB.
+    ///
+    /// The area is computed in double precision, but returned as an `f32`,
+    /// which is sufficient for comparing volumes using a Surface Area Heuristic.
+    #[inline(always)]
+    fn visible_area(&self) -> f32 {
+        let b = self.max - self.min;
+        (b.x * b.y) as f32
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &Self) -> bool {
+        other.min.x >= self.min.x
+            && other.min.y >= self.min.y
+            && other.max.x <= self.max.x
+            && other.max.y <= self.max.y
+    }
+
+    #[inline(always)]
+    fn merge(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    #[inline(always)]
+    fn grow(&self, amount: impl Into<Self::HalfSize>) -> Self {
+        let amount = amount.into();
+        let b = Self {
+            min: self.min - amount,
+            max: self.max + amount,
+        };
+        debug_assert!(b.min.x <= b.max.x && b.min.y <= b.max.y);
+        b
+    }
+
+    #[inline(always)]
+    fn shrink(&self, amount: impl Into<Self::HalfSize>) -> Self {
+        let amount = amount.into();
+        let b = Self {
+            min: self.min + amount,
+            max: self.max - amount,
+        };
+        debug_assert!(b.min.x <= b.max.x && b.min.y <= b.max.y);
+        b
+    }
+
+    #[inline(always)]
+    fn scale_around_center(&self, scale: impl Into<Self::HalfSize>) -> Self {
+        let scale = scale.into();
+        let b = Self {
+            min: self.center() - (self.half_size() * scale),
+            max: self.center() + (self.half_size() * scale),
+        };
+        debug_assert!(b.min.x <= b.max.x && b.min.y <= b.max.y);
+        b
+    }
+
+    #[inline(always)]
+    fn translate_by(&mut self, translation: impl Into<Self::Translation>) {
+        let translation = translation.into();
+        self.min += translation;
+        self.max += translation;
+    }
+
+    /// Rotates the bounding volume around the origin by the given rotation.
+    ///
+    /// The result is an Axis-Aligned Bounding Box that encompasses the rotated shape.
+    ///
+    /// Note that the result may not be as tightly fitting as the original, and repeated rotations
+    /// can cause the AABB to grow indefinitely. Avoid applying multiple rotations to the same AABB,
+    /// and consider storing the original AABB and rotating that every time instead.
+    #[inline(always)]
+    fn rotate_by(&mut self, rotation: impl Into<Self::Rotation>) {
+        // Use the same matrix as `Aabb2d` so that both types behave identically
+        let rot_mat = Mat2::from(rotation.into()).as_dmat2();
+        let half_size = rot_mat.abs() * self.half_size();
+        *self = Self::new(rot_mat * self.center(), half_size);
+    }
+}
+
+impl IntersectsVolume<Self> for DAabb2d {
+    #[inline(always)]
+    fn intersects(&self, other: &Self) -> bool {
+        let x_overlaps = self.min.x <= other.max.x && self.max.x >= other.min.x;
+        let y_overlaps = self.min.y <= other.max.y && self.max.y >= other.min.y;
+        x_overlaps && y_overlaps
+    }
+}
+
+impl IntersectsVolume<DBoundingCircle> for DAabb2d {
+    #[inline(always)]
+    fn intersects(&self, circle: &DBoundingCircle) -> bool {
+        let closest_point = self.closest_point(circle.center);
+        let distance_squared = circle.center.distance_squared(closest_point);
+        let radius_squared = circle.radius() * circle.radius();
+        distance_squared <= radius_squared
+    }
+}
+
+/// A bounding circle using double precision.
+///
+/// This is the `f64` counterpart of [`BoundingCircle`](super::BoundingCircle). It implements the
+/// [`BoundingVolume`] and [`IntersectsVolume`] traits, and has the inherent methods of
+/// [`BoundingCircle`](super::BoundingCircle) for construction ([`new`](Self::new), [`from_point_cloud`](Self::from_point_cloud),
+/// [`enclosing`](Self::enclosing), [`from_volumes`](Self::from_volumes)), queries ([`is_valid`](Self::is_valid),
+/// [`radius`](Self::radius), [`area`](Self::area), [`circumference`](Self::circumference),
+/// [`closest_point`](Self::closest_point), [`signed_distance`](Self::signed_distance)), intersections
+/// ([`intersects_circle`](Self::intersects_circle), [`contains_aabb`](Self::contains_aabb)), and transformations
+/// ([`inflate`](Self::inflate), [`deflate`](Self::deflate), [`transformed_by_isometry`](Self::transformed_by_isometry),
+/// [`aabb_2d`](Self::aabb_2d)). Code limited to these can switch between the two using a type alias.
+///
+/// As for [`DAabb2d`], [`SignedDistance2d`] is also available as the inherent [`DBoundingCircle::signed_distance`],
+/// which keeps double precision. The other methods of [`BoundingCircle`](super::BoundingCircle)
+/// are only available in single precision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DBoundingCircle {
+    /// The center of the bounding circle
+    pub center: DVec2,
+    /// The radius of the bounding circle
+    pub radius: f64,
+}
+
+impl DBoundingCircle {
+    /// Constructs a bounding circle from its center and radius.
+    #[inline(always)]
+    pub fn new(center: DVec2, radius: f64) -> Self {
+        debug_assert!(radius >= 0.);
+        Self { center, radius }
+    }
+
+    /// Checks if the bounding circle is well-formed, meaning that its center is finite,
+    /// and that its radius is finite and not negative.
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        self.center.is_finite() && self.radius().is_finite() && self.radius() >= 0.
+    }
+
+    /// Computes a [`DBoundingCircle`] containing the given set of points,
+    /// transformed by the rotation and translation of the given isometry.
+    ///
+    /// Note that the isometry is single-precision. To get the full benefit of double precision
+    /// far away from the origin, transform the points beforehand and pass [`Isometry2d::IDENTITY`].
+    ///
+    /// The bounding circle is not guaranteed to be the smallest possible.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given set of points is empty.
+    #[inline(always)]
+    pub fn from_point_cloud(isometry: impl Into<Isometry2d>, points: &[DVec2]) -> DBoundingCircle {
+        assert!(
+            !points.is_empty(),
+            "cannot compute the center of an empty set of points"
+        );
+        let isometry = isometry.into();
+
+        let denom = 1.0 / points.len() as f64;
+        let center = points.iter().fold(DVec2::ZERO, |acc, point| acc + *point) * denom;
+        let radius = points
+            .iter()
+            .map(|point| point.distance(center))
+            .fold(0.0, f64::max);
+
+        DBoundingCircle::new(transform_point(isometry, center), radius)
+    }
+
+    /// Computes the smallest [`DBoundingCircle`] containing the given set of points,
+    /// using Welzl's algorithm.
+    ///
+    /// Returns `None` if the set of points is empty. The points are visited in the same
+    /// scrambled order as for [`BoundingCircle::enclosing`](super::BoundingCircle::enclosing).
+    #[inline]
+    pub fn enclosing(points: &[DVec2]) -> Option<DBoundingCircle> {
+        if points.is_empty() {
+            return None;
+        }
+
+        // Any stride coprime with the number of points visits each of them exactly once
+        let len = points.len();
+        let mut stride = (len as f64 * 0.618_034) as usize | 1;
+        while gcd(stride, len) != 1 {
+            stride += 2;
+        }
+        let point = |k: usize| points[((k as u64 * stride as u64) % len as u64) as usize];
+
+        // Allow a small relative tolerance, so that points on the boundary of a circle computed
+        // from them are not rejected because of rounding errors.
+        let contains = |circle: &DBoundingCircle, point: DVec2| {
+            point.distance(circle.center) <= circle.radius() * (1.0 + 1e-12) + f64::EPSILON
+        };
+
+        let mut circle = DBoundingCircle::new(point(0), 0.0);
+        for i in 1..len {
+            let p = point(i);
+            if contains(&circle, p) {
+                continue;
+            }
+            // `p` is on the boundary of the smallest circle enclosing the points so far
+            circle = DBoundingCircle::new(p, 0.0);
+            for j in 0..i {
+                let q = point(j);
+                if contains(&circle, q) {
+                    continue;
+                }
+                // Both `p` and `q` are on the boundary
+                circle = DBoundingCircle::new((p + q) / 2.0, p.distance(q) / 2.0);
+                for r in (0..j).map(point) {
+                    if !contains(&circle, r) {
+                        // `p`, `q`, and `r` are all on the boundary
+                        circle = Self::circumcircle(p, q, r);
+                    }
+                }
+            }
+        }
+
+        Some(circle)
+    }
+
+    /// Computes the circle passing through three points,
+    /// or the smallest circle containing them if they are collinear.
+    #[inline(always)]
+    fn circumcircle(a: DVec2, b: DVec2, c: DVec2) -> DBoundingCircle {
+        let ab = b - a;
+        let ac = c - a;
+        let denom = 2.0 * ab.perp_dot(ac);
+
+        if denom == 0.0 {
+            // The points are collinear, so the two that are farthest apart span the circle
+            let (p, q) = [(a, b), (a, c), (b, c)]
+                .into_iter()
+                .max_by(|(p1, q1), (p2, q2)| {
+                    p1.distance_squared(*q1)
+                        .total_cmp(&p2.distance_squared(*q2))
+                })
+                .unwrap();
+            return DBoundingCircle::new((p + q) / 2.0, p.distance(q) / 2.0);
+        }
+
+        let offset = DVec2::new(
+            ac.y * ab.length_squared() - ab.y * ac.length_squared(),
+            ab.x * ac.length_squared() - ac.x * ab.length_squared(),
+        ) / denom;
+        DBoundingCircle::new(a + offset, offset.length())
+    }
+
+    /// Computes a [`DBoundingCircle`] containing all of the given bounding circles,
+    /// by repeatedly merging them.
+    ///
+    /// The bounding circle is not guaranteed to be the smallest possible.
+    ///
+    /// Returns `None` if the iterator is empty.
+    #[inline(always)]
+    pub fn from_volumes(
+        volumes: impl IntoIterator<Item = DBoundingCircle>,
+    ) -> Option<DBoundingCircle> {
+        volumes.into_iter().reduce(|acc, circle| acc.merge(&circle))
+    }
+
+    /// Get the radius of the bounding circle
+    #[inline(always)]
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Checks if this bounding circle intersects the given bounding circle,
+    /// including if they are only touching.
+    ///
+    /// This is the same test as the [`IntersectsVolume<DBoundingCircle>`] implementation,
+    /// but can be called in `const` contexts and without going through the trait.
+    #[inline(always)]
+    pub const fn intersects_circle(&self, other: &DBoundingCircle) -> bool {
+        let dx = self.center.x - other.center.x;
+        let dy = self.center.y - other.center.y;
+        let radius_sum = self.radius + other.radius;
+        dx * dx + dy * dy <= radius_sum * radius_sum
+    }
+
+    /// Checks if this bounding circle fully contains the given [`DAabb2d`],
+    /// meaning that every point of the AABB is inside or on the boundary of the circle.
+    #[inline(always)]
+    pub fn contains_aabb(&self, aabb: &DAabb2d) -> bool {
+        // The corner of the AABB farthest from the center of the circle must be within the circle
+        let farthest_corner = (aabb.center() - self.center).abs() + aabb.half_size();
+        farthest_corner.length_squared() <= self.radius() * self.radius()
+    }
+
+    /// Transforms this bounding circle by the given isometry, first rotating it around the origin
+    /// and then translating it. The radius is unaffected.
+    #[inline(always)]
+    pub fn transformed_by_isometry(&self, isometry: impl Into<Isometry2d>) -> DBoundingCircle {
+        DBoundingCircle::new(transform_point(isometry.into(), self.center), self.radius())
+    }
+
+    /// Computes the area of the bounding circle.
+    #[inline(always)]
+    pub fn area(&self) -> f64 {
+        core::f64::consts::PI * self.radius() * self.radius()
+    }
+
+    /// Computes the circumference of the bounding circle.
+    #[inline(always)]
+    pub fn circumference(&self) -> f64 {
+        core::f64::consts::TAU * self.radius()
+    }
+
+    /// Returns a copy of this bounding circle with `margin` added to its radius.
+    #[inline(always)]
+    pub fn inflate(&self, margin: f64) -> DBoundingCircle {
+        debug_assert!(margin >= 0.0);
+        DBoundingCircle::new(self.center, self.radius() + margin)
+    }
+
+    /// Returns a copy of this bounding circle with `margin` subtracted from its radius.
+    ///
+    /// The radius is clamped at zero, so deflating past zero yields a degenerate circle
+    /// with a radius of zero, centered at the original center.
+    #[inline(always)]
+    pub fn deflate(&self, margin: f64) -> DBoundingCircle {
+        debug_assert!(margin >= 0.0);
+        DBoundingCircle::new(self.center, (self.radius() - margin).max(0.0))
+    }
+
+    /// Computes the smallest [`DAabb2d`] containing this [`DBoundingCircle`].
+    #[inline(always)]
+    pub fn aabb_2d(&self) -> DAabb2d {
+        DAabb2d {
+            min: self.center - DVec2::splat(self.radius()),
+            max: self.center + DVec2::splat(self.radius()),
+        }
+    }
+
+    /// Finds the point on the bounding circle that is closest to the given `point`.
+    ///
+    /// If the point is outside the circle, the returned point will be on the perimeter of the circle.
+    /// Otherwise, it will be inside or on the perimeter of the circle and returned as is.
+    #[inline(always)]
+    pub fn closest_point(&self, point: DVec2) -> DVec2 {
+        let offset = point - self.center;
+
+        if offset.length_squared() <= self.radius() * self.radius() {
+            point
+        } else {
+            self.center + self.radius() * offset.normalize()
+        }
+    }
+
+    /// Computes the exact Euclidean signed distance from the given `point` to the boundary of the circle.
+    ///
+    /// This is the double-precision version of [`SignedDistance2d::signed_distance`].
+    #[inline(always)]
+    pub fn signed_distance(&self, point: DVec2) -> f64 {
+        point.distance(self.center) - self.radius()
+    }
+}
+
+impl SignedDistance2d for DBoundingCircle {
+    #[inline(always)]
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        DBoundingCircle::signed_distance(self, point.as_dvec2()) as f32
+    }
+}
+
+impl BoundingVolume for DBoundingCircle {
+    type Translation = DVec2;
+    type Rotation = Rot2;
+    type HalfSize = f64;
+
+    #[inline(always)]
+    fn center(&self) -> Self::Translation {
+        self.center
+    }
+
+    #[inline(always)]
+    fn half_size(&self) -> Self::HalfSize {
+        self.radius()
+    }
+
+    /// Computes the area of the circle.
+    ///
+    /// The area is computed in double precision, but returned as an `f32`,
+    /// which is sufficient for comparing volumes using a Surface Area Heuristic.
+    #[inline(always)]
+    fn visible_area(&self) -> f32 {
+        (core::f64::consts::PI * self.radius() * self.radius()) as f32
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &Self) -> bool {
+        let diff = self.radius() - other.radius();
+        diff >= 0.0 && self.center.distance_squared(other.center) <= diff * diff
+    }
+
+    #[inline(always)]
+    fn merge(&self, other: &Self) -> Self {
+        let diff = other.center - self.center;
+        let length = diff.length();
+        if self.radius() >= length + other.radius() {
+            return *self;
+        }
+        if other.radius() >= length + self.radius() {
+            return *other;
+        }
+        let dir = diff / length;
+        Self::new(
+            (self.center + other.center) / 2. + dir * ((other.radius() - self.radius()) / 2.),
+            (length + self.radius() + other.radius()) / 2.,
+        )
+    }
+
+    #[inline(always)]
+    fn grow(&self, amount: impl Into<Self::HalfSize>) -> Self {
+        let amount = amount.into();
+        debug_assert!(amount >= 0.);
+        Self::new(self.center, self.radius() + amount)
+    }
+
+    #[inline(always)]
+    fn shrink(&self, amount: impl Into<Self::HalfSize>) -> Self {
+        let amount = amount.into();
+        debug_assert!(amount >= 0.);
+        debug_assert!(self.radius() >= amount);
+        Self::new(self.center, self.radius() - amount)
+    }
+
+    #[inline(always)]
+    fn scale_around_center(&self, scale: impl Into<Self::HalfSize>) -> Self {
+        let scale = scale.into();
+        debug_assert!(scale >= 0.);
+        Self::new(self.center, self.radius() * scale)
+    }
+
+    #[inline(always)]
+    fn translate_by(&mut self, translation: impl Into<Self::Translation>) {
+        self.center += translation.into();
+    }
+
+    #[inline(always)]
+    fn rotate_by(&mut self, rotation: impl Into<Self::Rotation>) {
+        self.center = rotate_point(rotation.into(), self.center);
+    }
+}
+
+impl IntersectsVolume<Self> for DBoundingCircle {
+    #[inline(always)]
+    fn intersects(&self, other: &Self) -> bool {
+        let center_distance_squared = self.center.distance_squared(other.center);
+        let radius_sum = self.radius() + other.radius();
+        center_distance_squared <= radius_sum * radius_sum
+    }
+}
+
+impl IntersectsVolume<DAabb2d> for DBoundingCircle {
+    #[inline(always)]
+    fn intersects(&self, aabb: &DAabb2d) -> bool {
+        aabb.intersects(self)
+    }
+}
+
+#[cfg(test)]
+mod alias_tests {
+    use super::{DAabb2d, DBoundingCircle};
+    use crate::{
+        bounding::{Aabb2d, BoundingCircle, BoundingVolume},
+        DVec2, Isometry2d, Rot2, Vec2,
+    };
+
+    /// Expands to a test that only refers to the bounding volumes and vectors through type aliases,
+    /// so that it compiles for both precisions.
+    macro_rules! bounds_by_alias {
+        ($name:ident, $aabb:ty, $circle:ty, $vec:ty) => {
+            #[test]
+            fn $name() {
+                type Aabb = $aabb;
+                type Circle = $circle;
+                type V = $vec;
+
+                let mut aabb = Aabb::from_min_max(V::new(2., 1.), V::new(-1., -1.));
+                assert!(aabb.is_valid());
+                aabb.expand_to_include(V::new(3., 0.));
+                assert_eq!(aabb.corners()[2], V::new(3., 1.));
+                assert_eq!(aabb.clamp_point(V::new(5., 5.)), V::new(3., 1.));
+
+                let other = Aabb::new(V::new(3., 1.), V::ONE);
+                assert!(aabb.intersects_aabb(&other));
+                assert_eq!(
+                    aabb.intersection(&other),
+                    Some(Aabb::from_min_max(V::new(2., 0.), V::new(3., 1.)))
+                );
+
+                let isometry = Isometry2d::new(Vec2::new(1., 2.), Rot2::FRAC_PI_2);
+                let moved = aabb.transformed_by_isometry(isometry);
+                assert!((moved.relative_to(&isometry).center() - aabb.center()).length() < 1e-5);
+
+                let circle = Circle::enclosing(&aabb.corners()).unwrap();
+                assert!(circle.is_valid());
+                assert!(circle.contains_aabb(&aabb.deflate(0.1)));
+                assert!(circle.intersects_circle(&Circle::new(V::ZERO, 1.)));
+                let moved = circle.transformed_by_isometry(isometry);
+                assert!((moved.center - V::new(1., 3.)).length() < 1e-5);
+            }
+        };
+    }
+
+    bounds_by_alias!(single_precision, Aabb2d, BoundingCircle, Vec2);
+    bounds_by_alias!(double_precision, DAabb2d, DBoundingCircle, DVec2);
+}
+
+#[cfg(test)]
+mod daabb2d_tests {
+    use super::{DAabb2d, DBoundingCircle};
+    use crate::{
+        bounding::{Aabb2d, BoundingVolume, IntersectsVolume, SignedDistance2d},
+        DVec2, Isometry2d, Rot2, Vec2,
+    };
+
+    #[test]
+    fn precision_far_from_origin() {
+        // Far enough from the origin that an `f32` can't represent the offset
+        let origin = DVec2::splat(1.0e9);
+        let a = DAabb2d::new(origin, DVec2::splat(0.25));
+        let b = DAabb2d::new(origin + DVec2::X * 0.625, DVec2::splat(0.25));
+        assert!(!a.intersects(&b));
+        assert!(a.grow(DVec2::splat(0.125)).intersects(&b));
+        assert_eq!(a.merge(&b).half_size(), DVec2::new(0.5625, 0.25));
+    }
+
+    #[test]
+    fn from_point_cloud() {
+        let points = [
+            DVec2::new(-1.0, -2.0),
+            DVec2::new(3.0, 0.5),
+            DVec2::new(0.0, 4.0),
+        ];
+        let aabb = DAabb2d::from_point_cloud(Isometry2d::from_translation(Vec2::X), &points);
+        assert_eq!(aabb.min, DVec2::new(0.0, -2.0));
+        assert_eq!(aabb.max, DVec2::new(4.0, 4.0));
+    }
+
+    #[test]
+    fn contains_and_merge() {
+        let a = DAabb2d::new(DVec2::ZERO, DVec2::ONE);
+        let b = DAabb2d::new(DVec2::new(2.0, 0.5), DVec2::splat(0.5));
+        assert!(!a.contains(&b));
+        let merged = a.merge(&b);
+        assert!(merged.contains(&a) && merged.contains(&b));
+        assert_eq!(DAabb2d::from_volumes([a, b]), Some(merged));
+        assert_eq!(DAabb2d::from_volumes([]), None);
+    }
+
+    #[test]
+    fn overlap_mtv() {
+        let a = DAabb2d::new(DVec2::ZERO, DVec2::ONE);
+        let b = DAabb2d::new(DVec2::new(1.5, 0.25), DVec2::ONE);
+        assert_eq!(a.overlap_mtv(&b), Some(DVec2::new(-0.5, 0.0)));
+        assert_eq!(b.overlap_mtv(&a), Some(DVec2::new(0.5, 0.0)));
+        let touching = DAabb2d::new(DVec2::new(0.0, 2.0), DVec2::ONE);
+        assert_eq!(a.overlap_mtv(&touching), Some(DVec2::ZERO));
+        assert_eq!(
+            a.overlap_mtv(&DAabb2d::new(DVec2::splat(3.0), DVec2::ONE)),
+            None
+        );
+
+        // The result matches `Aabb2d` near the origin
+        let (fa, fb) = (
+            Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+            Aabb2d::new(Vec2::new(1.5, 0.25), Vec2::ONE),
+        );
+        assert_eq!(
+            fa.overlap_mtv(&fb).map(|mtv| mtv.as_dvec2()),
+            a.overlap_mtv(&b)
+        );
+
+        // And stays exact far from it
+        let origin = DVec2::splat(1.0e9);
+        let far_a = DAabb2d::new(origin, DVec2::splat(0.25));
+        let far_b = DAabb2d::new(origin + DVec2::new(0.0, 0.375), DVec2::splat(0.25));
+        assert_eq!(far_a.overlap_mtv(&far_b), Some(DVec2::new(0.0, -0.125)));
+    }
+
+    #[test]
+    fn signed_distance() {
+        let a = DAabb2d::new(DVec2::new(1.0e9, 0.0), DVec2::ONE);
+        assert_eq!(a.signed_distance(DVec2::new(1.0e9 + 0.5, 0.0)), -0.5);
+        assert_eq!(a.signed_distance(DVec2::new(1.0e9 + 4.0, 5.0)), 5.0);
+
+        let near = DAabb2d::new(DVec2::ZERO, DVec2::ONE);
+        let point = Vec2::new(3.0, 0.5);
+        assert_eq!(
+            SignedDistance2d::signed_distance(&near, point),
+            Aabb2d::new(Vec2::ZERO, Vec2::ONE).signed_distance(point)
+        );
+    }
+
+    #[test]
+    fn rotate() {
+        let a = DAabb2d::new(DVec2::ZERO, DVec2::new(2.0, 1.0));
+        let rotated = a.rotated_by(core::f32::consts::FRAC_PI_2);
+        assert!((rotated.half_size() - DVec2::new(1.0, 2.0)).length() < 1e-6);
+
+        // The center moves like a point rotated by `Rot2 * Vec2`
+        let rotation = Rot2::degrees(30.0);
+        let a = DAabb2d::new(DVec2::new(3.0, 1.0), DVec2::ONE);
+        let expected = (rotation * Vec2::new(3.0, 1.0)).as_dvec2();
+        assert!((a.rotated_by(rotation).center() - expected).length() < 1e-6);
+    }
+
+    #[test]
+    fn intersect_bounding_circle() {
+        let aabb = DAabb2d::new(DVec2::ZERO, DVec2::ONE);
+        assert!(aabb.intersects(&DBoundingCircle::new(DVec2::new(1.5, 0.0), 0.5)));
+        assert!(!aabb.intersects(&DBoundingCircle::new(DVec2::splat(1.5), 0.5)));
+    }
+}
+
+#[cfg(test)]
+mod dbounding_circle_tests {
+    use super::{DAabb2d, DBoundingCircle};
+    use crate::{
+        bounding::{BoundingVolume, IntersectsVolume, SignedDistance2d},
+        DVec2, Isometry2d, Vec2,
+    };
+
+    #[test]
+    fn enclosing_far_from_origin() {
+        assert_eq!(DBoundingCircle::enclosing(&[]), None);
+
+        // A square with an interior point, far enough from the origin that an `f32` can't represent it
+        let origin = DVec2::new(1.0e9, -1.0e9);
+        let points = [
+            origin + DVec2::new(0.5, 0.5),
+            origin + DVec2::new(-0.5, 0.5),
+            origin + DVec2::new(0.1, 0.2),
+            origin + DVec2::new(-0.5, -0.5),
+            origin + DVec2::new(0.5, -0.5),
+        ];
+        let circle = DBoundingCircle::enclosing(&points).unwrap();
+        assert!(circle.center.distance(origin) < 1e-6);
+        assert!((circle.radius() - core::f64::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn precision_far_from_origin() {
+        let origin = DVec2::new(1.0e9, -1.0e9);
+        let a = DBoundingCircle::new(origin, 0.25);
+        let b = DBoundingCircle::new(origin + DVec2::Y * 0.625, 0.25);
+        assert!(!a.intersects(&b));
+        assert!(a.grow(0.125).intersects(&b));
+        assert_eq!(a.merge(&b).radius(), 0.5625);
+    }
+
+    #[test]
+    fn from_point_cloud() {
+        let points = [DVec2::new(-2.0, 0.0), DVec2::new(2.0, 0.0)];
+        let circle = DBoundingCircle::from_point_cloud(Isometry2d::IDENTITY, &points);
+        assert_eq!(circle, DBoundingCircle::new(DVec2::ZERO, 2.0));
+    }
+
+    #[test]
+    fn contains_and_merge() {
+        let a = DBoundingCircle::new(DVec2::ZERO, 2.0);
+        let b = DBoundingCircle::new(DVec2::X, 0.5);
+        let c = DBoundingCircle::new(DVec2::X * 3.0, 1.0);
+        assert!(a.contains(&b));
+        assert!(!a.contains(&c));
+        let merged = DBoundingCircle::from_volumes([a, b, c]).unwrap();
+        assert!(merged.contains(&a) && merged.contains(&c));
+    }
+
+    #[test]
+    fn signed_distance() {
+        let circle = DBoundingCircle::new(DVec2::new(1.0e9, 0.0), 1.0);
+        assert_eq!(circle.signed_distance(DVec2::new(1.0e9 + 0.25, 0.0)), -0.75);
+        assert_eq!(circle.signed_distance(DVec2::new(1.0e9 + 3.0, 4.0)), 4.0);
+        assert_eq!(
+            SignedDistance2d::signed_distance(
+                &DBoundingCircle::new(DVec2::ZERO, 1.0),
+                Vec2::X * 3.0
+            ),
+            2.0
+        );
+    }
+
+    #[test]
+    fn closest_point() {
+        let circle = DBoundingCircle::new(DVec2::ZERO, 1.0);
+        assert_eq!(circle.closest_point(DVec2::X * 10.0), DVec2::X);
+        assert_eq!(circle.closest_point(DVec2::splat(0.5)), DVec2::splat(0.5));
+    }
+
+    #[test]
+    fn aabb_2d() {
+        let circle = DBoundingCircle::new(DVec2::ONE, 1.0);
+        assert_eq!(
+            circle.aabb_2d(),
+            DAabb2d {
+                min: DVec2::ZERO,
+                max: DVec2::splat(2.0)
+            }
+        );
+        assert!(circle.intersects(&circle.aabb_2d()));
+    }
+}
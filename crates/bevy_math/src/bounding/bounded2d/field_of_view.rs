@@ -0,0 +1,447 @@
+use super::{capsule::segment_intersects_aabb, Aabb2d, BoundingCircle, ContainsPoint2d};
+use crate::{
+    bounding::IntersectsVolume,
+    ops,
+    primitives::{CircularSector, Segment2d},
+    Dir2, FloatPow, Isometry2d, Rot2, Vec2,
+};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A sector-shaped field of view, for example the perception cone of an AI agent.
+///
+/// The field of view covers every point within `range` of the `origin`, and at most `half_angle`
+/// radians away from `direction` as seen from the origin. This is the same shape as a [`CircularSector`],
+/// placed at the origin and rotated to face the direction, which is what [`FieldOfView2d::sector`]
+/// and [`FieldOfView2d::isometry`] return.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_math::{bounding::{Aabb2d, BoundingCircle, FieldOfView2d, IntersectsVolume}, Dir2, Vec2};
+/// # use core::f32::consts::FRAC_PI_4;
+/// // A guard looking to the right, seeing 45 degrees to either side up to 10 units away
+/// let view = FieldOfView2d::new(Vec2::ZERO, Dir2::X, FRAC_PI_4, 10.0);
+///
+/// assert!(view.contains_point(Vec2::new(5.0, 1.0)));
+/// assert!(!view.contains_point(Vec2::new(-5.0, 1.0)));
+///
+/// // The circle is outside the cone, but close enough to its edge to be seen
+/// let target = BoundingCircle::new(Vec2::new(4.0, 5.0), 1.0);
+/// assert!(view.intersects(&target));
+///
+/// // The box is within the range, but behind the guard
+/// let wall = Aabb2d::new(Vec2::new(-3.0, 0.0), Vec2::new(0.5, 5.0));
+/// assert!(!view.intersects(&wall));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct FieldOfView2d {
+    /// The position the field of view is seen from
+    pub origin: Vec2,
+    /// The direction the field of view is centered on
+    pub direction: Dir2,
+    /// Half of the angle of the field of view in radians, between `0` and `π`.
+    ///
+    /// A half angle of `π` or more covers the whole circle around the origin.
+    pub half_angle: f32,
+    /// The distance from the origin up to which points are visible
+    pub range: f32,
+}
+
+impl FieldOfView2d {
+    /// Creates a new field of view seen from `origin`, centered on `direction`,
+    /// and covering `half_angle` radians to either side of it up to the given `range`.
+    #[inline(always)]
+    pub fn new(origin: Vec2, direction: Dir2, half_angle: f32, range: f32) -> Self {
+        Self {
+            origin,
+            direction,
+            half_angle,
+            range,
+        }
+    }
+
+    /// Returns the [`CircularSector`] covered by the field of view, in its local space.
+    ///
+    /// Transforming the sector by [`FieldOfView2d::isometry`] places it in the world.
+    #[inline(always)]
+    pub fn sector(&self) -> CircularSector {
+        CircularSector::from_radians(self.range, 2.0 * self.half_angle)
+    }
+
+    /// Returns the isometry placing the [sector](Self::sector) of the field of view in the world.
+    ///
+    /// The sector is symmetric around [`Vec2::Y`], so the isometry rotates it to `direction`.
+    #[inline(always)]
+    pub fn isometry(&self) -> Isometry2d {
+        Isometry2d::new(
+            self.origin,
+            Rot2::from_sin_cos(-self.direction.x, self.direction.y),
+        )
+    }
+
+    /// Returns the directions of the two edges bounding the field of view,
+    /// rotated clockwise and counterclockwise from `direction` by `half_angle`.
+    #[inline(always)]
+    pub fn edge_directions(&self) -> [Dir2; 2] {
+        let (sin, cos) = ops::sin_cos(self.half_angle);
+        [
+            Rot2::from_sin_cos(-sin, cos) * self.direction,
+            Rot2::from_sin_cos(sin, cos) * self.direction,
+        ]
+    }
+
+    /// Returns the two straight edges of the field of view,
+    /// from the origin to the ends of its arc.
+    #[inline(always)]
+    fn edges(&self) -> [Segment2d; 2] {
+        self.edge_directions()
+            .map(|direction| Segment2d::new(self.origin, self.origin + direction * self.range))
+    }
+
+    /// Checks if the direction from the origin to `point` is at most `half_angle` away from `direction`,
+    /// ignoring the range.
+    #[inline(always)]
+    fn within_angle(&self, point: Vec2) -> bool {
+        let offset = point - self.origin;
+        let forward = offset.dot(*self.direction);
+        let sideways = ops::abs(self.direction.perp_dot(offset));
+        ops::atan2(sideways, forward) <= self.half_angle
+    }
+
+    /// Checks if the given `point` is inside the field of view or on its boundary.
+    ///
+    /// The origin is always considered inside.
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.distance_squared(self.origin) <= self.range.squared() && self.within_angle(point)
+    }
+
+    /// Checks if the field of view overlaps the given `circle`.
+    ///
+    /// This is exact: the circle overlaps the field of view if its center is inside,
+    /// if it reaches the arc of the field of view, or if it reaches one of the straight edges.
+    pub fn intersects_circle(&self, circle: &BoundingCircle) -> bool {
+        let radius = circle.radius();
+        if circle.center.distance_squared(self.origin) > (self.range + radius).squared() {
+            return false;
+        }
+        // The center is within the angle and close enough to the origin,
+        // so it's either inside or within the radius of the arc
+        if self.within_angle(circle.center) {
+            return true;
+        }
+        // Otherwise, the closest point of the field of view is on one of its edges
+        self.edges().iter().any(|edge| {
+            edge.closest_point(circle.center)
+                .distance_squared(circle.center)
+                <= radius.squared()
+        })
+    }
+
+    /// Checks if the field of view overlaps the given `aabb`.
+    ///
+    /// This is exact: the AABB overlaps the field of view if one contains a point of the other,
+    /// or if the boundary of the AABB crosses one of the straight edges or the arc of the field of view.
+    pub fn intersects_aabb(&self, aabb: &Aabb2d) -> bool {
+        let closest = aabb.closest_point(self.origin);
+        if closest.distance_squared(self.origin) > self.range.squared() {
+            return false;
+        }
+        // Either shape may contain the other, in which case their boundaries don't cross
+        if closest == self.origin
+            || aabb
+                .corners()
+                .iter()
+                .any(|&corner| self.contains_point(corner))
+        {
+            return true;
+        }
+        if self
+            .edges()
+            .iter()
+            .any(|edge| segment_intersects_aabb(edge, aabb))
+        {
+            return true;
+        }
+
+        // The only remaining way to overlap is for a side of the AABB to cross the arc,
+        // at a point of the circle that is within the angle
+        let offset_min = aabb.min - self.origin;
+        let offset_max = aabb.max - self.origin;
+        (0..2).any(|axis| {
+            let other = 1 - axis;
+            [offset_min[axis], offset_max[axis]]
+                .into_iter()
+                .any(|side| {
+                    let half_chord_squared = self.range.squared() - side.squared();
+                    if half_chord_squared < 0.0 {
+                        return false;
+                    }
+                    let half_chord = ops::sqrt(half_chord_squared);
+                    [-half_chord, half_chord].into_iter().any(|along| {
+                        if along < offset_min[other] || along > offset_max[other] {
+                            return false;
+                        }
+                        let mut offset = Vec2::ZERO;
+                        offset[axis] = side;
+                        offset[other] = along;
+                        self.within_angle(self.origin + offset)
+                    })
+                })
+        })
+    }
+}
+
+impl ContainsPoint2d for FieldOfView2d {
+    #[inline(always)]
+    fn contains_point(&self, point: Vec2) -> bool {
+        FieldOfView2d::contains_point(self, point)
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for FieldOfView2d {
+    #[inline]
+    fn intersects(&self, volume: &Aabb2d) -> bool {
+        self.intersects_aabb(volume)
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for FieldOfView2d {
+    #[inline]
+    fn intersects(&self, volume: &BoundingCircle) -> bool {
+        self.intersects_circle(volume)
+    }
+}
+
+#[cfg(test)]
+mod field_of_view_tests {
+    use super::FieldOfView2d;
+    use crate::{
+        bounding::{Aabb2d, BoundingCircle, IntersectsVolume},
+        ops, Dir2, Vec2,
+    };
+    use core::f32::consts::{FRAC_PI_4, PI};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn contains_point() {
+        let view = FieldOfView2d::new(Vec2::new(1.0, 1.0), Dir2::Y, FRAC_PI_4, 5.0);
+        for (test, point, expected) in &[
+            ("the origin", Vec2::new(1.0, 1.0), true),
+            ("straight ahead", Vec2::new(1.0, 5.0), true),
+            ("beyond the range", Vec2::new(1.0, 6.5), false),
+            ("left of the cone", Vec2::new(-2.0, 2.0), false),
+            ("right of the cone", Vec2::new(4.0, 2.0), false),
+            ("inside near the edge", Vec2::new(3.0, 3.5), true),
+            ("behind", Vec2::new(1.0, 0.0), false),
+        ] {
+            assert_eq!(
+                view.contains_point(*point),
+                *expected,
+                "Case:\n  Test: {test:?}",
+            );
+        }
+
+        // A half angle of π or more covers the whole circle
+        let all_around = FieldOfView2d::new(Vec2::ZERO, Dir2::X, PI, 2.0);
+        assert!(all_around.contains_point(Vec2::new(-1.9, 0.0)));
+        assert!(!all_around.contains_point(Vec2::new(-2.1, 0.0)));
+    }
+
+    #[test]
+    fn sector_and_isometry() {
+        let view = FieldOfView2d::new(Vec2::new(3.0, -1.0), Dir2::X, FRAC_PI_4, 5.0);
+        let sector = view.sector();
+        assert_eq!(sector.radius(), 5.0);
+        assert_eq!(sector.half_angle(), FRAC_PI_4);
+
+        // The local Y axis of the sector points along the direction
+        let isometry = view.isometry();
+        assert!(isometry
+            .transform_point(Vec2::Y * 2.0)
+            .abs_diff_eq(Vec2::new(5.0, -1.0), 1e-6));
+
+        let [clockwise, counterclockwise] = view.edge_directions();
+        assert!(clockwise
+            .as_vec2()
+            .abs_diff_eq(Vec2::new(1.0, -1.0).normalize(), 1e-6));
+        assert!(counterclockwise
+            .as_vec2()
+            .abs_diff_eq(Vec2::new(1.0, 1.0).normalize(), 1e-6));
+    }
+
+    #[test]
+    fn intersects_circle() {
+        let view = FieldOfView2d::new(Vec2::ZERO, Dir2::Y, FRAC_PI_4, 10.0);
+        for (test, circle, expected) in &[
+            (
+                "center inside",
+                BoundingCircle::new(Vec2::new(0.0, 5.0), 0.5),
+                true,
+            ),
+            (
+                "containing the whole cone",
+                BoundingCircle::new(Vec2::new(0.0, 0.0), 20.0),
+                true,
+            ),
+            (
+                "crossing the arc",
+                BoundingCircle::new(Vec2::new(0.0, 10.5), 1.0),
+                true,
+            ),
+            (
+                "beyond the arc",
+                BoundingCircle::new(Vec2::new(0.0, 11.5), 1.0),
+                false,
+            ),
+            (
+                "crossing an edge",
+                BoundingCircle::new(Vec2::new(3.5, 2.5), 1.0),
+                true,
+            ),
+            (
+                "beside an edge",
+                BoundingCircle::new(Vec2::new(5.0, 2.5), 1.0),
+                false,
+            ),
+            (
+                "overlapping the apex from behind",
+                BoundingCircle::new(Vec2::new(0.0, -0.5), 1.0),
+                true,
+            ),
+            (
+                "behind the apex",
+                BoundingCircle::new(Vec2::new(0.0, -1.5), 1.0),
+                false,
+            ),
+            // Within the radius of the circle the arc lies on, but not of the arc itself
+            (
+                "near the end of the arc",
+                BoundingCircle::new(Vec2::new(8.5, 5.0), 1.0),
+                false,
+            ),
+        ] {
+            assert_eq!(
+                view.intersects(circle),
+                *expected,
+                "Case:\n  Test: {test:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn intersects_aabb() {
+        let view = FieldOfView2d::new(Vec2::ZERO, Dir2::Y, FRAC_PI_4, 10.0);
+        for (test, aabb, expected) in &[
+            (
+                "inside",
+                Aabb2d::new(Vec2::new(0.0, 5.0), Vec2::splat(0.5)),
+                true,
+            ),
+            (
+                "containing the apex",
+                Aabb2d::new(Vec2::new(0.0, -1.0), Vec2::splat(1.5)),
+                true,
+            ),
+            (
+                "containing the whole cone",
+                Aabb2d::new(Vec2::ZERO, Vec2::splat(20.0)),
+                true,
+            ),
+            (
+                "crossing an edge",
+                Aabb2d::new(Vec2::new(4.0, 3.0), Vec2::splat(1.0)),
+                true,
+            ),
+            // All corners are outside the cone
+            (
+                "across the cone",
+                Aabb2d::new(Vec2::new(0.0, 1.25), Vec2::new(3.0, 0.25)),
+                true,
+            ),
+            (
+                "beside an edge",
+                Aabb2d::new(Vec2::new(5.0, 2.0), Vec2::splat(1.0)),
+                false,
+            ),
+            (
+                "behind",
+                Aabb2d::new(Vec2::new(0.0, -3.0), Vec2::splat(1.0)),
+                false,
+            ),
+            // A thin box cutting through the arc, with all corners outside the cone
+            (
+                "crossing the arc",
+                Aabb2d::new(Vec2::new(0.0, 10.0), Vec2::new(20.0, 0.1)),
+                true,
+            ),
+            (
+                "beyond the arc",
+                Aabb2d::new(Vec2::new(0.0, 10.5), Vec2::new(20.0, 0.1)),
+                false,
+            ),
+            // Within the range, but beyond the end of the arc
+            (
+                "near the end of the arc",
+                Aabb2d::new(Vec2::new(8.5, 5.0), Vec2::splat(0.5)),
+                false,
+            ),
+        ] {
+            assert_eq!(view.intersects(aabb), *expected, "Case:\n  Test: {test:?}",);
+        }
+    }
+
+    #[test]
+    fn intersections_match_sampling() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        for _ in 0..200 {
+            let view = FieldOfView2d::new(
+                Vec2::new(rng.gen_range(-2.0..2.0), rng.gen_range(-2.0..2.0)),
+                Dir2::from_xy(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0))
+                    .unwrap_or(Dir2::X),
+                rng.gen_range(0.1..3.5),
+                rng.gen_range(1.0..5.0),
+            );
+            let center = Vec2::new(rng.gen_range(-8.0..8.0), rng.gen_range(-8.0..8.0));
+            let half_size = Vec2::new(rng.gen_range(0.1..2.0), rng.gen_range(0.1..2.0));
+            let aabb = Aabb2d::new(center, half_size);
+            let circle = BoundingCircle::new(center, half_size.x);
+
+            // Any sample of the volume inside the field of view proves an intersection
+            let mut aabb_sampled = false;
+            let mut circle_sampled = false;
+            for i in 0..40 {
+                for j in 0..40 {
+                    let t = Vec2::new(i as f32, j as f32) / 39.0;
+                    aabb_sampled |= view.contains_point(aabb.min + (aabb.max - aabb.min) * t);
+                    let (sin, cos) = ops::sin_cos(t.x * 2.0 * PI);
+                    let point = center + Vec2::new(cos, sin) * t.y * circle.radius();
+                    circle_sampled |= view.contains_point(point);
+                }
+            }
+            if aabb_sampled {
+                assert!(view.intersects(&aabb), "{view:?}\n  {aabb:?}");
+            }
+            if circle_sampled {
+                assert!(view.intersects(&circle), "{view:?}\n  {circle:?}");
+            }
+        }
+    }
+}
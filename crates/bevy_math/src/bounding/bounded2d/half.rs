@@ -0,0 +1,204 @@
+use super::{Aabb2d, BoundingCircle};
+use crate::Vec2;
+
+use crate::f16;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Converts a value to the largest `f16` that is less than or equal to it.
+///
+/// Values below the range of `f16` become negative infinity, and values above it become [`f16::MAX`].
+#[inline(always)]
+fn round_down(value: f64) -> f16 {
+    let rounded = f16::from_f64(value);
+    if rounded.to_f64() <= value || rounded.is_nan() {
+        rounded
+    } else if rounded == f16::ZERO {
+        // Both positive and negative zero step down to the smallest negative subnormal
+        f16::from_bits(0x8001)
+    } else if rounded.is_sign_positive() {
+        f16::from_bits(rounded.to_bits() - 1)
+    } else {
+        f16::from_bits(rounded.to_bits() + 1)
+    }
+}
+
+/// Converts a value to the smallest `f16` that is greater than or equal to it.
+///
+/// Values above the range of `f16` become infinity, and values below it become [`f16::MIN`].
+#[inline(always)]
+fn round_up(value: f64) -> f16 {
+    -round_down(-value)
+}
+
+/// A 2D axis-aligned bounding box stored in half precision, taking half of the memory of an [`Aabb2d`].
+///
+/// This is meant for storing large numbers of coarse bounds, for example in a broadphase.
+/// It has no intersection tests of its own: convert it to an [`Aabb2d`] to use it.
+///
+/// Converting an [`Aabb2d`] to a [`HAabb2d`] is lossy, but always conservative: the minimum
+/// is rounded down and the maximum is rounded up, so the result contains the original AABB.
+/// An [`f16`](struct@crate::f16) has 11 significant bits, so the AABB can grow by about 0.05% of the distance
+/// of its sides from the origin, and coordinates beyond 65504 become infinite.
+/// To keep the bounds tight, store them relative to a nearby origin, like the cell of a grid.
+///
+/// Converting back to an [`Aabb2d`] is exact.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_math::{bounding::{Aabb2d, HAabb2d}, Vec2};
+/// let aabb = Aabb2d::new(Vec2::new(10.1, -3.3), Vec2::new(0.7, 1.9));
+/// let compact = HAabb2d::from(aabb);
+///
+/// let widened = Aabb2d::from(compact);
+/// assert!(widened.min.cmple(aabb.min).all());
+/// assert!(widened.max.cmpge(aabb.max).all());
+/// ```
+#[doc(alias = "Aabb2dF16")]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+pub struct HAabb2d {
+    /// The minimum, conventionally bottom-left, point of the box
+    pub min: [f16; 2],
+    /// The maximum, conventionally top-right, point of the box
+    pub max: [f16; 2],
+}
+
+impl From<Aabb2d> for HAabb2d {
+    #[inline]
+    fn from(aabb: Aabb2d) -> Self {
+        Self {
+            min: [round_down(aabb.min.x.into()), round_down(aabb.min.y.into())],
+            max: [round_up(aabb.max.x.into()), round_up(aabb.max.y.into())],
+        }
+    }
+}
+
+impl From<HAabb2d> for Aabb2d {
+    #[inline]
+    fn from(aabb: HAabb2d) -> Self {
+        Aabb2d {
+            min: Vec2::new(aabb.min[0].to_f32(), aabb.min[1].to_f32()),
+            max: Vec2::new(aabb.max[0].to_f32(), aabb.max[1].to_f32()),
+        }
+    }
+}
+
+/// A bounding circle stored in half precision, taking half of the memory of a [`BoundingCircle`].
+///
+/// Like [`HAabb2d`], this is meant for compact storage, and has to be converted to a [`BoundingCircle`]
+/// to use it.
+///
+/// Converting a [`BoundingCircle`] to a [`HBoundingCircle`] is lossy, but always conservative:
+/// the center is rounded to the nearest representable point, and the radius grows
+/// by at least the distance the center moved, so the result contains the original circle.
+/// Converting back to a [`BoundingCircle`] is exact.
+#[doc(alias = "BoundingCircleF16")]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+pub struct HBoundingCircle {
+    /// The center of the bounding circle
+    pub center: [f16; 2],
+    /// The radius of the bounding circle
+    pub radius: f16,
+}
+
+impl From<BoundingCircle> for HBoundingCircle {
+    #[inline]
+    fn from(circle: BoundingCircle) -> Self {
+        let center = [
+            f16::from_f32(circle.center.x),
+            f16::from_f32(circle.center.y),
+        ];
+        // The distance the center moved is bounded by the sum of the offsets along both axes.
+        // These are exact in double precision, so rounding up the sum covers any error.
+        let offset_x = center[0].to_f64() - f64::from(circle.center.x);
+        let offset_y = center[1].to_f64() - f64::from(circle.center.y);
+        Self {
+            center,
+            radius: round_up(f64::from(circle.radius()) + offset_x.abs() + offset_y.abs()),
+        }
+    }
+}
+
+impl From<HBoundingCircle> for BoundingCircle {
+    #[inline]
+    fn from(circle: HBoundingCircle) -> Self {
+        BoundingCircle::new(
+            Vec2::new(circle.center[0].to_f32(), circle.center[1].to_f32()),
+            circle.radius.to_f32(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod half_tests {
+    use super::{round_down, round_up, HAabb2d, HBoundingCircle};
+    use crate::f16;
+    use crate::{
+        bounding::{Aabb2d, BoundingCircle, BoundingVolume},
+        Vec2,
+    };
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn directed_rounding() {
+        for value in [0.0, 1.0, -1.0, 0.1, -0.1, 1e-7, -1e-7, 1000.3, -65000.7] {
+            let down = round_down(value);
+            let up = round_up(value);
+            assert!(down.to_f64() <= value, "{value} rounded down to {down}");
+            assert!(up.to_f64() >= value, "{value} rounded up to {up}");
+            // The rounded values are adjacent, or equal if the value is representable
+            assert!(u16::abs_diff(up.to_bits(), down.to_bits()) <= 1);
+        }
+        assert_eq!(round_down(1.0), f16::ONE);
+        assert_eq!(round_up(1.0), f16::ONE);
+        assert_eq!(round_up(1e6), f16::INFINITY);
+        assert_eq!(round_down(1e6), f16::MAX);
+        assert_eq!(round_down(-1e6), f16::NEG_INFINITY);
+    }
+
+    #[test]
+    fn aabb_round_trip_is_conservative() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        for _ in 0..1000 {
+            let center = Vec2::new(rng.gen_range(-1e4..1e4), rng.gen_range(-1e4..1e4));
+            let half_size = Vec2::new(rng.gen_range(0.0..10.0), rng.gen_range(0.0..10.0));
+            let aabb = Aabb2d::new(center, half_size);
+            let widened = Aabb2d::from(HAabb2d::from(aabb));
+            assert!(widened.contains(&aabb), "{aabb:?} shrank to {widened:?}");
+            // The error is bounded by the precision of `f16`
+            let tolerance = aabb.min.abs().max(aabb.max.abs()) / 1024.0;
+            assert!((aabb.min - widened.min).cmple(tolerance).all());
+            assert!((widened.max - aabb.max).cmple(tolerance).all());
+        }
+
+        // Representable values are kept exactly
+        let aabb = Aabb2d::new(Vec2::new(0.5, -2.0), Vec2::new(1.0, 0.25));
+        assert_eq!(Aabb2d::from(HAabb2d::from(aabb)), aabb);
+    }
+
+    #[test]
+    fn circle_round_trip_is_conservative() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        for _ in 0..1000 {
+            let center = Vec2::new(rng.gen_range(-1e4..1e4), rng.gen_range(-1e4..1e4));
+            let circle = BoundingCircle::new(center, rng.gen_range(0.0..10.0));
+            let widened = BoundingCircle::from(HBoundingCircle::from(circle));
+            assert!(
+                widened.contains(&circle),
+                "{circle:?} shrank to {widened:?}"
+            );
+        }
+
+        let circle = BoundingCircle::new(Vec2::new(0.5, -2.0), 1.5);
+        assert_eq!(BoundingCircle::from(HBoundingCircle::from(circle)), circle);
+    }
+}
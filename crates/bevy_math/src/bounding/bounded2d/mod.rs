@@ -1,10 +1,20 @@
+mod capsule;
+#[cfg(feature = "alloc")]
+mod compound;
+mod double;
+mod field_of_view;
+#[cfg(feature = "half")]
+mod half;
+mod obb;
 mod primitive_impls;
+#[cfg(feature = "alloc")]
+mod region;
 
-use super::{BoundingVolume, IntersectsVolume};
+use super::{AabbCast2d, BoundingVolume, IntersectsVolume, RayCast2d, VolumeDistance};
 use crate::{
     ops,
-    prelude::{Mat2, Rot2, Vec2},
-    FloatPow, Isometry2d,
+    prelude::{Dir2, Mat2, Rot2, Vec2},
+    Affine2, BVec2, FloatPow, Isometry2d, Ray2d,
 };
 
 #[cfg(feature = "bevy_reflect")]
@@ -14,6 +24,17 @@ use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
+pub use capsule::BoundingCapsule2d;
+#[cfg(feature = "alloc")]
+pub use compound::{CompoundVolume2d, VolumePart2d};
+pub use double::{DAabb2d, DBoundingCircle};
+pub use field_of_view::FieldOfView2d;
+#[cfg(feature = "half")]
+pub use half::{HAabb2d, HBoundingCircle};
+pub use obb::Obb2d;
+#[cfg(feature = "alloc")]
+pub use region::{HalfPlaneRegion2d, RegionClassification};
+
 /// Computes the geometric center of the given set of points.
 #[inline(always)]
 fn point_cloud_2d_center(points: &[Vec2]) -> Vec2 {
@@ -26,17 +47,182 @@ fn point_cloud_2d_center(points: &[Vec2]) -> Vec2 {
     points.iter().fold(Vec2::ZERO, |acc, point| acc + *point) * denom
 }
 
+/// Computes the greatest common divisor of two integers.
+#[inline(always)]
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
 /// A trait with methods that return 2D bounding volumes for a shape.
+///
+/// The methods are generic over the isometry, so this trait is not dyn-compatible.
+/// To store shapes of different types together, use [`DynamicBounded2d`] instead,
+/// which is implemented for every type implementing this trait.
+///
+/// # Scaling
+///
+/// An [`Isometry2d`] can't represent scaling, so shapes with a scaled transform should be bounded
+/// with [`Bounded2d::aabb_2d_scaled`] and [`Bounded2d::bounding_circle_scaled`] instead.
+/// Like for a `Transform`, the scale is applied first, in the local space of the shape,
+/// followed by the rotation and translation of the isometry.
 pub trait Bounded2d {
     /// Get an axis-aligned bounding box for the shape translated and rotated by the given isometry.
     fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d;
     /// Get a bounding circle for the shape translated and rotated by the given isometry.
     fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle;
+
+    /// Get an axis-aligned bounding box for the shape scaled by `scale`,
+    /// and then translated and rotated by the given isometry.
+    ///
+    /// By default, this transforms the AABB of the untransformed shape with
+    /// [`Aabb2d::transformed_by_affine`]. The result always contains the shape,
+    /// but it is only tight if the shape is not rotated, or is itself a rectangle.
+    /// With a scale of [`Vec2::ONE`], prefer [`Bounded2d::aabb_2d`], which is tight for most shapes.
+    fn aabb_2d_scaled(&self, isometry: impl Into<Isometry2d>, scale: Vec2) -> Aabb2d {
+        let isometry = isometry.into();
+        let affine = Affine2::from_mat2_translation(
+            Mat2::from(isometry.rotation) * Mat2::from_diagonal(scale),
+            isometry.translation,
+        );
+        self.aabb_2d(Isometry2d::IDENTITY)
+            .transformed_by_affine(affine)
+    }
+
+    /// Get a bounding circle for the shape scaled by `scale`,
+    /// and then translated and rotated by the given isometry.
+    ///
+    /// By default, this scales the bounding circle of the untransformed shape by the largest
+    /// absolute component of `scale`, which always contains the shape, but is only tight
+    /// if the scale is uniform.
+    fn bounding_circle_scaled(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        scale: Vec2,
+    ) -> BoundingCircle {
+        let isometry = isometry.into();
+        let circle = self.bounding_circle(Isometry2d::IDENTITY);
+        BoundingCircle::new(
+            isometry * (circle.center * scale),
+            circle.radius() * scale.abs().max_element(),
+        )
+    }
+}
+
+/// A dyn-compatible version of [`Bounded2d`], for storing shapes of different types
+/// as trait objects like `Box<dyn DynamicBounded2d>`.
+///
+/// This is automatically implemented for every type implementing [`Bounded2d`],
+/// and `dyn DynamicBounded2d` implements [`Bounded2d`] in turn, so trait objects
+/// can be used like any other shape.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_math::{bounding::{Aabb2d, Bounded2d, BoundingCircle, DynamicBounded2d}, primitives::Circle, Isometry2d, Vec2};
+/// /// A custom shape: a cross made of two bars.
+/// struct Cross {
+///     half_length: f32,
+/// }
+///
+/// impl Bounded2d for Cross {
+///     fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+///         // The cross fits in a square, so its AABB is bounded by that of its bounding circle
+///         self.bounding_circle(isometry).aabb_2d()
+///     }
+///
+///     fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+///         BoundingCircle::new(isometry.into().translation, self.half_length)
+///     }
+/// }
+///
+/// let shapes: Vec<Box<dyn DynamicBounded2d>> = vec![
+///     Box::new(Circle::new(1.0)),
+///     Box::new(Cross { half_length: 2.0 }),
+/// ];
+/// for shape in &shapes {
+///     let aabb = shape.aabb_2d(Vec2::new(5.0, 0.0));
+///     assert!(aabb.min.x >= 3.0 && aabb.max.x <= 7.0);
+/// }
+/// ```
+pub trait DynamicBounded2d {
+    /// Get an axis-aligned bounding box for the shape translated and rotated by the given isometry.
+    ///
+    /// See [`Bounded2d::aabb_2d`].
+    fn dyn_aabb_2d(&self, isometry: Isometry2d) -> Aabb2d;
+    /// Get a bounding circle for the shape translated and rotated by the given isometry.
+    ///
+    /// See [`Bounded2d::bounding_circle`].
+    fn dyn_bounding_circle(&self, isometry: Isometry2d) -> BoundingCircle;
+}
+
+impl<T: Bounded2d> DynamicBounded2d for T {
+    #[inline]
+    fn dyn_aabb_2d(&self, isometry: Isometry2d) -> Aabb2d {
+        self.aabb_2d(isometry)
+    }
+
+    #[inline]
+    fn dyn_bounding_circle(&self, isometry: Isometry2d) -> BoundingCircle {
+        self.bounding_circle(isometry)
+    }
+}
+
+impl Bounded2d for dyn DynamicBounded2d + '_ {
+    #[inline]
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        self.dyn_aabb_2d(isometry.into())
+    }
+
+    #[inline]
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        self.dyn_bounding_circle(isometry.into())
+    }
+}
+
+/// A trait for 2D bounding volumes that can compute the signed distance to a point.
+///
+/// This is useful for sorting candidates by proximity before doing exact intersection tests,
+/// or for effects that fade in as shapes approach each other.
+pub trait SignedDistance2d {
+    /// Computes the exact Euclidean signed distance from the given `point` to the boundary of the volume.
+    ///
+    /// The result is negative if the point is inside the volume, zero if it is on the boundary,
+    /// and positive if it is outside.
+    fn signed_distance(&self, point: Vec2) -> f32;
+}
+
+/// A trait for 2D bounding volumes that can check if they contain a point.
+///
+/// This lets generic code test point membership, like picking with the cursor,
+/// without knowing the concrete type of the volume.
+pub trait ContainsPoint2d {
+    /// Checks if the given `point` is inside the volume or on its boundary.
+    fn contains_point(&self, point: Vec2) -> bool;
 }
 
 /// A 2D axis-aligned bounding box, or bounding rectangle
+///
+/// # Memory layout
+///
+/// The type is `#[repr(C)]`, and consists of four `f32`s in the order `min.x`, `min.y`, `max.x`, `max.y`,
+/// for a total size of 16 bytes. This matches the following WGSL struct:
+///
+/// ```wgsl
+/// struct Aabb2d {
+///     min: vec2<f32>,
+///     max: vec2<f32>,
+/// }
+/// ```
+///
+/// With the `bytemuck` feature, the type implements `bytemuck::Pod` and `bytemuck::Zeroable`,
+/// so that slices of it can be cast to bytes, for example to upload them to the GPU.
 #[doc(alias = "BoundingRectangle")]
+#[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[cfg_attr(
     feature = "bevy_reflect",
     derive(Reflect),
@@ -56,12 +242,77 @@ pub struct Aabb2d {
 
 impl Aabb2d {
     /// Constructs an AABB from its center and half-size.
+    ///
+    /// In debug builds, this panics if the half-size is negative or if any bound is NaN.
+    /// Infinite or overflowing bounds are allowed, since they are used for unbounded shapes
+    /// like [`Line2d`](crate::primitives::Line2d), even though [`Aabb2d::is_valid`] rejects them.
     #[inline(always)]
     pub fn new(center: Vec2, half_size: Vec2) -> Self {
-        debug_assert!(half_size.x >= 0.0 && half_size.y >= 0.0);
-        Self {
+        debug_assert!(
+            half_size.x >= 0.0 && half_size.y >= 0.0,
+            "the half-size of an `Aabb2d` must not be negative, got {half_size}"
+        );
+        let aabb = Self {
             min: center - half_size,
             max: center + half_size,
+        };
+        debug_assert!(
+            !aabb.min.is_nan() && !aabb.max.is_nan(),
+            "an `Aabb2d` must not have NaN bounds, got center {center} and half-size {half_size}"
+        );
+        aabb
+    }
+
+    /// Checks if the AABB is well-formed, meaning that its bounds are finite,
+    /// and that its minimum is not greater than its maximum on any axis.
+    ///
+    /// Intersection tests and the other operations on an invalid AABB give meaningless results,
+    /// so this is useful for catching bugs that produce negative extents or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::Aabb2d, Vec2};
+    /// assert!(Aabb2d::new(Vec2::ZERO, Vec2::ONE).is_valid());
+    ///
+    /// let inverted = Aabb2d { min: Vec2::ONE, max: Vec2::ZERO };
+    /// assert!(!inverted.is_valid());
+    /// let nan = Aabb2d { min: Vec2::ZERO, max: Vec2::new(f32::NAN, 1.0) };
+    /// assert!(!nan.is_valid());
+    /// ```
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        self.min.is_finite() && self.max.is_finite() && self.min.cmple(self.max).all()
+    }
+
+    /// Constructs an AABB from two opposite corners, such as its minimum and maximum.
+    ///
+    /// The corners may be given in any order. The minimum and maximum of the AABB are computed
+    /// on each axis, so swapped or inverted corners still produce a valid AABB.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::Aabb2d, Vec2};
+    /// const TILE: Aabb2d = Aabb2d::from_min_max(Vec2::new(16.0, 0.0), Vec2::new(0.0, 16.0));
+    /// assert_eq!(TILE.min, Vec2::new(0.0, 0.0));
+    /// assert_eq!(TILE.max, Vec2::new(16.0, 16.0));
+    /// ```
+    #[inline(always)]
+    pub const fn from_min_max(min: Vec2, max: Vec2) -> Self {
+        let (min_x, max_x) = if min.x <= max.x {
+            (min.x, max.x)
+        } else {
+            (max.x, min.x)
+        };
+        let (min_y, max_y) = if min.y <= max.y {
+            (min.y, max.y)
+        } else {
+            (max.y, min.y)
+        };
+        Self {
+            min: Vec2::new(min_x, min_y),
+            max: Vec2::new(max_x, max_y),
         }
     }
 
@@ -92,6 +343,26 @@ impl Aabb2d {
         }
     }
 
+    /// Computes the smallest [`Aabb2d`] containing the polyline through the given points,
+    /// transformed by the rotation and translation of the given isometry.
+    ///
+    /// Returns `None` if the set of points is empty.
+    ///
+    /// This is equivalent to [`Aabb2d::from_point_cloud`], since the segments
+    /// of a polyline never reach further than its vertices.
+    #[inline(always)]
+    pub fn from_polyline(isometry: impl Into<Isometry2d>, points: &[Vec2]) -> Option<Aabb2d> {
+        (!points.is_empty()).then(|| Aabb2d::from_point_cloud(isometry, points))
+    }
+
+    /// Computes the smallest [`Aabb2d`] containing all of the given AABBs.
+    ///
+    /// Returns `None` if the iterator is empty.
+    #[inline(always)]
+    pub fn from_volumes(volumes: impl IntoIterator<Item = Aabb2d>) -> Option<Aabb2d> {
+        volumes.into_iter().reduce(|acc, aabb| acc.merge(&aabb))
+    }
+
     /// Computes the smallest [`BoundingCircle`] containing this [`Aabb2d`].
     #[inline(always)]
     pub fn bounding_circle(&self) -> BoundingCircle {
@@ -102,12 +373,517 @@ impl Aabb2d {
     /// Finds the point on the AABB that is closest to the given `point`.
     ///
     /// If the point is outside the AABB, the returned point will be on the perimeter of the AABB.
-    /// Otherwise, it will be inside the AABB and returned as is.
+    /// Otherwise, it will be inside or on the perimeter of the AABB and returned as is.
+    ///
+    /// For an AABB with a zero half-size, this always returns its center.
     #[inline(always)]
     pub fn closest_point(&self, point: Vec2) -> Vec2 {
         // Clamp point coordinates to the AABB
         point.clamp(self.min, self.max)
     }
+
+    /// Clamps the given `point` to the AABB, returning the nearest point inside or on the AABB.
+    ///
+    /// This is the same as [`closest_point`](Self::closest_point), named for keeping positions
+    /// within an area, like keeping the player within the bounds of a level.
+    #[inline(always)]
+    pub fn clamp_point(&self, point: Vec2) -> Vec2 {
+        self.closest_point(point)
+    }
+
+    /// Wraps the given `point` around the AABB, so that a point leaving the AABB on one side
+    /// comes back in on the opposite side, like in a game of Asteroids.
+    ///
+    /// Points inside the AABB are returned as is, except that the maximum edges are treated as
+    /// the same as the minimum edges, so points on them wrap to the minimum edges.
+    /// Points that are several sizes of the AABB away wrap around as many times as needed.
+    /// On an axis along which the AABB has no size, the point is moved onto the AABB.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::Aabb2d, Vec2};
+    /// let play_area = Aabb2d::new(Vec2::ZERO, Vec2::new(10.0, 5.0));
+    /// assert_eq!(play_area.wrap_point(Vec2::new(11.0, 1.0)), Vec2::new(-9.0, 1.0));
+    /// assert_eq!(play_area.wrap_point(Vec2::new(0.0, -7.0)), Vec2::new(0.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn wrap_point(&self, point: Vec2) -> Vec2 {
+        let wrap = |value: f32, min: f32, max: f32| {
+            let size = max - min;
+            if size <= 0. {
+                return min;
+            }
+            let wrapped = min + ops::rem_euclid(value - min, size);
+            // Points just below the minimum can round up to the maximum
+            if wrapped >= max {
+                min
+            } else {
+                wrapped
+            }
+        };
+        Vec2::new(
+            wrap(point.x, self.min.x, self.max.x),
+            wrap(point.y, self.min.y, self.max.y),
+        )
+    }
+
+    /// Computes the area of the AABB.
+    #[inline(always)]
+    pub fn area(&self) -> f32 {
+        let size = self.max - self.min;
+        size.x * size.y
+    }
+
+    /// Computes the perimeter of the AABB.
+    #[inline(always)]
+    pub fn perimeter(&self) -> f32 {
+        let size = self.max - self.min;
+        2.0 * (size.x + size.y)
+    }
+
+    /// Transforms this AABB by the given isometry, first rotating it around the origin
+    /// and then translating it.
+    ///
+    /// This is equivalent to [`BoundingVolume::transformed_by`] with the translation and rotation
+    /// of the isometry, so the same caveats about the result growing with rotations apply.
+    /// It is useful for updating a bounding volume when only the transform of the shape it bounds changed,
+    /// without recomputing it from the shape itself.
+    #[inline(always)]
+    pub fn transformed_by_isometry(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        let isometry = isometry.into();
+        self.transformed_by(isometry.translation, isometry.rotation)
+    }
+
+    /// Transforms this AABB by the given affine transformation, computing the smallest AABB
+    /// enclosing the transformed box.
+    ///
+    /// Unlike [`BoundingVolume::transformed_by`], this also supports scaling, including
+    /// non-uniform and negative scaling, and shearing. Like for rotations, the result may not
+    /// fit the transformed shape as tightly as the original AABB fit the shape.
+    #[inline(always)]
+    pub fn transformed_by_affine(&self, affine: Affine2) -> Aabb2d {
+        // Each axis of the result is the sum of the extents of the transformed half-size along it
+        let half_size = affine.matrix2.abs() * self.half_size();
+        Self::new(affine.transform_point2(self.center()), half_size)
+    }
+
+    /// Expresses this AABB in the local frame of the given `origin`,
+    /// by transforming it by the inverse of the isometry.
+    ///
+    /// This is the counterpart of [`Aabb2d::transformed_by_isometry`], and like it,
+    /// the result is an AABB enclosing the rotated box, which may be larger than the original.
+    /// If `origin` is the identity, the AABB is returned unchanged.
+    #[inline(always)]
+    pub fn relative_to(&self, origin: &Isometry2d) -> Aabb2d {
+        if *origin == Isometry2d::IDENTITY {
+            return *self;
+        }
+        self.transformed_by_isometry(origin.inverse())
+    }
+
+    /// Checks if this AABB fully contains the given [`BoundingCircle`],
+    /// meaning that every point of the circle is inside or on the boundary of the AABB.
+    ///
+    /// To check if an AABB contains another AABB, use [`BoundingVolume::contains`].
+    #[inline(always)]
+    pub fn contains_circle(&self, circle: &BoundingCircle) -> bool {
+        // The extremal points of the circle along both axes must be within the slabs
+        let radius = circle.radius();
+        (circle.center - radius).cmpge(self.min).all()
+            && (circle.center + radius).cmple(self.max).all()
+    }
+
+    /// Checks if this AABB intersects the given AABB, including if they are only touching.
+    ///
+    /// This is the same test as the [`IntersectsVolume<Aabb2d>`] implementation,
+    /// but can be called in `const` contexts and without going through the trait.
+    #[inline(always)]
+    pub const fn intersects_aabb(&self, other: &Aabb2d) -> bool {
+        let x_overlaps = self.min.x <= other.max.x && self.max.x >= other.min.x;
+        let y_overlaps = self.min.y <= other.max.y && self.max.y >= other.min.y;
+        x_overlaps && y_overlaps
+    }
+
+    /// Checks if this AABB intersects the given AABB, treating them as intersecting
+    /// if the gap between them is at most `epsilon`.
+    ///
+    /// The exact [`intersects_aabb`](Self::intersects_aabb) test can flip between frames
+    /// for AABBs whose edges are touching, since their positions pick up rounding errors as they move.
+    /// With a tolerance, such grazing contacts consistently intersect, and only stop intersecting
+    /// once the AABBs have clearly separated.
+    /// A good default for `epsilon` is a small fraction of the size of the volumes,
+    /// like `1e-4 * aabb.half_size().max_element()`, so that the check is independent of their scale.
+    #[inline(always)]
+    pub fn intersects_eps(&self, other: &Aabb2d, epsilon: f32) -> bool {
+        debug_assert!(epsilon >= 0.0);
+        (self.min - epsilon).cmple(other.max).all() && (self.max + epsilon).cmpge(other.min).all()
+    }
+
+    /// Returns a copy of this AABB with `margin` added to each half-extent.
+    ///
+    /// This is useful for fattening volumes in a broad phase, so that they don't need to be
+    /// updated every time the object they bound moves a little.
+    #[inline(always)]
+    pub fn inflate(&self, margin: f32) -> Aabb2d {
+        debug_assert!(margin >= 0.0);
+        Aabb2d {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// Returns a copy of this AABB with `margin` subtracted from each half-extent.
+    ///
+    /// The half-extents are clamped at zero, so deflating past zero on an axis yields
+    /// a degenerate AABB that is flat on that axis, centered at the original center.
+    #[inline(always)]
+    pub fn deflate(&self, margin: f32) -> Aabb2d {
+        debug_assert!(margin >= 0.0);
+        let half_size = (self.half_size() - margin).max(Vec2::ZERO);
+        Aabb2d::new(self.center(), half_size)
+    }
+
+    /// Returns a copy of this AABB scaled by `factor` around the given `pivot`.
+    ///
+    /// Unlike [`scale_around_center`](BoundingVolume::scale_around_center), the offset of the AABB
+    /// from the pivot is scaled too, so the pivot stays at the same relative position within the AABB,
+    /// like when growing a trigger area around an anchor that is not at its center.
+    ///
+    /// A `factor` of zero collapses the AABB to the pivot. A negative `factor` mirrors the AABB
+    /// through the pivot, and scales it by the absolute value of the factor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::Aabb2d, Vec2};
+    /// let trigger = Aabb2d::new(Vec2::new(2.0, 1.0), Vec2::ONE);
+    /// let grown = trigger.scaled_around(Vec2::new(1.0, 0.0), 2.0);
+    /// assert_eq!(grown, Aabb2d::new(Vec2::new(3.0, 2.0), Vec2::splat(2.0)));
+    /// ```
+    #[inline(always)]
+    pub fn scaled_around(&self, pivot: Vec2, factor: f32) -> Aabb2d {
+        // A negative factor swaps the corners, which `from_min_max` sorts out
+        Aabb2d::from_min_max(
+            pivot + (self.min - pivot) * factor,
+            pivot + (self.max - pivot) * factor,
+        )
+    }
+
+    /// Grows this AABB in place to the smallest AABB containing both it and the given `point`.
+    ///
+    /// This is the incremental counterpart to [`Aabb2d::from_point_cloud`],
+    /// for building a bound from points that arrive one at a time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::Aabb2d, Vec2};
+    /// let mut aabb = Aabb2d::from_min_max(Vec2::ZERO, Vec2::ZERO);
+    /// for point in [Vec2::new(1.0, -2.0), Vec2::new(-3.0, 0.5)] {
+    ///     aabb.expand_to_include(point);
+    /// }
+    /// assert_eq!(aabb, Aabb2d::from_min_max(Vec2::new(-3.0, -2.0), Vec2::new(1.0, 0.5)));
+    /// ```
+    #[inline(always)]
+    pub fn expand_to_include(&mut self, point: Vec2) {
+        self.min = self.min.min(point);
+        self.max = self.max.max(point);
+    }
+
+    /// Returns the smallest AABB containing both this AABB and the given `point`.
+    ///
+    /// See [`Aabb2d::expand_to_include`] for the in-place version.
+    #[inline(always)]
+    #[must_use]
+    pub fn including(mut self, point: Vec2) -> Aabb2d {
+        self.expand_to_include(point);
+        self
+    }
+
+    /// Computes the minimum translation vector that pushes `self` out of `other`,
+    /// along the axis of least penetration.
+    ///
+    /// Returns `None` if the AABBs don't intersect. AABBs that are merely touching
+    /// produce a zero-length vector.
+    ///
+    /// If the penetration depth is equal on both axes, the X axis is used.
+    /// If the centers coincide on the chosen axis, `self` is pushed in the positive direction.
+    #[inline(always)]
+    pub fn overlap_mtv(&self, other: &Aabb2d) -> Option<Vec2> {
+        self.overlap_info(other).map(|info| info.mtv)
+    }
+
+    /// Computes the minimum translation vector that pushes `self` out of `other`,
+    /// along with the axis it is on and the penetration depth along that axis.
+    ///
+    /// This is the same as [`Aabb2d::overlap_mtv`], but also reports which axis the overlap
+    /// is resolved on, for example so that a character controller can stop its velocity on that axis.
+    ///
+    /// Returns `None` if the AABBs don't intersect. AABBs that are merely touching
+    /// produce a zero depth.
+    ///
+    /// If the penetration depth is equal on both axes, [`Axis2d::X`] is used.
+    /// If the centers coincide on the chosen axis, `self` is pushed in the positive direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::{Aabb2d, Axis2d}, Vec2};
+    /// let player = Aabb2d::new(Vec2::new(0.0, 0.9), Vec2::splat(0.5));
+    /// let ground = Aabb2d::new(Vec2::ZERO, Vec2::new(10.0, 0.5));
+    ///
+    /// let info = player.overlap_info(&ground).unwrap();
+    /// assert_eq!(info.axis, Axis2d::Y);
+    ///
+    /// // Push the player out of the ground, and stop it from falling further
+    /// let mut velocity = Vec2::new(2.0, -3.0);
+    /// velocity[info.axis.index()] = 0.0;
+    /// assert_eq!(velocity, Vec2::new(2.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn overlap_info(&self, other: &Aabb2d) -> Option<OverlapInfo2d> {
+        let offset = self.center() - other.center();
+        let penetration = (self.half_size() + other.half_size()) - offset.abs();
+
+        if penetration.x < 0.0 || penetration.y < 0.0 {
+            return None;
+        }
+
+        let axis = if penetration.x <= penetration.y {
+            Axis2d::X
+        } else {
+            Axis2d::Y
+        };
+        let index = axis.index();
+        let depth = penetration[index];
+        let mut mtv = Vec2::ZERO;
+        mtv[index] = ops::copysign(depth, offset[index]);
+        Some(OverlapInfo2d { mtv, axis, depth })
+    }
+
+    /// Computes the AABB of the region where this AABB and the given AABB overlap.
+    ///
+    /// Returns `None` if the AABBs don't intersect. Like [`Aabb2d::intersects_aabb`], AABBs that are
+    /// only touching are considered intersecting, and produce a degenerate AABB with zero area
+    /// along the shared edge or corner.
+    ///
+    /// This is the counterpart of [`BoundingVolume::merge`].
+    #[inline(always)]
+    pub fn intersection(&self, other: &Aabb2d) -> Option<Aabb2d> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        min.cmple(max).all().then_some(Aabb2d { min, max })
+    }
+
+    /// Computes the Minkowski sum of this AABB and the given AABB,
+    /// the set of all points `a + b` with `a` in `self` and `b` in `other`.
+    ///
+    /// The Minkowski sum of two AABBs is exactly an AABB, with the sum of their centers as its center
+    /// and the sum of their half-sizes as its half-size. Sweeping an AABB along a ray against another
+    /// AABB is equivalent to casting the ray against the Minkowski sum of the target and the negated swept AABB.
+    #[inline(always)]
+    pub fn minkowski_sum(&self, other: &Aabb2d) -> Aabb2d {
+        Aabb2d {
+            min: self.min + other.min,
+            max: self.max + other.max,
+        }
+    }
+
+    /// Get the distance at which the given ray hits this AABB, if it does within `max`.
+    ///
+    /// If `solid` is `true`, a ray starting inside the AABB hits it at a distance of `0.0`,
+    /// and otherwise it hits it where it exits the AABB, like a [`RayCast2d`] with the same settings.
+    ///
+    /// This is a shorthand for one-off casts. To cast the same ray against many volumes,
+    /// construct a [`RayCast2d`] once instead, which precomputes the inverse of the direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::Aabb2d, Dir2, Ray2d, Vec2};
+    /// let aabb = Aabb2d::new(Vec2::new(5.0, 0.0), Vec2::ONE);
+    /// let ray = Ray2d::new(Vec2::ZERO, Dir2::X);
+    /// assert_eq!(aabb.cast_ray(ray, 10.0, true), Some(4.0));
+    /// assert_eq!(aabb.cast_ray(ray, 3.0, true), None);
+    /// ```
+    #[inline]
+    pub fn cast_ray(&self, ray: Ray2d, max: f32, solid: bool) -> Option<f32> {
+        RayCast2d::from_ray(ray, max)
+            .with_solid(solid)
+            .aabb_intersection_at(self)
+    }
+
+    /// Computes the time of impact between this AABB moving with the velocity `vel_self`
+    /// and the `other` AABB moving with the velocity `vel_other`, if they collide within `max_t`.
+    ///
+    /// The motion is reduced to sweeping this AABB with the relative velocity against a static `other`,
+    /// which is cast as an [`AabbCast2d`]. The returned time is in the range `[0, max_t]`,
+    /// and the AABBs touch at `center + velocity * t` for both of them.
+    ///
+    /// If the AABBs already overlap at the start, the time of impact is `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::Aabb2d, Vec2};
+    /// let player = Aabb2d::new(Vec2::ZERO, Vec2::splat(0.5));
+    /// let wall = Aabb2d::new(Vec2::new(5.0, 0.0), Vec2::splat(0.5));
+    ///
+    /// // The gap of 4 units is closed after 2 seconds
+    /// let toi = player.sweep_against(Vec2::new(2.0, 0.0), &wall, Vec2::ZERO, 10.0);
+    /// assert_eq!(toi, Some(2.0));
+    /// ```
+    pub fn sweep_against(
+        &self,
+        vel_self: Vec2,
+        other: &Aabb2d,
+        vel_other: Vec2,
+        max_t: f32,
+    ) -> Option<f32> {
+        let Ok((direction, speed)) = Dir2::new_and_length(vel_self - vel_other) else {
+            // Without relative motion, the AABBs either overlap the whole time or never
+            return self.intersects(other).then_some(0.0);
+        };
+        AabbCast2d::new(*self, Vec2::ZERO, direction, max_t * speed)
+            .aabb_collision_at(*other)
+            .map(|distance| distance / speed)
+    }
+
+    /// Computes the area of the region where this AABB and the given AABB overlap.
+    ///
+    /// Returns `0.0` if the AABBs don't intersect or are only touching.
+    #[inline(always)]
+    pub fn overlap_area(&self, other: &Aabb2d) -> f32 {
+        self.intersection(other)
+            .map_or(0.0, |overlap| overlap.area())
+    }
+
+    /// Splits the AABB into four equally sized quadrants that share its center as a corner,
+    /// for example for building a quadtree.
+    ///
+    /// The quadrant at index `i` is in the positive half of the X axis if bit 0 of `i` is set,
+    /// and in the positive half of the Y axis if bit 1 is set. With Y pointing up,
+    /// the order is southwest, southeast, northwest, northeast.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::{Aabb2d, BoundingVolume}, Vec2};
+    /// let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+    /// let [southwest, southeast, northwest, northeast] = aabb.quadrants();
+    /// assert_eq!(southwest.center(), Vec2::new(-0.5, -0.5));
+    /// assert_eq!(northeast.center(), Vec2::new(0.5, 0.5));
+    /// ```
+    #[inline]
+    pub fn quadrants(&self) -> [Aabb2d; 4] {
+        let center = self.center();
+        core::array::from_fn(|i| {
+            let positive = BVec2::new(i & 1 != 0, i & 2 != 0);
+            Aabb2d {
+                min: Vec2::select(positive, center, self.min),
+                max: Vec2::select(positive, self.max, center),
+            }
+        })
+    }
+
+    /// Returns the four corners of the AABB in counterclockwise order, starting from [`Aabb2d::min`].
+    ///
+    /// With Y pointing up, the order is bottom-left, bottom-right, top-right, top-left,
+    /// matching [`Obb2d::corners`].
+    #[inline(always)]
+    pub fn corners(&self) -> [Vec2; 4] {
+        [
+            self.min,
+            Vec2::new(self.max.x, self.min.y),
+            self.max,
+            Vec2::new(self.min.x, self.max.y),
+        ]
+    }
+
+    /// Finds the corner of the AABB that is farthest along the given `direction`,
+    /// which is the support function of the AABB used by algorithms like GJK.
+    ///
+    /// On axes where the direction is zero, the maximum of the AABB is chosen.
+    /// The direction does not need to be normalized.
+    #[inline(always)]
+    pub fn support_point(&self, direction: Vec2) -> Vec2 {
+        Vec2::select(direction.cmplt(Vec2::ZERO), self.min, self.max)
+    }
+
+    /// Checks if this AABB and the given AABB are equal within `epsilon`,
+    /// meaning that none of their edges are more than `epsilon` apart.
+    ///
+    /// This is useful for skipping updates that depend on a bounding volume, like re-inserting it
+    /// into a broad phase, when the volume only moved or resized by a negligible amount.
+    /// A good default for `epsilon` is a small fraction of the size of the volume,
+    /// like `1e-4 * aabb.half_size().max_element()`, so that the check is independent of its scale.
+    #[inline(always)]
+    pub fn approx_eq(&self, other: &Aabb2d, epsilon: f32) -> bool {
+        self.min.abs_diff_eq(other.min, epsilon) && self.max.abs_diff_eq(other.max, epsilon)
+    }
+}
+
+/// One of the two coordinate axes in 2D.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Hash, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum Axis2d {
+    /// The X axis
+    X,
+    /// The Y axis
+    Y,
+}
+
+impl Axis2d {
+    /// Returns the index of the axis in a [`Vec2`], `0` for X and `1` for Y.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        match self {
+            Axis2d::X => 0,
+            Axis2d::Y => 1,
+        }
+    }
+
+    /// Returns the unit vector pointing in the positive direction of the axis.
+    #[inline(always)]
+    pub const fn unit_vector(self) -> Vec2 {
+        match self {
+            Axis2d::X => Vec2::X,
+            Axis2d::Y => Vec2::Y,
+        }
+    }
+}
+
+/// Information about how to resolve the overlap of two [`Aabb2d`]s, returned by [`Aabb2d::overlap_info`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct OverlapInfo2d {
+    /// The minimum translation vector, which moves the first AABB out of the second one.
+    ///
+    /// It is zero on every axis except [`axis`](Self::axis).
+    pub mtv: Vec2,
+    /// The axis the overlap is resolved on
+    pub axis: Axis2d,
+    /// The penetration depth along [`axis`](Self::axis), which is the length of the MTV
+    pub depth: f32,
 }
 
 impl BoundingVolume for Aabb2d {
@@ -249,12 +1025,28 @@ impl BoundingVolume for Aabb2d {
     }
 }
 
+impl SignedDistance2d for Aabb2d {
+    #[inline(always)]
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let d = (point - self.center()).abs() - self.half_size();
+        // The distance to the perimeter from the outside, and the (negative) distance from the inside.
+        let outside = d.max(Vec2::ZERO).length();
+        let inside = d.max_element().min(0.0);
+        outside + inside
+    }
+}
+
+impl ContainsPoint2d for Aabb2d {
+    #[inline(always)]
+    fn contains_point(&self, point: Vec2) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+}
+
 impl IntersectsVolume<Self> for Aabb2d {
     #[inline(always)]
     fn intersects(&self, other: &Self) -> bool {
-        let x_overlaps = self.min.x <= other.max.x && self.max.x >= other.min.x;
-        let y_overlaps = self.min.y <= other.max.y && self.max.y >= other.min.y;
-        x_overlaps && y_overlaps
+        self.intersects_aabb(other)
     }
 }
 
@@ -272,12 +1064,41 @@ impl IntersectsVolume<BoundingCircle> for Aabb2d {
 mod aabb2d_tests {
     use approx::assert_relative_eq;
 
-    use super::Aabb2d;
+    use super::{Aabb2d, Axis2d};
     use crate::{
-        bounding::{BoundingCircle, BoundingVolume, IntersectsVolume},
-        ops, Vec2,
+        bounding::{
+            Bounded2d, BoundingCapsule2d, BoundingCircle, BoundingVolume, ContainsPoint2d,
+            FieldOfView2d, IntersectsVolume, Obb2d, SignedDistance2d, VolumeDistance,
+        },
+        ops,
+        primitives::{Circle, Rectangle, Triangle2d},
+        Affine2, Dir2, Isometry2d, Ray2d, Rot2, Vec2,
     };
 
+    #[test]
+    fn from_min_max() {
+        let expected = Aabb2d {
+            min: Vec2::new(-1.0, 2.0),
+            max: Vec2::new(3.0, 4.0),
+        };
+        for (test, a, b) in &[
+            ("ordered", Vec2::new(-1.0, 2.0), Vec2::new(3.0, 4.0)),
+            ("swapped", Vec2::new(3.0, 4.0), Vec2::new(-1.0, 2.0)),
+            ("other corners", Vec2::new(-1.0, 4.0), Vec2::new(3.0, 2.0)),
+        ] {
+            assert_eq!(
+                Aabb2d::from_min_max(*a, *b),
+                expected,
+                "Case:\n  Test: {test:?}",
+            );
+        }
+
+        // A degenerate AABB
+        let point = Aabb2d::from_min_max(Vec2::ONE, Vec2::ONE);
+        assert_eq!(point.half_size(), Vec2::ZERO);
+        assert_eq!(point.center(), Vec2::ONE);
+    }
+
     #[test]
     fn center() {
         let aabb = Aabb2d {
@@ -334,6 +1155,32 @@ mod aabb2d_tests {
         assert!(a.contains(&b));
     }
 
+    #[test]
+    fn from_polyline() {
+        let points = [Vec2::new(0., 2.), Vec2::new(2., -2.), Vec2::new(4., 2.)];
+        let aabb = Aabb2d::from_polyline(Vec2::new(1., 0.), &points).unwrap();
+        assert_eq!(aabb.min, Vec2::new(1., -2.));
+        assert_eq!(aabb.max, Vec2::new(5., 2.));
+
+        let isometry = Isometry2d::new(Vec2::ZERO, Rot2::FRAC_PI_2);
+        let aabb = Aabb2d::from_polyline(isometry, &points).unwrap();
+        assert_relative_eq!(aabb.min, Vec2::new(-2., 0.), epsilon = 1e-6);
+        assert_relative_eq!(aabb.max, Vec2::new(2., 4.), epsilon = 1e-6);
+
+        assert_eq!(Aabb2d::from_polyline(Isometry2d::IDENTITY, &[]), None);
+    }
+
+    #[test]
+    fn contains_circle() {
+        let a = Aabb2d::new(Vec2::ZERO, Vec2::new(2., 1.));
+        assert!(a.contains_circle(&BoundingCircle::new(Vec2::ZERO, 1.)));
+        assert!(a.contains_circle(&BoundingCircle::new(Vec2::X, 1.)));
+        assert!(!a.contains_circle(&BoundingCircle::new(Vec2::X * 1.5, 1.)));
+        assert!(!a.contains_circle(&BoundingCircle::new(Vec2::ZERO, 1.5)));
+        // The circle doesn't reach the corners, but still sticks out of the bottom
+        assert!(!a.contains_circle(&BoundingCircle::new(Vec2::NEG_Y * 0.1, 1.)));
+    }
+
     #[test]
     fn merge() {
         let a = Aabb2d {
@@ -353,6 +1200,23 @@ mod aabb2d_tests {
         assert!(!b.contains(&merged));
     }
 
+    #[test]
+    fn from_volumes() {
+        assert_eq!(Aabb2d::from_volumes([]), None);
+
+        let a = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        assert_eq!(Aabb2d::from_volumes([a]), Some(a));
+
+        let volumes = [
+            a,
+            Aabb2d::new(Vec2::new(3.0, 1.0), Vec2::splat(0.5)),
+            Aabb2d::new(Vec2::new(-1.0, -4.0), Vec2::ONE),
+        ];
+        let merged = Aabb2d::from_volumes(volumes).unwrap();
+        assert_eq!(merged.min, Vec2::new(-2.0, -5.0));
+        assert_eq!(merged.max, Vec2::new(3.5, 1.5));
+    }
+
     #[test]
     fn grow() {
         let a = Aabb2d {
@@ -379,6 +1243,47 @@ mod aabb2d_tests {
         assert!(!shrunk.contains(&a));
     }
 
+    #[test]
+    fn inflate_deflate() {
+        let a = Aabb2d::new(Vec2::new(1., -1.), Vec2::new(2., 0.5));
+        let inflated = a.inflate(0.25);
+        assert_eq!(inflated.center(), a.center());
+        assert_eq!(inflated.half_size(), Vec2::new(2.25, 0.75));
+        assert_eq!(inflated.deflate(0.25), a);
+
+        // Deflating past zero on one axis flattens the box on that axis
+        let deflated = a.deflate(1.);
+        assert_eq!(deflated.center(), a.center());
+        assert_eq!(deflated.half_size(), Vec2::new(1., 0.));
+        let degenerate = a.deflate(10.);
+        assert_eq!(degenerate, Aabb2d::new(a.center(), Vec2::ZERO));
+    }
+
+    #[test]
+    fn expand_to_include() {
+        let points = [
+            Vec2::new(1., 2.),
+            Vec2::new(-3., 0.5),
+            Vec2::new(0., 0.),
+            Vec2::new(2., -1.),
+        ];
+        let mut aabb = Aabb2d::from_min_max(points[0], points[0]);
+        for point in &points[1..] {
+            aabb.expand_to_include(*point);
+        }
+        assert_eq!(
+            aabb,
+            Aabb2d::from_point_cloud(Isometry2d::IDENTITY, &points)
+        );
+
+        // Points that are already inside don't change the AABB
+        assert_eq!(aabb.including(Vec2::ZERO), aabb);
+        assert_eq!(
+            aabb.including(Vec2::new(5., 0.)),
+            Aabb2d::from_min_max(Vec2::new(-3., -1.), Vec2::new(5., 2.))
+        );
+    }
+
     #[test]
     fn scale_around_center() {
         let a = Aabb2d {
@@ -403,6 +1308,24 @@ mod aabb2d_tests {
         assert_relative_eq!(rotated.max, a.max);
     }
 
+    #[test]
+    fn rotation_direction() {
+        // The center of the AABB moves like a point rotated by `Rot2 * Vec2`
+        let a = Aabb2d::new(Vec2::new(3.0, 1.0), Vec2::new(1.0, 0.5));
+        let rotation = Rot2::degrees(30.0);
+        let translation = Vec2::new(-1.0, 2.0);
+        let isometry = Isometry2d::new(translation, rotation);
+        assert_relative_eq!(a.rotated_by(rotation).center(), rotation * a.center());
+        assert_relative_eq!(
+            a.transformed_by(translation, rotation).center(),
+            rotation * a.center() + translation
+        );
+        assert_relative_eq!(
+            a.transformed_by_isometry(isometry).center(),
+            isometry * a.center()
+        );
+    }
+
     #[test]
     fn transform() {
         let a = Aabb2d {
@@ -421,6 +1344,125 @@ mod aabb2d_tests {
         );
     }
 
+    #[test]
+    fn area_and_perimeter() {
+        let a = Aabb2d::new(Vec2::new(5.0, -1.0), Vec2::new(1.5, 0.5));
+        assert_eq!(a.area(), 3.0);
+        assert_eq!(a.area(), a.visible_area());
+        assert_eq!(a.perimeter(), 8.0);
+
+        let degenerate = Aabb2d::new(Vec2::ONE, Vec2::new(2.0, 0.0));
+        assert_eq!(degenerate.area(), 0.0);
+        assert_eq!(degenerate.perimeter(), 8.0);
+    }
+
+    #[test]
+    fn transformed_by_isometry() {
+        let a = Aabb2d::new(Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0));
+        let isometry = Isometry2d::new(Vec2::new(0.0, 3.0), Rot2::FRAC_PI_2);
+        let transformed = a.transformed_by_isometry(isometry);
+        // A quarter turn moves the center from (1, 0) to (0, 1), before translating it
+        assert_relative_eq!(
+            transformed.center(),
+            Vec2::new(0.0, 1.0) + isometry.translation
+        );
+        assert_relative_eq!(transformed.half_size(), Vec2::new(1.0, 2.0));
+
+        // The center moves the same way as the center of a bounding circle
+        let circle = BoundingCircle::new(a.center(), 1.0).transformed_by_isometry(isometry);
+        assert_relative_eq!(transformed.center(), circle.center);
+        assert_eq!(
+            transformed,
+            a.transformed_by(Vec2::new(0.0, 3.0), Rot2::FRAC_PI_2)
+        );
+        assert_eq!(
+            a.transformed_by_isometry(Vec2::new(1.0, -1.0)),
+            a.translated_by(Vec2::new(1.0, -1.0))
+        );
+    }
+
+    #[test]
+    fn bounded_scaled() {
+        let rectangle = Rectangle::new(2.0, 1.0);
+        let isometry = Isometry2d::new(Vec2::new(3.0, -1.0), Rot2::FRAC_PI_2);
+
+        // Scaling in local space before rotating swaps which axis is stretched
+        let aabb = rectangle.aabb_2d_scaled(isometry, Vec2::new(3.0, 1.0));
+        assert_relative_eq!(aabb.min, Vec2::new(2.5, -4.0), epsilon = 1e-6);
+        assert_relative_eq!(aabb.max, Vec2::new(3.5, 2.0), epsilon = 1e-6);
+
+        // A unit scale gives the same bounds as the unscaled methods
+        let aabb = rectangle.aabb_2d_scaled(isometry, Vec2::ONE);
+        let expected = rectangle.aabb_2d(isometry);
+        assert_relative_eq!(aabb.min, expected.min, epsilon = 1e-6);
+        assert_relative_eq!(aabb.max, expected.max, epsilon = 1e-6);
+        let circle = rectangle.bounding_circle_scaled(isometry, Vec2::ONE);
+        assert_relative_eq!(circle.center, rectangle.bounding_circle(isometry).center);
+        assert_relative_eq!(
+            circle.radius(),
+            rectangle.bounding_circle(isometry).radius()
+        );
+
+        // The bounding circle grows with the largest scale, and mirroring doesn't shrink it
+        let circle = Circle::new(1.0).bounding_circle_scaled(isometry, Vec2::new(-2.0, 0.5));
+        assert_relative_eq!(circle.center, Vec2::new(3.0, -1.0));
+        assert_relative_eq!(circle.radius(), 2.0);
+
+        // An off-center shape has its center scaled too
+        let triangle = Triangle2d::new(
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(1.0, 1.0),
+        );
+        let aabb = triangle.aabb_2d_scaled(Isometry2d::IDENTITY, Vec2::splat(2.0));
+        assert_relative_eq!(aabb.min, Vec2::new(2.0, 0.0));
+        assert_relative_eq!(aabb.max, Vec2::new(4.0, 2.0));
+    }
+
+    #[test]
+    fn transformed_by_affine() {
+        let a = Aabb2d::new(Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0));
+
+        // Without scaling, this matches transforming by an isometry
+        let isometry = Isometry2d::new(Vec2::new(0.0, 3.0), Rot2::radians(0.3));
+        let affine = Affine2::from_angle_translation(0.3, Vec2::new(0.0, 3.0));
+        let transformed = a.transformed_by_affine(affine);
+        let expected = a.transformed_by_isometry(isometry);
+        assert_relative_eq!(transformed.min, expected.min, epsilon = 1e-6);
+        assert_relative_eq!(transformed.max, expected.max, epsilon = 1e-6);
+
+        // Non-uniform and negative scaling
+        let affine = Affine2::from_scale(Vec2::new(3.0, -0.5));
+        let transformed = a.transformed_by_affine(affine);
+        assert_eq!(transformed.min, Vec2::new(-3.0, -0.5));
+        assert_eq!(transformed.max, Vec2::new(9.0, 0.5));
+
+        // The result is the smallest AABB enclosing the transformed corners
+        let affine = Affine2::from_scale_angle_translation(Vec2::new(2.0, 0.5), 1.0, Vec2::ONE);
+        let transformed = a.transformed_by_affine(affine);
+        let corners = a.corners().map(|corner| affine.transform_point2(corner));
+        let expected = Aabb2d::from_point_cloud(Isometry2d::IDENTITY, &corners);
+        assert_relative_eq!(transformed.min, expected.min, epsilon = 1e-5);
+        assert_relative_eq!(transformed.max, expected.max, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn relative_to() {
+        let a = Aabb2d::new(Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0));
+        assert_eq!(a.relative_to(&Isometry2d::IDENTITY), a);
+
+        // The parent frame is translated and rotated a quarter turn
+        let origin = Isometry2d::new(Vec2::new(0.0, 3.0), Rot2::FRAC_PI_2);
+        let relative = a.relative_to(&origin);
+        assert_relative_eq!(relative.center(), Vec2::new(-3.0, -1.0), epsilon = 1e-6);
+        assert_relative_eq!(relative.half_size(), Vec2::new(1.0, 2.0), epsilon = 1e-6);
+
+        // Transforming back into the parent frame restores the AABB
+        let restored = relative.transformed_by_isometry(origin);
+        assert_relative_eq!(restored.min, a.min, epsilon = 1e-6);
+        assert_relative_eq!(restored.max, a.max, epsilon = 1e-6);
+    }
+
     #[test]
     fn closest_point() {
         let aabb = Aabb2d {
@@ -435,6 +1477,21 @@ mod aabb2d_tests {
         );
     }
 
+    #[test]
+    fn closest_point_on_boundary() {
+        let aabb = Aabb2d::new(Vec2::new(0.3, -0.7), Vec2::new(1.1, 0.4));
+        for point in [aabb.min, aabb.max, Vec2::new(aabb.min.x, -0.5)] {
+            assert_eq!(aabb.closest_point(point), point);
+        }
+    }
+
+    #[test]
+    fn closest_point_degenerate() {
+        let aabb = Aabb2d::new(Vec2::new(2.0, -1.0), Vec2::ZERO);
+        assert_eq!(aabb.closest_point(Vec2::ZERO), aabb.center());
+        assert_eq!(aabb.closest_point(Vec2::splat(100.0)), aabb.center());
+    }
+
     #[test]
     fn intersect_aabb() {
         let aabb = Aabb2d {
@@ -456,6 +1513,248 @@ mod aabb2d_tests {
         }));
     }
 
+    #[test]
+    fn is_valid() {
+        assert!(Aabb2d::new(Vec2::ONE, Vec2::ZERO).is_valid());
+        assert!(!Aabb2d {
+            min: Vec2::new(0.0, 1.0),
+            max: Vec2::new(1.0, 0.0),
+        }
+        .is_valid());
+        assert!(!Aabb2d {
+            min: Vec2::ZERO,
+            max: Vec2::new(f32::INFINITY, 1.0),
+        }
+        .is_valid());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "must not be negative")]
+    fn new_with_negative_half_size() {
+        Aabb2d::new(Vec2::ZERO, Vec2::new(1.0, -1.0));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "must not have NaN bounds")]
+    fn new_with_nan() {
+        Aabb2d::new(Vec2::new(f32::NAN, 0.0), Vec2::ONE);
+    }
+
+    #[test]
+    fn distance() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        for (test, other, expected) in &[
+            (
+                "overlapping",
+                Aabb2d::new(Vec2::new(1.5, 0.0), Vec2::ONE),
+                0.0,
+            ),
+            ("touching", Aabb2d::new(Vec2::new(2.0, 0.0), Vec2::ONE), 0.0),
+            (
+                "apart on one axis",
+                Aabb2d::new(Vec2::new(0.5, -5.0), Vec2::ONE),
+                3.0,
+            ),
+            (
+                "apart on both axes",
+                Aabb2d::new(Vec2::new(-5.0, 6.0), Vec2::ONE),
+                5.0,
+            ),
+        ] {
+            assert_relative_eq!(aabb.distance(other), *expected);
+            assert_relative_eq!(other.distance(&aabb), *expected);
+            assert_relative_eq!(aabb.distance_squared(other), expected * expected);
+            assert_eq!(
+                *expected == 0.0,
+                aabb.intersects(other),
+                "Case:\n  Test: {test:?}"
+            );
+        }
+
+        // The distance to a circle is measured from the nearest point of the box
+        let circle = BoundingCircle::new(Vec2::new(4.0, 5.0), 1.0);
+        assert_relative_eq!(aabb.distance(&circle), 4.0);
+        assert_relative_eq!(circle.distance(&aabb), 4.0);
+        assert_relative_eq!(aabb.distance_squared(&circle), 16.0);
+        let circle = BoundingCircle::new(Vec2::new(1.5, 0.0), 1.0);
+        assert_eq!(aabb.distance(&circle), 0.0);
+    }
+
+    #[test]
+    fn contains_point() {
+        // Every volume type can be tested without knowing its concrete type
+        let volumes: [(&str, &dyn ContainsPoint2d); 5] = [
+            ("aabb", &Aabb2d::new(Vec2::ZERO, Vec2::new(2.0, 1.0))),
+            ("circle", &BoundingCircle::new(Vec2::ZERO, 1.0)),
+            (
+                "obb",
+                &Obb2d::new(Vec2::ZERO, Vec2::new(2.0, 1.0), Rot2::FRAC_PI_2),
+            ),
+            (
+                "capsule",
+                &BoundingCapsule2d::new(Vec2::NEG_X, Vec2::X, 1.0),
+            ),
+            (
+                "field of view",
+                &FieldOfView2d::new(Vec2::NEG_X, Dir2::X, core::f32::consts::FRAC_PI_4, 2.0),
+            ),
+        ];
+        for (test, point, expected) in [
+            ("the center", Vec2::ZERO, [true, true, true, true, true]),
+            (
+                "on the boundary",
+                Vec2::new(0.0, 1.0),
+                [true, true, true, true, true],
+            ),
+            (
+                "along the x axis",
+                Vec2::new(1.5, 0.0),
+                [true, false, false, true, false],
+            ),
+            (
+                "along the y axis",
+                Vec2::new(0.0, 1.5),
+                [false, false, true, false, false],
+            ),
+            ("far away", Vec2::splat(5.0), [false; 5]),
+        ] {
+            for ((volume_name, volume), expected) in volumes.iter().zip(expected) {
+                assert_eq!(
+                    volume.contains_point(point),
+                    expected,
+                    "Case:\n  Test: {test:?}\n  Volume: {volume_name}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn scaled_around() {
+        let aabb = Aabb2d::new(Vec2::new(2.0, 1.0), Vec2::ONE);
+        let pivot = Vec2::new(1.0, 0.0);
+        for (test, factor, expected) in &[
+            ("identity", 1.0, aabb),
+            (
+                "growing",
+                2.0,
+                Aabb2d::new(Vec2::new(3.0, 2.0), Vec2::splat(2.0)),
+            ),
+            (
+                "shrinking",
+                0.5,
+                Aabb2d::new(Vec2::new(1.5, 0.5), Vec2::splat(0.5)),
+            ),
+            ("collapsing", 0.0, Aabb2d::new(pivot, Vec2::ZERO)),
+            (
+                "mirroring",
+                -1.0,
+                Aabb2d::new(Vec2::new(0.0, -1.0), Vec2::ONE),
+            ),
+        ] {
+            let scaled = aabb.scaled_around(pivot, *factor);
+            assert_eq!(scaled, *expected, "Case:\n  Test: {test:?}");
+            assert!(
+                scaled.min.cmple(scaled.max).all(),
+                "Case:\n  Test: {test:?}"
+            );
+        }
+
+        // Scaling around the center is the same as scaling the half-size
+        assert_eq!(
+            aabb.scaled_around(aabb.center(), 3.0),
+            aabb.scale_around_center(Vec2::splat(3.0))
+        );
+    }
+
+    #[test]
+    fn clamp_and_wrap_point() {
+        let aabb = Aabb2d::new(Vec2::new(1.0, 2.0), Vec2::new(2.0, 1.0));
+        assert_eq!(aabb.clamp_point(Vec2::new(5.0, 0.0)), Vec2::new(3.0, 1.0));
+        assert_eq!(aabb.clamp_point(Vec2::new(0.5, 2.5)), Vec2::new(0.5, 2.5));
+
+        for (test, point, expected) in &[
+            ("inside", Vec2::new(0.5, 2.5), Vec2::new(0.5, 2.5)),
+            ("past the max x", Vec2::new(4.0, 2.0), Vec2::new(0.0, 2.0)),
+            ("past the min y", Vec2::new(1.0, 0.5), Vec2::new(1.0, 2.5)),
+            ("past a corner", Vec2::new(-1.5, 3.5), Vec2::new(2.5, 1.5)),
+            (
+                "several sizes away",
+                Vec2::new(13.0, -9.0),
+                Vec2::new(1.0, 1.0),
+            ),
+            ("on the max edge", Vec2::new(3.0, 3.0), Vec2::new(-1.0, 1.0)),
+            (
+                "on the min edge",
+                Vec2::new(-1.0, 1.0),
+                Vec2::new(-1.0, 1.0),
+            ),
+        ] {
+            let wrapped = aabb.wrap_point(*point);
+            assert!(
+                wrapped.abs_diff_eq(*expected, 1e-6),
+                "Case:\n  Test: {test:?}\n  Wrapped: {wrapped:?}",
+            );
+        }
+
+        // Wrapped points are always inside the AABB, even for tiny offsets
+        let wrapped = aabb.wrap_point(Vec2::new(-1.0 - 1e-7, 1.0 - 1e-7));
+        assert!(wrapped.cmpge(aabb.min).all() && wrapped.cmplt(aabb.max).all());
+
+        // A flat AABB moves points onto it
+        let flat = Aabb2d::new(Vec2::ZERO, Vec2::new(1.0, 0.0));
+        assert_eq!(flat.wrap_point(Vec2::new(1.5, 2.0)), Vec2::new(-0.5, 0.0));
+    }
+
+    #[test]
+    fn intersects_aabb_const() {
+        const AABB: Aabb2d = Aabb2d {
+            min: Vec2::NEG_ONE,
+            max: Vec2::ONE,
+        };
+        const TOUCHING: bool = AABB.intersects_aabb(&Aabb2d {
+            min: Vec2::new(1.0, -3.0),
+            max: Vec2::new(2.0, -1.0),
+        });
+        const DISJOINT: bool = AABB.intersects_aabb(&Aabb2d {
+            min: Vec2::new(-3.0, 1.1),
+            max: Vec2::new(3.0, 2.0),
+        });
+        const _: () = assert!(TOUCHING);
+        const _: () = assert!(!DISJOINT);
+    }
+
+    #[test]
+    fn intersects_eps() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        let epsilon = 1e-4;
+
+        // The other AABB jitters around touching the right edge, as if moved by a noisy simulation
+        for jitter in [-1e-6, 1e-6, -2e-7, 3e-6, 0.0, 5e-5] {
+            let other = Aabb2d::new(Vec2::new(2.0 + jitter, 0.5), Vec2::ONE);
+            assert!(aabb.intersects_eps(&other, epsilon), "jitter: {jitter}");
+        }
+        assert!(!aabb.intersects_eps(&Aabb2d::new(Vec2::new(2.001, 0.0), Vec2::ONE), epsilon));
+        assert!(!aabb.intersects_eps(&Aabb2d::new(Vec2::new(0.0, -2.001), Vec2::ONE), epsilon));
+
+        // A slowly separating pair stops intersecting once, and never goes back
+        let mut changes = 0;
+        let mut previous = true;
+        let mut position = 1.9;
+        while position < 2.1 {
+            let current =
+                aabb.intersects_eps(&Aabb2d::new(Vec2::new(position, 0.0), Vec2::ONE), epsilon);
+            if current != previous {
+                changes += 1;
+                previous = current;
+            }
+            position += 1e-5;
+        }
+        assert_eq!(changes, 1);
+        assert!(!previous);
+    }
+
     #[test]
     fn intersect_bounding_circle() {
         let aabb = Aabb2d {
@@ -467,12 +1766,451 @@ mod aabb2d_tests {
         assert!(aabb.intersects(&BoundingCircle::new(Vec2::NEG_ONE * 1.5, 1.0)));
         assert!(!aabb.intersects(&BoundingCircle::new(Vec2::ONE * 1.75, 1.0)));
     }
+
+    #[test]
+    fn signed_distance() {
+        let aabb = Aabb2d::new(Vec2::ONE, Vec2::new(2.0, 1.0));
+
+        // Outside, facing an edge and facing a corner
+        assert_relative_eq!(aabb.signed_distance(Vec2::new(5.0, 1.0)), 2.0);
+        assert_relative_eq!(
+            aabb.signed_distance(Vec2::new(6.0, 6.0)),
+            ops::hypot(3.0, 4.0)
+        );
+
+        // On the boundary
+        assert_relative_eq!(aabb.signed_distance(Vec2::new(3.0, 0.5)), 0.0);
+        assert_relative_eq!(aabb.signed_distance(aabb.max), 0.0);
+
+        // Inside, the distance to the nearest edge is not clamped to zero
+        assert_relative_eq!(aabb.signed_distance(Vec2::ONE), -1.0);
+        assert_relative_eq!(aabb.signed_distance(Vec2::new(2.5, 1.25)), -0.5);
+    }
+
+    #[test]
+    fn overlap_mtv() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+
+        // Least penetration along X, pushing towards positive X
+        let other = Aabb2d::new(Vec2::new(-1.5, 0.25), Vec2::ONE);
+        assert_eq!(aabb.overlap_mtv(&other), Some(Vec2::new(0.5, 0.0)));
+
+        // Least penetration along Y, pushing towards negative Y
+        let other = Aabb2d::new(Vec2::new(0.25, 1.75), Vec2::ONE);
+        assert_eq!(aabb.overlap_mtv(&other), Some(Vec2::new(0.0, -0.25)));
+
+        // Moving `self` by the MTV separates the boxes up to touching
+        let mtv = aabb.overlap_mtv(&other).unwrap();
+        let moved = aabb.translated_by(mtv);
+        assert_eq!(moved.overlap_mtv(&other), Some(Vec2::ZERO));
+
+        // Equal penetration on both axes picks X
+        let other = Aabb2d::new(Vec2::new(1.5, 1.5), Vec2::ONE);
+        assert_eq!(aabb.overlap_mtv(&other), Some(Vec2::new(-0.5, 0.0)));
+
+        // Disjoint boxes
+        let other = Aabb2d::new(Vec2::new(2.5, 0.0), Vec2::ONE);
+        assert_eq!(aabb.overlap_mtv(&other), None);
+    }
+
+    #[test]
+    fn overlap_info() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        for (test, other, expected) in &[
+            (
+                "least penetration along X",
+                Aabb2d::new(Vec2::new(-1.5, 0.25), Vec2::ONE),
+                Some((Vec2::new(0.5, 0.0), Axis2d::X, 0.5)),
+            ),
+            (
+                "least penetration along Y",
+                Aabb2d::new(Vec2::new(0.25, 1.75), Vec2::ONE),
+                Some((Vec2::new(0.0, -0.25), Axis2d::Y, 0.25)),
+            ),
+            (
+                "equal penetration on both axes",
+                Aabb2d::new(Vec2::new(1.5, 1.5), Vec2::ONE),
+                Some((Vec2::new(-0.5, 0.0), Axis2d::X, 0.5)),
+            ),
+            (
+                "coincident centers",
+                Aabb2d::new(Vec2::ZERO, Vec2::new(2.0, 0.5)),
+                Some((Vec2::new(0.0, 1.5), Axis2d::Y, 1.5)),
+            ),
+            (
+                "touching",
+                Aabb2d::new(Vec2::new(0.0, -2.0), Vec2::ONE),
+                Some((Vec2::ZERO, Axis2d::Y, 0.0)),
+            ),
+            (
+                "disjoint",
+                Aabb2d::new(Vec2::new(2.5, 0.0), Vec2::ONE),
+                None,
+            ),
+        ] {
+            let info = aabb.overlap_info(other);
+            assert_eq!(
+                info.map(|info| (info.mtv, info.axis, info.depth)),
+                *expected,
+                "Case:\n  Test: {test:?}",
+            );
+            assert_eq!(info.map(|info| info.mtv), aabb.overlap_mtv(other));
+            if let Some(info) = info {
+                assert_eq!(info.mtv.length(), info.depth);
+                // The MTV is along the reported axis
+                assert_eq!(info.mtv * info.axis.unit_vector(), info.mtv);
+            }
+        }
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Aabb2d {
+            min: Vec2::new(-1.0, -1.0),
+            max: Vec2::new(2.0, 1.0),
+        };
+        for (test, b, expected) in &[
+            (
+                "overlapping",
+                Aabb2d {
+                    min: Vec2::new(1.0, 0.0),
+                    max: Vec2::new(4.0, 3.0),
+                },
+                Some(Aabb2d {
+                    min: Vec2::new(1.0, 0.0),
+                    max: Vec2::new(2.0, 1.0),
+                }),
+            ),
+            (
+                "contained",
+                Aabb2d {
+                    min: Vec2::new(0.0, -0.5),
+                    max: Vec2::new(0.5, 0.5),
+                },
+                Some(Aabb2d {
+                    min: Vec2::new(0.0, -0.5),
+                    max: Vec2::new(0.5, 0.5),
+                }),
+            ),
+            (
+                "sharing an edge",
+                Aabb2d {
+                    min: Vec2::new(2.0, -3.0),
+                    max: Vec2::new(3.0, 0.0),
+                },
+                Some(Aabb2d {
+                    min: Vec2::new(2.0, -1.0),
+                    max: Vec2::new(2.0, 0.0),
+                }),
+            ),
+            (
+                "disjoint",
+                Aabb2d {
+                    min: Vec2::new(-3.0, 1.5),
+                    max: Vec2::new(3.0, 2.0),
+                },
+                None,
+            ),
+        ] {
+            let intersection = a.intersection(b);
+            assert_eq!(
+                intersection, *expected,
+                "Case:\n  Test: {test:?}\n  Intersection: {intersection:?}\n  Expected: {expected:?}",
+            );
+            assert_eq!(b.intersection(&a), intersection, "Case:\n  Test: {test:?}");
+            assert_eq!(
+                intersection.is_some(),
+                a.intersects(b),
+                "Case:\n  Test: {test:?}"
+            );
+            assert_eq!(
+                a.overlap_area(b),
+                expected.map_or(0.0, |overlap| overlap.area()),
+                "Case:\n  Test: {test:?}",
+            );
+        }
+        assert_eq!(a.overlap_area(&a), a.area());
+    }
+
+    #[test]
+    fn corners() {
+        let aabb = Aabb2d {
+            min: Vec2::new(-2.0, 1.0),
+            max: Vec2::new(3.0, 4.0),
+        };
+        let corners = aabb.corners();
+        assert_eq!(
+            corners,
+            [
+                Vec2::new(-2.0, 1.0),
+                Vec2::new(3.0, 1.0),
+                Vec2::new(3.0, 4.0),
+                Vec2::new(-2.0, 4.0),
+            ]
+        );
+
+        // The corners wind counterclockwise, like those of an `Obb2d` without rotation
+        let signed_area: f32 = (0..4)
+            .map(|i| corners[i].perp_dot(corners[(i + 1) % 4]))
+            .sum();
+        assert_eq!(signed_area / 2.0, aabb.area());
+        assert_eq!(
+            corners,
+            Obb2d::new(aabb.center(), aabb.half_size(), Rot2::IDENTITY).corners()
+        );
+    }
+
+    #[test]
+    fn support_point() {
+        let aabb = Aabb2d {
+            min: Vec2::new(-2.0, 1.0),
+            max: Vec2::new(3.0, 4.0),
+        };
+        assert_eq!(aabb.support_point(Vec2::new(1.0, 1.0)), aabb.max);
+        assert_eq!(aabb.support_point(Vec2::new(-1.0, -0.1)), aabb.min);
+        assert_eq!(
+            aabb.support_point(Vec2::new(5.0, -2.0)),
+            Vec2::new(3.0, 1.0)
+        );
+        assert_eq!(aabb.support_point(Vec2::ZERO), aabb.max);
+
+        // The support point is the farthest corner along the direction
+        for direction in [Vec2::X, Vec2::new(-3.0, 1.0), Vec2::new(0.5, -7.0)] {
+            let support = aabb.support_point(direction);
+            for corner in aabb.corners() {
+                assert!(corner.dot(direction) <= support.dot(direction));
+            }
+        }
+    }
+
+    #[test]
+    fn cast_ray() {
+        let aabb = Aabb2d::new(Vec2::new(5.0, 0.0), Vec2::ONE);
+        let ray = Ray2d::new(Vec2::ZERO, Dir2::X);
+        assert_eq!(aabb.cast_ray(ray, 10.0, true), Some(4.0));
+        assert_eq!(aabb.cast_ray(ray, 10.0, false), Some(4.0));
+        assert_eq!(aabb.cast_ray(ray, 3.0, true), None);
+        assert_eq!(
+            aabb.cast_ray(Ray2d::new(Vec2::ZERO, Dir2::NEG_X), 10.0, true),
+            None
+        );
+
+        // Starting inside
+        let inside = Ray2d::new(Vec2::new(5.5, 0.0), Dir2::X);
+        assert_eq!(aabb.cast_ray(inside, 10.0, true), Some(0.0));
+        assert_eq!(aabb.cast_ray(inside, 10.0, false), Some(0.5));
+    }
+
+    #[test]
+    fn sweep_against() {
+        let player = Aabb2d::new(Vec2::ZERO, Vec2::splat(0.5));
+        let wall = Aabb2d::new(Vec2::new(5.0, 0.0), Vec2::splat(0.5));
+        for (test, vel_self, vel_other, max_t, expected) in &[
+            (
+                "moving into a static wall",
+                Vec2::X * 2.0,
+                Vec2::ZERO,
+                10.0,
+                Some(2.0),
+            ),
+            (
+                "moving towards each other",
+                Vec2::X,
+                -Vec2::X,
+                10.0,
+                Some(2.0),
+            ),
+            (
+                "the wall moving into the player",
+                Vec2::ZERO,
+                -Vec2::X * 4.0,
+                10.0,
+                Some(1.0),
+            ),
+            ("too slow to reach the wall", Vec2::X, Vec2::ZERO, 3.0, None),
+            ("moving away", -Vec2::X, Vec2::ZERO, 10.0, None),
+            ("moving in parallel", Vec2::X, Vec2::X, 10.0, None),
+            (
+                "passing above the wall",
+                Vec2::new(1.0, 1.0),
+                Vec2::ZERO,
+                10.0,
+                None,
+            ),
+            (
+                "hitting the wall diagonally",
+                Vec2::new(2.0, 0.4),
+                Vec2::ZERO,
+                10.0,
+                Some(2.0),
+            ),
+        ] {
+            let toi = player.sweep_against(*vel_self, &wall, *vel_other, *max_t);
+            assert_eq!(
+                toi, *expected,
+                "Case:\n  Test: {test:?}\n  Time of impact: {toi:?}\n  Expected: {expected:?}",
+            );
+        }
+
+        // The AABBs touch at the time of impact
+        let (vel_self, vel_other) = (Vec2::new(3.0, 0.5), Vec2::new(-1.0, 0.0));
+        let toi = player
+            .sweep_against(vel_self, &wall, vel_other, 10.0)
+            .unwrap();
+        let moved_player = Aabb2d::new(player.center() + vel_self * toi, player.half_size());
+        let moved_wall = Aabb2d::new(wall.center() + vel_other * toi, wall.half_size());
+        assert_relative_eq!(moved_player.max.x, moved_wall.min.x);
+
+        // Overlapping AABBs collide immediately, with or without relative motion
+        let overlapping = Aabb2d::new(Vec2::new(0.5, 0.5), Vec2::splat(0.5));
+        assert_eq!(
+            player.sweep_against(Vec2::X, &overlapping, Vec2::ZERO, 1.0),
+            Some(0.0)
+        );
+        assert_eq!(
+            player.sweep_against(Vec2::X, &overlapping, Vec2::X, 1.0),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn minkowski_sum() {
+        let a = Aabb2d::new(Vec2::new(1.0, 2.0), Vec2::new(2.0, 1.0));
+        let b = Aabb2d::new(Vec2::new(-3.0, 1.0), Vec2::new(0.5, 0.5));
+        let sum = a.minkowski_sum(&b);
+        assert_eq!(sum.center(), Vec2::new(-2.0, 3.0));
+        assert_eq!(sum.half_size(), Vec2::new(2.5, 1.5));
+        assert_eq!(sum, b.minkowski_sum(&a));
+
+        // The AABBs intersect exactly when the Minkowski sum of one and the negation of the other contains the origin
+        let negated = Aabb2d {
+            min: -b.max,
+            max: -b.min,
+        };
+        let difference = a.minkowski_sum(&negated);
+        assert!(!a.intersects(&b));
+        assert!(!difference.intersects(&Aabb2d::new(Vec2::ZERO, Vec2::ZERO)));
+        let moved = Aabb2d::new(Vec2::new(-1.0, 1.0), Vec2::new(0.5, 0.5));
+        assert!(a.intersects(&moved));
+        assert!(a
+            .minkowski_sum(&Aabb2d {
+                min: -moved.max,
+                max: -moved.min,
+            })
+            .intersects(&Aabb2d::new(Vec2::ZERO, Vec2::ZERO)));
+    }
+
+    #[test]
+    fn approx_eq() {
+        let aabb = Aabb2d::new(Vec2::new(10.0, 5.0), Vec2::new(2.0, 1.0));
+        for (test, other, expected) in &[
+            ("identical", aabb, true),
+            (
+                "moved within epsilon",
+                Aabb2d::new(Vec2::new(10.0005, 4.9995), Vec2::new(2.0, 1.0)),
+                true,
+            ),
+            (
+                "resized within epsilon",
+                Aabb2d::new(Vec2::new(10.0, 5.0), Vec2::new(2.0009, 1.0)),
+                true,
+            ),
+            (
+                "moved beyond epsilon",
+                Aabb2d::new(Vec2::new(10.0, 5.002), Vec2::new(2.0, 1.0)),
+                false,
+            ),
+            (
+                "one edge beyond epsilon",
+                Aabb2d {
+                    min: aabb.min,
+                    max: aabb.max + Vec2::new(0.0, 0.002),
+                },
+                false,
+            ),
+        ] {
+            assert_eq!(
+                aabb.approx_eq(other, 0.001),
+                *expected,
+                "Case:\n  Test: {test:?}\n  Other: {other:?}",
+            );
+            assert_eq!(other.approx_eq(&aabb, 0.001), *expected);
+        }
+    }
+
+    #[test]
+    fn quadrants() {
+        let aabb = Aabb2d {
+            min: Vec2::new(-2.0, 1.0),
+            max: Vec2::new(2.0, 3.0),
+        };
+        let quadrants = aabb.quadrants();
+        for (i, quadrant) in quadrants.iter().enumerate() {
+            assert_eq!(quadrant.half_size(), aabb.half_size() / 2.0);
+            assert!(aabb.contains(quadrant));
+            // One of the corners of each quadrant is the center of the parent
+            let corner = Vec2::new(
+                if i & 1 == 0 {
+                    quadrant.max.x
+                } else {
+                    quadrant.min.x
+                },
+                if i & 2 == 0 {
+                    quadrant.max.y
+                } else {
+                    quadrant.min.y
+                },
+            );
+            assert_eq!(corner, aabb.center());
+        }
+        assert_eq!(quadrants[0].min, aabb.min);
+        assert_eq!(quadrants[1].center(), Vec2::new(1.0, 1.5));
+        assert_eq!(quadrants[2].center(), Vec2::new(-1.0, 2.5));
+        assert_eq!(quadrants[3].max, aabb.max);
+        assert_eq!(Aabb2d::from_volumes(quadrants), Some(aabb));
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_layout() {
+        let aabbs = [
+            Aabb2d {
+                min: Vec2::new(1.0, 2.0),
+                max: Vec2::new(3.0, 4.0),
+            },
+            Aabb2d {
+                min: Vec2::new(5.0, 6.0),
+                max: Vec2::new(7.0, 8.0),
+            },
+        ];
+        let floats: &[f32] = bytemuck::cast_slice(&aabbs);
+        assert_eq!(floats, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(bytemuck::cast_slice::<f32, Aabb2d>(floats), aabbs);
+    }
 }
 
 use crate::primitives::Circle;
 
 /// A bounding circle
+///
+/// # Memory layout
+///
+/// The type is `#[repr(C)]`, and consists of three `f32`s in the order `center.x`, `center.y`, `radius`,
+/// for a total size of 12 bytes. Note that WGSL aligns a struct with a `vec2<f32>` field to 8 bytes,
+/// so an array of the WGSL struct below has a stride of 16 bytes instead of 12:
+///
+/// ```wgsl
+/// struct BoundingCircle {
+///     center: vec2<f32>,
+///     radius: f32,
+/// }
+/// ```
+///
+/// With the `bytemuck` feature, the type implements `bytemuck::Pod` and `bytemuck::Zeroable`,
+/// so that slices of it can be cast to bytes, for example to upload them to the GPU.
+#[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[cfg_attr(
     feature = "bevy_reflect",
     derive(Reflect),
@@ -492,15 +2230,34 @@ pub struct BoundingCircle {
 
 impl BoundingCircle {
     /// Constructs a bounding circle from its center and radius.
+    ///
+    /// In debug builds, this panics if the radius is negative or NaN, or if the center is NaN.
+    /// Infinite radii are allowed, since they come up when bounding unbounded shapes
+    /// like [`Line2d`](crate::primitives::Line2d), even though [`BoundingCircle::is_valid`] rejects them.
+    /// Such a circle contains every point, so its center may also be NaN.
     #[inline(always)]
     pub fn new(center: Vec2, radius: f32) -> Self {
-        debug_assert!(radius >= 0.);
+        debug_assert!(
+            radius >= 0. && (!center.is_nan() || radius == f32::INFINITY),
+            "a `BoundingCircle` must have a center that is not NaN and a non-negative radius, \
+            got center {center} and radius {radius}"
+        );
         Self {
             center,
             circle: Circle { radius },
         }
     }
 
+    /// Checks if the bounding circle is well-formed, meaning that its center is finite,
+    /// and that its radius is finite and not negative.
+    ///
+    /// Intersection tests and the other operations on an invalid bounding circle give meaningless results,
+    /// so this is useful for catching bugs that produce negative radii or NaN.
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        self.center.is_finite() && self.radius().is_finite() && self.radius() >= 0.
+    }
+
     /// Computes a [`BoundingCircle`] containing the given set of points,
     /// transformed by the rotation and translation of the given isometry.
     ///
@@ -523,12 +2280,249 @@ impl BoundingCircle {
         BoundingCircle::new(isometry * center, ops::sqrt(radius_squared))
     }
 
+    /// Computes a [`BoundingCircle`] containing the polyline through the given points,
+    /// transformed by the rotation and translation of the given isometry.
+    ///
+    /// Returns `None` if the set of points is empty. Like for [`BoundingCircle::from_point_cloud`],
+    /// the bounding circle is not guaranteed to be the smallest possible.
+    #[inline(always)]
+    pub fn from_polyline(
+        isometry: impl Into<Isometry2d>,
+        points: &[Vec2],
+    ) -> Option<BoundingCircle> {
+        (!points.is_empty()).then(|| BoundingCircle::from_point_cloud(isometry, points))
+    }
+
+    /// Computes the smallest [`BoundingCircle`] containing the given set of points,
+    /// using Welzl's algorithm.
+    ///
+    /// Returns `None` if the set of points is empty, and a circle with a radius of zero
+    /// if it contains a single point.
+    ///
+    /// The algorithm runs in expected linear time when the points are visited in a random order.
+    /// Since the points can't be shuffled without allocating, they are instead visited in a fixed,
+    /// scrambled order that steps through them by a stride of about 0.618 times their count.
+    /// This keeps sorted input, like the vertices of a polygon or points ordered by their
+    /// coordinates, from hitting the worst case, and makes the result deterministic.
+    /// Inputs crafted against this order can still take time cubic in the number of points.
+    #[inline]
+    pub fn enclosing(points: &[Vec2]) -> Option<BoundingCircle> {
+        if points.is_empty() {
+            return None;
+        }
+
+        // Any stride coprime with the number of points visits each of them exactly once
+        let len = points.len();
+        let mut stride = (len as f64 * 0.618_034) as usize | 1;
+        while gcd(stride, len) != 1 {
+            stride += 2;
+        }
+        let point = |k: usize| points[((k as u64 * stride as u64) % len as u64) as usize];
+
+        // Allow a small relative tolerance, so that points on the boundary of a circle computed
+        // from them are not rejected because of rounding errors.
+        let contains = |circle: &BoundingCircle, point: Vec2| {
+            point.distance(circle.center) <= circle.radius() * (1.0 + 1e-5) + f32::EPSILON
+        };
+
+        let mut circle = BoundingCircle::new(point(0), 0.0);
+        for i in 1..len {
+            let p = point(i);
+            if contains(&circle, p) {
+                continue;
+            }
+            // `p` is on the boundary of the smallest circle enclosing the points so far
+            circle = BoundingCircle::new(p, 0.0);
+            for j in 0..i {
+                let q = point(j);
+                if contains(&circle, q) {
+                    continue;
+                }
+                // Both `p` and `q` are on the boundary
+                circle = BoundingCircle::new((p + q) / 2.0, p.distance(q) / 2.0);
+                for r in (0..j).map(point) {
+                    if !contains(&circle, r) {
+                        // `p`, `q`, and `r` are all on the boundary
+                        circle = Self::circumcircle(p, q, r);
+                    }
+                }
+            }
+        }
+
+        Some(circle)
+    }
+
+    /// Computes the circle passing through three points,
+    /// or the smallest circle containing them if they are collinear.
+    #[inline(always)]
+    fn circumcircle(a: Vec2, b: Vec2, c: Vec2) -> BoundingCircle {
+        let ab = b - a;
+        let ac = c - a;
+        let denom = 2.0 * ab.perp_dot(ac);
+
+        if denom == 0.0 {
+            // The points are collinear, so the two that are farthest apart span the circle
+            let (p, q) = [(a, b), (a, c), (b, c)]
+                .into_iter()
+                .max_by(|(p1, q1), (p2, q2)| {
+                    p1.distance_squared(*q1)
+                        .total_cmp(&p2.distance_squared(*q2))
+                })
+                .unwrap();
+            return BoundingCircle::new((p + q) / 2.0, p.distance(q) / 2.0);
+        }
+
+        let offset = Vec2::new(
+            ac.y * ab.length_squared() - ab.y * ac.length_squared(),
+            ab.x * ac.length_squared() - ac.x * ab.length_squared(),
+        ) / denom;
+        BoundingCircle::new(a + offset, offset.length())
+    }
+
+    /// Computes a [`BoundingCircle`] containing all of the given bounding circles,
+    /// by merging them pairwise in order.
+    ///
+    /// The bounding circle is not guaranteed to be the smallest possible.
+    ///
+    /// Returns `None` if the iterator is empty.
+    #[inline(always)]
+    pub fn from_volumes(
+        volumes: impl IntoIterator<Item = BoundingCircle>,
+    ) -> Option<BoundingCircle> {
+        volumes.into_iter().reduce(|acc, circle| acc.merge(&circle))
+    }
+
     /// Get the radius of the bounding circle
     #[inline(always)]
     pub fn radius(&self) -> f32 {
         self.circle.radius
     }
 
+    /// Checks if this bounding circle intersects the given bounding circle,
+    /// including if they are only touching.
+    ///
+    /// This is the same test as the [`IntersectsVolume<BoundingCircle>`] implementation,
+    /// but can be called in `const` contexts and without going through the trait.
+    #[inline(always)]
+    pub const fn intersects_circle(&self, other: &BoundingCircle) -> bool {
+        let dx = self.center.x - other.center.x;
+        let dy = self.center.y - other.center.y;
+        let radius_sum = self.circle.radius + other.circle.radius;
+        dx * dx + dy * dy <= radius_sum * radius_sum
+    }
+
+    /// Checks if this bounding circle intersects the given bounding circle, treating them as intersecting
+    /// if the gap between them is at most `epsilon`.
+    ///
+    /// The exact [`intersects_circle`](Self::intersects_circle) test can flip between frames
+    /// for circles that are touching, since their positions pick up rounding errors as they move.
+    /// With a tolerance, such grazing contacts consistently intersect, and only stop intersecting
+    /// once the circles have clearly separated.
+    /// A good default for `epsilon` is a small fraction of the size of the volumes,
+    /// like `1e-4 * circle.radius()`, so that the check is independent of their scale.
+    #[inline(always)]
+    pub fn intersects_eps(&self, other: &BoundingCircle, epsilon: f32) -> bool {
+        debug_assert!(epsilon >= 0.0);
+        let radius_sum = self.radius() + other.radius() + epsilon;
+        self.center.distance_squared(other.center) <= radius_sum.squared()
+    }
+
+    /// Computes the area of the bounding circle.
+    #[inline(always)]
+    pub fn area(&self) -> f32 {
+        core::f32::consts::PI * self.radius().squared()
+    }
+
+    /// Computes the circumference of the bounding circle.
+    #[inline(always)]
+    pub fn circumference(&self) -> f32 {
+        core::f32::consts::TAU * self.radius()
+    }
+
+    /// Transforms this bounding circle by the given isometry, first rotating it around the origin
+    /// and then translating it.
+    ///
+    /// This is equivalent to [`BoundingVolume::transformed_by`] with the translation and rotation
+    /// of the isometry. The radius is unaffected.
+    #[inline(always)]
+    pub fn transformed_by_isometry(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        BoundingCircle::new(isometry.into() * self.center, self.radius())
+    }
+
+    /// Checks if this bounding circle fully contains the given [`Aabb2d`],
+    /// meaning that every point of the AABB is inside or on the boundary of the circle.
+    ///
+    /// To check if a bounding circle contains another bounding circle, use [`BoundingVolume::contains`].
+    #[inline(always)]
+    pub fn contains_aabb(&self, aabb: &Aabb2d) -> bool {
+        // The corner of the AABB farthest from the center of the circle must be within the circle
+        let farthest_corner = (aabb.center() - self.center).abs() + aabb.half_size();
+        farthest_corner.length_squared() <= self.radius().squared()
+    }
+
+    /// Returns a copy of this bounding circle with `margin` added to its radius.
+    ///
+    /// This is useful for fattening volumes in a broad phase, so that they don't need to be
+    /// updated every time the object they bound moves a little.
+    #[inline(always)]
+    pub fn inflate(&self, margin: f32) -> BoundingCircle {
+        debug_assert!(margin >= 0.0);
+        BoundingCircle::new(self.center, self.radius() + margin)
+    }
+
+    /// Returns a copy of this bounding circle with `margin` subtracted from its radius.
+    ///
+    /// The radius is clamped at zero, so deflating past zero yields a degenerate circle
+    /// with a radius of zero, centered at the original center.
+    #[inline(always)]
+    pub fn deflate(&self, margin: f32) -> BoundingCircle {
+        debug_assert!(margin >= 0.0);
+        BoundingCircle::new(self.center, (self.radius() - margin).max(0.0))
+    }
+
+    /// Returns a copy of this bounding circle scaled by `factor` around the given `pivot`.
+    ///
+    /// Unlike [`scale_around_center`](BoundingVolume::scale_around_center), the offset of the center
+    /// from the pivot is scaled too, so the pivot stays at the same relative position within the circle.
+    ///
+    /// A `factor` of zero collapses the circle to the pivot. A negative `factor` mirrors the circle
+    /// through the pivot, and scales its radius by the absolute value of the factor.
+    #[inline(always)]
+    pub fn scaled_around(&self, pivot: Vec2, factor: f32) -> BoundingCircle {
+        BoundingCircle::new(
+            pivot + (self.center - pivot) * factor,
+            self.radius() * ops::abs(factor),
+        )
+    }
+
+    /// Grows this bounding circle in place to the smallest circle containing both it and the given `point`.
+    ///
+    /// If the point is outside the circle, the center moves towards it, so that the opposite side
+    /// of the circle stays in place. Unlike [`BoundingCircle::enclosing`], growing a circle
+    /// one point at a time is not guaranteed to find the smallest circle containing all of the points.
+    #[inline]
+    pub fn expand_to_include(&mut self, point: Vec2) {
+        let offset = point - self.center;
+        let distance = offset.length();
+        let radius = self.radius();
+        if distance <= radius {
+            return;
+        }
+        let new_radius = (radius + distance) / 2.0;
+        self.center += offset * ((new_radius - radius) / distance);
+        self.circle.radius = new_radius;
+    }
+
+    /// Returns the smallest bounding circle containing both this circle and the given `point`.
+    ///
+    /// See [`BoundingCircle::expand_to_include`] for the in-place version.
+    #[inline]
+    #[must_use]
+    pub fn including(mut self, point: Vec2) -> BoundingCircle {
+        self.expand_to_include(point);
+        self
+    }
+
     /// Computes the smallest [`Aabb2d`] containing this [`BoundingCircle`].
     #[inline(always)]
     pub fn aabb_2d(&self) -> Aabb2d {
@@ -538,13 +2532,98 @@ impl BoundingCircle {
         }
     }
 
+    /// Computes the Minkowski sum of this bounding circle and the given bounding circle,
+    /// the set of all points `a + b` with `a` in `self` and `b` in `other`.
+    ///
+    /// The Minkowski sum of two circles is exactly a circle, with the sum of their centers as its center
+    /// and the sum of their radii as its radius. Sweeping a circle along a ray against another circle
+    /// is equivalent to casting the ray against the Minkowski sum of the target and the negated swept circle.
+    #[inline(always)]
+    pub fn minkowski_sum(&self, other: &BoundingCircle) -> BoundingCircle {
+        BoundingCircle::new(self.center + other.center, self.radius() + other.radius())
+    }
+
+    /// Finds the point on the perimeter of the bounding circle that is farthest along the given `direction`,
+    /// which is the support function of the circle used by algorithms like GJK.
+    ///
+    /// The direction does not need to be normalized. If it is zero, the center of the circle is returned.
+    #[inline(always)]
+    pub fn support_point(&self, direction: Vec2) -> Vec2 {
+        self.center + direction.normalize_or_zero() * self.radius()
+    }
+
+    /// Checks if this bounding circle and the given bounding circle are equal within `epsilon`,
+    /// meaning that no point on the perimeter of one is more than `epsilon` away from the perimeter of the other.
+    ///
+    /// This is the case when the distance between the centers plus the difference between the radii
+    /// is at most `epsilon`, and is consistent with [`Aabb2d::approx_eq`] for the edges of an AABB.
+    /// A good default for `epsilon` is a small fraction of the size of the volume,
+    /// like `1e-4 * circle.radius()`, so that the check is independent of its scale.
+    #[inline(always)]
+    pub fn approx_eq(&self, other: &BoundingCircle, epsilon: f32) -> bool {
+        self.center.distance(other.center) + ops::abs(self.radius() - other.radius()) <= epsilon
+    }
+
     /// Finds the point on the bounding circle that is closest to the given `point`.
     ///
     /// If the point is outside the circle, the returned point will be on the perimeter of the circle.
-    /// Otherwise, it will be inside the circle and returned as is.
+    /// Otherwise, it will be inside or on the perimeter of the circle and returned as is.
     #[inline(always)]
     pub fn closest_point(&self, point: Vec2) -> Vec2 {
-        self.circle.closest_point(point - self.center) + self.center
+        let offset = point - self.center;
+        let distance_squared = offset.length_squared();
+
+        if distance_squared <= self.radius().squared() {
+            // Return the point itself rather than round-tripping it through the offset,
+            // so that points on the perimeter are returned exactly.
+            point
+        } else {
+            self.center + self.radius() * (offset / ops::sqrt(distance_squared))
+        }
+    }
+
+    /// Computes the two points where the lines through `from` touch the circle tangentially.
+    ///
+    /// The first point is counterclockwise from the direction of `from` as seen from the center,
+    /// and the second one is clockwise. The tangent lines from `from` through these points
+    /// bound the region shadowed by the circle, for example for a light at `from`.
+    ///
+    /// Returns `None` if `from` is inside the circle. If `from` is exactly on the boundary,
+    /// the only tangent point is `from` itself, and it is returned twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::BoundingCircle, Vec2};
+    /// let circle = BoundingCircle::new(Vec2::ZERO, 1.0);
+    /// let (left, right) = circle.tangent_points(Vec2::new(2.0, 0.0)).unwrap();
+    ///
+    /// // The tangent lines are perpendicular to the radius at the tangent points
+    /// assert!((Vec2::new(2.0, 0.0) - left).dot(left).abs() < 1e-6);
+    /// assert!(left.y > 0.0 && right.y < 0.0);
+    ///
+    /// assert_eq!(circle.tangent_points(Vec2::new(0.5, 0.0)), None);
+    /// ```
+    #[inline]
+    pub fn tangent_points(&self, from: Vec2) -> Option<(Vec2, Vec2)> {
+        let offset = from - self.center;
+        let distance_squared = offset.length_squared();
+        let radius_squared = self.radius().squared();
+
+        if distance_squared < radius_squared {
+            return None;
+        }
+        if distance_squared == radius_squared {
+            return Some((from, from));
+        }
+
+        // The radius to a tangent point is perpendicular to the tangent line, so it's at an angle
+        // from the offset whose cosine is `radius / distance`. Scaling by the distance once more
+        // gives the components along the offset and its perpendicular directly.
+        let along = offset * (radius_squared / distance_squared);
+        let across = offset.perp()
+            * (self.radius() * ops::sqrt(distance_squared - radius_squared) / distance_squared);
+        Some((self.center + along + across, self.center + along - across))
     }
 }
 
@@ -625,12 +2704,72 @@ impl BoundingVolume for BoundingCircle {
     }
 }
 
+impl SignedDistance2d for BoundingCircle {
+    #[inline(always)]
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        point.distance(self.center) - self.radius()
+    }
+}
+
+impl VolumeDistance<Self> for Aabb2d {
+    #[inline(always)]
+    fn distance_squared(&self, other: &Self) -> f32 {
+        // The gap between the boxes on each axis, or zero if they overlap on it
+        let gap = (other.min - self.max)
+            .max(self.min - other.max)
+            .max(Vec2::ZERO);
+        gap.length_squared()
+    }
+}
+
+impl VolumeDistance<BoundingCircle> for Aabb2d {
+    #[inline(always)]
+    fn distance_squared(&self, circle: &BoundingCircle) -> f32 {
+        self.distance(circle).squared()
+    }
+
+    #[inline(always)]
+    fn distance(&self, circle: &BoundingCircle) -> f32 {
+        let closest_point = self.closest_point(circle.center);
+        (circle.center.distance(closest_point) - circle.radius()).max(0.0)
+    }
+}
+
+impl VolumeDistance<Self> for BoundingCircle {
+    #[inline(always)]
+    fn distance_squared(&self, other: &Self) -> f32 {
+        self.distance(other).squared()
+    }
+
+    #[inline(always)]
+    fn distance(&self, other: &Self) -> f32 {
+        (self.center.distance(other.center) - self.radius() - other.radius()).max(0.0)
+    }
+}
+
+impl VolumeDistance<Aabb2d> for BoundingCircle {
+    #[inline(always)]
+    fn distance_squared(&self, aabb: &Aabb2d) -> f32 {
+        aabb.distance_squared(self)
+    }
+
+    #[inline(always)]
+    fn distance(&self, aabb: &Aabb2d) -> f32 {
+        aabb.distance(self)
+    }
+}
+
+impl ContainsPoint2d for BoundingCircle {
+    #[inline(always)]
+    fn contains_point(&self, point: Vec2) -> bool {
+        self.center.distance_squared(point) <= self.radius().squared()
+    }
+}
+
 impl IntersectsVolume<Self> for BoundingCircle {
     #[inline(always)]
     fn intersects(&self, other: &Self) -> bool {
-        let center_distance_squared = self.center.distance_squared(other.center);
-        let radius_sum_squared = (self.radius() + other.radius()).squared();
-        center_distance_squared <= radius_sum_squared
+        self.intersects_circle(other)
     }
 }
 
@@ -643,12 +2782,34 @@ impl IntersectsVolume<Aabb2d> for BoundingCircle {
 
 #[cfg(test)]
 mod bounding_circle_tests {
+    use approx::assert_relative_eq;
+
     use super::BoundingCircle;
     use crate::{
-        bounding::{BoundingVolume, IntersectsVolume},
-        ops, Vec2,
+        bounding::{Aabb2d, BoundingVolume, IntersectsVolume, SignedDistance2d, VolumeDistance},
+        ops,
+        primitives::Circle,
+        Isometry2d, Rot2, Vec2,
     };
 
+    #[test]
+    fn from_polyline() {
+        let points = [Vec2::new(0., 2.), Vec2::new(2., -2.), Vec2::new(4., 2.)];
+        let circle = BoundingCircle::from_polyline(Vec2::new(1., 0.), &points).unwrap();
+        for point in points {
+            assert!(circle.center.distance(point + Vec2::X) <= circle.radius() + 1e-6);
+        }
+        assert_eq!(
+            circle,
+            BoundingCircle::from_point_cloud(Vec2::new(1., 0.), &points)
+        );
+
+        assert_eq!(
+            BoundingCircle::from_polyline(Isometry2d::IDENTITY, &[]),
+            None
+        );
+    }
+
     #[test]
     fn area() {
         let circle = BoundingCircle::new(Vec2::ONE, 5.);
@@ -665,6 +2826,50 @@ mod bounding_circle_tests {
         assert!(a.contains(&b));
     }
 
+    #[test]
+    fn support_point() {
+        let circle = BoundingCircle::new(Vec2::new(1.0, 2.0), 2.0);
+        assert_relative_eq!(circle.support_point(Vec2::X * 5.0), Vec2::new(3.0, 2.0));
+        assert_relative_eq!(
+            circle.support_point(Vec2::new(-1.0, -1.0)),
+            circle.center - Vec2::splat(core::f32::consts::SQRT_2)
+        );
+        assert_eq!(circle.support_point(Vec2::ZERO), circle.center);
+    }
+
+    #[test]
+    fn minkowski_sum() {
+        let a = BoundingCircle::new(Vec2::new(1.0, 2.0), 2.0);
+        let b = BoundingCircle::new(Vec2::new(-3.0, 1.0), 0.5);
+        let sum = a.minkowski_sum(&b);
+        assert_eq!(sum.center, Vec2::new(-2.0, 3.0));
+        assert_eq!(sum.radius(), 2.5);
+        assert_eq!(sum, b.minkowski_sum(&a));
+    }
+
+    #[test]
+    fn approx_eq() {
+        let circle = BoundingCircle::new(Vec2::new(10.0, 5.0), 2.0);
+        assert!(circle.approx_eq(&circle, 0.0));
+        assert!(circle.approx_eq(&BoundingCircle::new(Vec2::new(10.0005, 5.0), 2.0005), 0.001));
+        assert!(!circle.approx_eq(&BoundingCircle::new(Vec2::new(10.0, 5.0), 2.002), 0.001));
+        assert!(!circle.approx_eq(&BoundingCircle::new(Vec2::new(10.002, 5.0), 2.0), 0.001));
+        // The center and radius both moving within epsilon can still move the perimeter too far
+        assert!(!circle.approx_eq(&BoundingCircle::new(Vec2::new(10.0008, 5.0), 2.0008), 0.001));
+    }
+
+    #[test]
+    fn contains_aabb() {
+        let a = BoundingCircle::new(Vec2::ONE, 5.);
+        // A rectangle inscribed in the circle touches it at the corners
+        assert!(a.contains_aabb(&Aabb2d::new(Vec2::ONE, Vec2::new(3., 4.))));
+        assert!(!a.contains_aabb(&Aabb2d::new(Vec2::ONE, Vec2::new(3.01, 4.))));
+        // The extents fit within the circle, but the corners don't
+        assert!(!a.contains_aabb(&Aabb2d::new(Vec2::ONE, Vec2::splat(4.))));
+        assert!(a.contains_aabb(&Aabb2d::new(Vec2::new(4., 1.), Vec2::new(1., 2.))));
+        assert!(!a.contains_aabb(&Aabb2d::new(Vec2::new(5., 1.), Vec2::new(1., 2.))));
+    }
+
     #[test]
     fn contains_identical() {
         let a = BoundingCircle::new(Vec2::ONE, 5.);
@@ -699,6 +2904,103 @@ mod bounding_circle_tests {
         assert_eq!(merged.radius(), b.radius());
     }
 
+    #[test]
+    fn from_volumes() {
+        assert_eq!(BoundingCircle::from_volumes([]), None);
+
+        let a = BoundingCircle::new(Vec2::ONE, 5.);
+        assert_eq!(BoundingCircle::from_volumes([a]), Some(a));
+
+        let volumes = [
+            a,
+            BoundingCircle::new(Vec2::new(1., -6.), 1.),
+            BoundingCircle::new(Vec2::new(8., 2.), 2.),
+            BoundingCircle::new(Vec2::new(2., 2.), 1.),
+        ];
+        let merged = BoundingCircle::from_volumes(volumes).unwrap();
+        for volume in &volumes {
+            assert!(merged.grow(0.001).contains(volume));
+        }
+    }
+
+    #[test]
+    fn enclosing() {
+        assert_eq!(BoundingCircle::enclosing(&[]), None);
+        assert_eq!(
+            BoundingCircle::enclosing(&[Vec2::ONE]),
+            Some(BoundingCircle::new(Vec2::ONE, 0.))
+        );
+
+        // Two points span the diameter
+        let circle = BoundingCircle::enclosing(&[Vec2::ZERO, Vec2::new(4., 0.)]).unwrap();
+        assert_eq!(circle, BoundingCircle::new(Vec2::new(2., 0.), 2.));
+
+        // An acute triangle is circumscribed
+        let points = [Vec2::new(-1., 0.), Vec2::new(1., 0.), Vec2::new(0., 1.5)];
+        let circle = BoundingCircle::enclosing(&points).unwrap();
+        assert!((circle.center - Vec2::new(0., 5. / 12.)).length() < 0.0001);
+        assert!(ops::abs(circle.radius() - 13. / 12.) < 0.0001);
+
+        // Interior points and an obtuse triangle don't affect the diameter
+        let points = [
+            Vec2::new(-2., 0.),
+            Vec2::new(0.5, 0.5),
+            Vec2::new(0., 0.25),
+            Vec2::new(2., 0.),
+            Vec2::new(-0.3, -1.),
+        ];
+        let circle = BoundingCircle::enclosing(&points).unwrap();
+        assert!(circle.center.length() < 0.0001);
+        assert!(ops::abs(circle.radius() - 2.) < 0.0001);
+
+        // Collinear points
+        let points = [Vec2::new(1., 1.), Vec2::new(3., 3.), Vec2::new(2., 2.)];
+        let circle = BoundingCircle::enclosing(&points).unwrap();
+        assert!((circle.center - Vec2::new(2., 2.)).length() < 0.0001);
+        assert!(ops::abs(circle.radius() - ops::sqrt(2.)) < 0.0001);
+    }
+
+    #[test]
+    fn enclosing_is_tight() {
+        // A regular hexagon, translated away from the origin, with a duplicated vertex
+        let points: [Vec2; 7] = core::array::from_fn(|i| {
+            let angle = i as f32 * core::f32::consts::FRAC_PI_3;
+            Vec2::new(10., -5.) + 3. * Vec2::new(ops::cos(angle), ops::sin(angle))
+        });
+        let circle = BoundingCircle::enclosing(&points).unwrap();
+        assert!((circle.center - Vec2::new(10., -5.)).length() < 0.001);
+        assert!(ops::abs(circle.radius() - 3.) < 0.001);
+        for point in points {
+            assert!(point.distance(circle.center) <= circle.radius() + 0.001);
+        }
+
+        let loose = BoundingCircle::from_point_cloud(Isometry2d::IDENTITY, &points);
+        assert!(circle.radius() <= loose.radius());
+    }
+
+    #[test]
+    fn enclosing_sorted_points() {
+        // Points sorted by their x coordinate along a parabola, of every count up to 64,
+        // so that the strides cover both even and odd counts
+        for len in 1..=64 {
+            let points: [Vec2; 64] = core::array::from_fn(|i| {
+                let x = i.min(len - 1) as f32 / 8. - 4.;
+                Vec2::new(x, x * x / 4.)
+            });
+            let points = &points[..len];
+            let circle = BoundingCircle::enclosing(points).unwrap();
+            for point in points {
+                assert!(
+                    point.distance(circle.center) <= circle.radius() + 0.001,
+                    "Case:\n  Test: {len:?}\n  Point: {point:?}\n  Circle: {circle:?}",
+                );
+            }
+
+            let loose = BoundingCircle::from_point_cloud(Isometry2d::IDENTITY, points);
+            assert!(circle.radius() <= loose.radius() + 0.001);
+        }
+    }
+
     #[test]
     fn merge_identical() {
         let a = BoundingCircle::new(Vec2::ONE, 5.);
@@ -725,6 +3027,34 @@ mod bounding_circle_tests {
         assert!(!shrunk.contains(&a));
     }
 
+    #[test]
+    fn inflate_deflate() {
+        let a = BoundingCircle::new(Vec2::ONE, 2.);
+        assert_eq!(a.inflate(0.5), BoundingCircle::new(Vec2::ONE, 2.5));
+        assert_eq!(a.deflate(0.5), BoundingCircle::new(Vec2::ONE, 1.5));
+        assert_eq!(a.deflate(3.), BoundingCircle::new(Vec2::ONE, 0.));
+    }
+
+    #[test]
+    fn expand_to_include() {
+        let circle = BoundingCircle::new(Vec2::ZERO, 1.);
+        // Points that are already inside don't change the circle
+        assert_eq!(circle.including(Vec2::new(0., 1.)), circle);
+        assert_eq!(circle.including(Vec2::new(0.5, -0.5)), circle);
+
+        // The side of the circle opposite to the point stays in place
+        let grown = circle.including(Vec2::new(3., 0.));
+        assert_eq!(grown, BoundingCircle::new(Vec2::new(1., 0.), 2.));
+        assert!(grown.contains(&circle));
+
+        let mut circle = BoundingCircle::new(Vec2::ONE, 0.);
+        for point in [Vec2::new(4., 5.), Vec2::new(-2., 3.), Vec2::new(0., -1.)] {
+            circle.expand_to_include(point);
+            assert!(circle.center.distance(point) <= circle.radius() + 1e-5);
+        }
+        assert!(circle.center.distance(Vec2::ONE) <= circle.radius() + 1e-5);
+    }
+
     #[test]
     fn scale_around_center() {
         let a = BoundingCircle::new(Vec2::ONE, 5.);
@@ -745,6 +3075,23 @@ mod bounding_circle_tests {
         assert_eq!(transformed.radius(), 5.0);
     }
 
+    #[test]
+    fn area_and_circumference() {
+        let a = BoundingCircle::new(Vec2::ONE, 2.);
+        assert_relative_eq!(a.area(), 4. * core::f32::consts::PI);
+        assert_eq!(a.area(), a.visible_area());
+        assert_relative_eq!(a.circumference(), 4. * core::f32::consts::PI);
+    }
+
+    #[test]
+    fn transformed_by_isometry() {
+        let a = BoundingCircle::new(Vec2::new(1.0, 0.0), 2.0);
+        let transformed =
+            a.transformed_by_isometry(Isometry2d::new(Vec2::new(0.0, 3.0), Rot2::FRAC_PI_2));
+        assert_relative_eq!(transformed.center, Vec2::new(0.0, 4.0));
+        assert_eq!(transformed.radius(), 2.0);
+    }
+
     #[test]
     fn closest_point() {
         let circle = BoundingCircle::new(Vec2::ZERO, 1.0);
@@ -759,6 +3106,69 @@ mod bounding_circle_tests {
         );
     }
 
+    #[test]
+    fn tangent_points() {
+        let circle = BoundingCircle::new(Vec2::new(1.0, -2.0), 2.0);
+        for from in [
+            Vec2::new(5.0, -2.0),
+            Vec2::new(-3.0, 4.0),
+            Vec2::new(1.0, 10.0),
+        ] {
+            let (first, second) = circle.tangent_points(from).unwrap();
+            for point in [first, second] {
+                assert_relative_eq!(point.distance(circle.center), 2.0, epsilon = 1e-5);
+                // The tangent line is perpendicular to the radius
+                let radius = (point - circle.center).normalize();
+                let tangent = (from - point).normalize();
+                assert!(ops::abs(radius.dot(tangent)) < 1e-5, "{from:?}: {point:?}");
+            }
+            // The first point is counterclockwise from the direction to `from`
+            let direction = from - circle.center;
+            assert!(direction.perp_dot(first - circle.center) > 0.0);
+            assert!(direction.perp_dot(second - circle.center) < 0.0);
+        }
+
+        let (first, second) = circle.tangent_points(Vec2::new(5.0, -2.0)).unwrap();
+        assert_relative_eq!(first, Vec2::new(2.0, ops::sqrt(3.0) - 2.0), epsilon = 1e-5);
+        assert_relative_eq!(
+            second,
+            Vec2::new(2.0, -ops::sqrt(3.0) - 2.0),
+            epsilon = 1e-5
+        );
+
+        // On the boundary, the point is its own tangent point
+        let on_boundary = Vec2::new(1.0, 0.0);
+        assert_eq!(
+            circle.tangent_points(on_boundary),
+            Some((on_boundary, on_boundary))
+        );
+
+        // Inside
+        assert_eq!(circle.tangent_points(Vec2::new(1.5, -1.5)), None);
+        assert_eq!(circle.tangent_points(circle.center), None);
+    }
+
+    #[test]
+    fn closest_point_on_boundary() {
+        let circle = BoundingCircle::new(Vec2::new(0.3, -0.7), 2.5);
+        for point in [
+            circle.center + Vec2::X * 2.5,
+            circle.center - Vec2::Y * 2.5,
+            circle.center + Vec2::new(1.5, 2.0),
+        ] {
+            assert_eq!(circle.closest_point(point), point);
+        }
+    }
+
+    #[test]
+    fn signed_distance() {
+        let circle = BoundingCircle::new(Vec2::ONE, 2.0);
+        assert_relative_eq!(circle.signed_distance(Vec2::new(4.0, 5.0)), 3.0);
+        assert_relative_eq!(circle.signed_distance(Vec2::new(3.0, 1.0)), 0.0);
+        assert_relative_eq!(circle.signed_distance(Vec2::new(1.5, 1.0)), -1.5);
+        assert_relative_eq!(circle.signed_distance(circle.center), -2.0);
+    }
+
     #[test]
     fn intersect_bounding_circle() {
         let circle = BoundingCircle::new(Vec2::ZERO, 1.0);
@@ -767,4 +3177,127 @@ mod bounding_circle_tests {
         assert!(circle.intersects(&BoundingCircle::new(Vec2::NEG_ONE * 1.25, 1.0)));
         assert!(!circle.intersects(&BoundingCircle::new(Vec2::ONE * 1.5, 1.0)));
     }
+
+    #[test]
+    fn intersects_eps() {
+        let circle = BoundingCircle::new(Vec2::ZERO, 1.0);
+        let epsilon = 1e-4;
+
+        // The other circle jitters around touching, as if moved by a noisy simulation
+        for jitter in [-1e-6, 1e-6, -2e-7, 3e-6, 0.0, 5e-5] {
+            let other = BoundingCircle::new(Vec2::new(0.0, 3.0 + jitter), 2.0);
+            assert!(circle.intersects_eps(&other, epsilon), "jitter: {jitter}");
+        }
+        assert!(!circle.intersects_eps(&BoundingCircle::new(Vec2::new(0.0, 3.001), 2.0), epsilon));
+
+        // A slowly separating pair stops intersecting once, and never goes back
+        let direction = Vec2::new(0.6, 0.8);
+        let mut changes = 0;
+        let mut previous = true;
+        let mut distance = 2.9;
+        while distance < 3.1 {
+            let other = BoundingCircle::new(direction * distance, 2.0);
+            let current = circle.intersects_eps(&other, epsilon);
+            if current != previous {
+                changes += 1;
+                previous = current;
+            }
+            distance += 1e-5;
+        }
+        assert_eq!(changes, 1);
+        assert!(!previous);
+    }
+
+    #[test]
+    fn is_valid() {
+        assert!(BoundingCircle::new(Vec2::ONE, 0.0).is_valid());
+        assert!(!BoundingCircle {
+            center: Vec2::ZERO,
+            circle: Circle { radius: -1.0 },
+        }
+        .is_valid());
+        assert!(!BoundingCircle {
+            center: Vec2::new(f32::NAN, 0.0),
+            circle: Circle { radius: 1.0 },
+        }
+        .is_valid());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "non-negative radius")]
+    fn new_with_negative_radius() {
+        BoundingCircle::new(Vec2::ZERO, -1.0);
+    }
+
+    #[test]
+    fn distance() {
+        let circle = BoundingCircle::new(Vec2::ZERO, 1.0);
+        for (other, expected) in [
+            (BoundingCircle::new(Vec2::new(1.0, 1.0), 1.0), 0.0),
+            (BoundingCircle::new(Vec2::new(3.0, 0.0), 2.0), 0.0),
+            (BoundingCircle::new(Vec2::new(3.0, 4.0), 2.0), 2.0),
+            (BoundingCircle::new(Vec2::new(-10.0, 0.0), 0.5), 8.5),
+        ] {
+            assert_eq!(circle.distance(&other), expected);
+            assert_eq!(other.distance(&circle), expected);
+            assert_eq!(circle.distance_squared(&other), expected * expected);
+        }
+    }
+
+    #[test]
+    fn scaled_around() {
+        let circle = BoundingCircle::new(Vec2::new(2.0, 1.0), 1.0);
+        let pivot = Vec2::new(1.0, 0.0);
+        for (test, factor, expected) in &[
+            ("identity", 1.0, circle),
+            (
+                "growing",
+                2.0,
+                BoundingCircle::new(Vec2::new(3.0, 2.0), 2.0),
+            ),
+            ("collapsing", 0.0, BoundingCircle::new(pivot, 0.0)),
+            (
+                "mirroring",
+                -2.0,
+                BoundingCircle::new(Vec2::new(-1.0, -2.0), 2.0),
+            ),
+        ] {
+            assert_eq!(
+                circle.scaled_around(pivot, *factor),
+                *expected,
+                "Case:\n  Test: {test:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn intersects_circle_const() {
+        const CIRCLE: BoundingCircle = BoundingCircle {
+            center: Vec2::ZERO,
+            circle: Circle { radius: 1.0 },
+        };
+        const TOUCHING: bool = CIRCLE.intersects_circle(&BoundingCircle {
+            center: Vec2::new(3.0, 0.0),
+            circle: Circle { radius: 2.0 },
+        });
+        const DISJOINT: bool = CIRCLE.intersects_circle(&BoundingCircle {
+            center: Vec2::new(2.0, 2.0),
+            circle: Circle { radius: 1.5 },
+        });
+        const _: () = assert!(TOUCHING);
+        const _: () = assert!(!DISJOINT);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_layout() {
+        let circles = [
+            BoundingCircle::new(Vec2::new(1.0, 2.0), 3.0),
+            BoundingCircle::new(Vec2::new(4.0, 5.0), 6.0),
+        ];
+        let floats: &[f32] = bytemuck::cast_slice(&circles);
+        assert_eq!(floats, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(size_of::<BoundingCircle>(), 12);
+    }
 }
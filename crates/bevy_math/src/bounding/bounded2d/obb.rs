@@ -0,0 +1,419 @@
+use super::{Aabb2d, BoundingCircle, ContainsPoint2d};
+use crate::{
+    bounding::{BoundingVolume, IntersectsVolume},
+    ops,
+    primitives::Rectangle,
+    FloatPow, Isometry2d, Mat2, Rot2, Vec2,
+};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A 2D oriented bounding box, or rotated bounding rectangle
+///
+/// Unlike an [`Aabb2d`], this can tightly bound rotated shapes like a rotated [`Rectangle`].
+#[doc(alias = "OrientedBoundingBox2d")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Obb2d {
+    /// The center of the box
+    pub center: Vec2,
+    /// The half-size of the box along its local axes
+    pub half_size: Vec2,
+    /// The rotation of the box around its center
+    pub rotation: Rot2,
+}
+
+impl Obb2d {
+    /// Constructs an OBB from its center, half-size, and rotation.
+    #[inline(always)]
+    pub fn new(center: Vec2, half_size: Vec2, rotation: Rot2) -> Self {
+        debug_assert!(half_size.x >= 0.0 && half_size.y >= 0.0);
+        Self {
+            center,
+            half_size,
+            rotation,
+        }
+    }
+
+    /// Returns the local X and Y axes of the box in world space.
+    #[inline(always)]
+    pub fn axes(&self) -> [Vec2; 2] {
+        let (sin, cos) = self.rotation.sin_cos();
+        [Vec2::new(cos, sin), Vec2::new(-sin, cos)]
+    }
+
+    /// Returns the four corners of the box in counterclockwise order,
+    /// starting from the corner that is bottom-left in the local frame of the box.
+    #[inline(always)]
+    pub fn corners(&self) -> [Vec2; 4] {
+        let [x, y] = self.axes();
+        let (x, y) = (x * self.half_size.x, y * self.half_size.y);
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+
+    /// Computes the smallest [`Aabb2d`] containing this [`Obb2d`].
+    #[inline(always)]
+    pub fn aabb_2d(&self) -> Aabb2d {
+        let half_size = Mat2::from(self.rotation).abs() * self.half_size;
+        Aabb2d::new(self.center, half_size)
+    }
+
+    /// Computes the smallest [`BoundingCircle`] containing this [`Obb2d`].
+    #[inline(always)]
+    pub fn bounding_circle(&self) -> BoundingCircle {
+        BoundingCircle::new(self.center, self.half_size.length())
+    }
+
+    /// Finds the point on the OBB that is closest to the given `point`.
+    ///
+    /// If the point is outside the OBB, the returned point will be on the perimeter of the OBB.
+    /// Otherwise, it will be inside or on the perimeter of the OBB and returned as is.
+    #[inline(always)]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let local = self.rotation.inverse_mul_vec(point - self.center);
+        if local.abs().cmple(self.half_size).all() {
+            return point;
+        }
+        self.center + self.rotation * local.clamp(-self.half_size, self.half_size)
+    }
+
+    /// Computes the radius of the projection of the box onto the given `axis`.
+    #[inline(always)]
+    fn projected_radius(&self, axis: Vec2) -> f32 {
+        let [x, y] = self.axes();
+        self.half_size.x * ops::abs(x.dot(axis)) + self.half_size.y * ops::abs(y.dot(axis))
+    }
+}
+
+impl Rectangle {
+    /// Get an oriented bounding box for the rectangle translated and rotated by the given isometry.
+    ///
+    /// This bounds the rectangle exactly, unlike [`Bounded2d::aabb_2d`](super::Bounded2d::aabb_2d).
+    #[inline(always)]
+    pub fn obb_2d(&self, isometry: impl Into<Isometry2d>) -> Obb2d {
+        let isometry = isometry.into();
+        Obb2d::new(isometry.translation, self.half_size, isometry.rotation)
+    }
+}
+
+impl From<Aabb2d> for Obb2d {
+    #[inline(always)]
+    fn from(aabb: Aabb2d) -> Self {
+        Obb2d::new(aabb.center(), aabb.half_size(), Rot2::IDENTITY)
+    }
+}
+
+impl BoundingVolume for Obb2d {
+    type Translation = Vec2;
+    type Rotation = Rot2;
+    type HalfSize = Vec2;
+
+    #[inline(always)]
+    fn center(&self) -> Self::Translation {
+        self.center
+    }
+
+    #[inline(always)]
+    fn half_size(&self) -> Self::HalfSize {
+        self.half_size
+    }
+
+    #[inline(always)]
+    fn visible_area(&self) -> f32 {
+        4.0 * self.half_size.x * self.half_size.y
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &Self) -> bool {
+        // Boxes are convex, so it is enough to check the corners
+        let inverse = self.rotation.inverse();
+        other.corners().iter().all(|&corner| {
+            let local = inverse * (corner - self.center);
+            local.abs().cmple(self.half_size).all()
+        })
+    }
+
+    /// Computes an [`Obb2d`] with the rotation of `self` that contains both `self` and `other`.
+    ///
+    /// The box is not guaranteed to be the smallest possible.
+    #[inline(always)]
+    fn merge(&self, other: &Self) -> Self {
+        let inverse = self.rotation.inverse();
+        let (min, max) = other.corners().iter().fold(
+            (-self.half_size, self.half_size),
+            |(min, max), &corner| {
+                let local = inverse * (corner - self.center);
+                (min.min(local), max.max(local))
+            },
+        );
+        Self::new(
+            self.center + self.rotation * ((min + max) / 2.0),
+            (max - min) / 2.0,
+            self.rotation,
+        )
+    }
+
+    #[inline(always)]
+    fn grow(&self, amount: impl Into<Self::HalfSize>) -> Self {
+        let amount = amount.into();
+        let b = Self {
+            half_size: self.half_size + amount,
+            ..*self
+        };
+        debug_assert!(b.half_size.x >= 0.0 && b.half_size.y >= 0.0);
+        b
+    }
+
+    #[inline(always)]
+    fn shrink(&self, amount: impl Into<Self::HalfSize>) -> Self {
+        let amount = amount.into();
+        let b = Self {
+            half_size: self.half_size - amount,
+            ..*self
+        };
+        debug_assert!(b.half_size.x >= 0.0 && b.half_size.y >= 0.0);
+        b
+    }
+
+    #[inline(always)]
+    fn scale_around_center(&self, scale: impl Into<Self::HalfSize>) -> Self {
+        let scale = scale.into();
+        let b = Self {
+            half_size: self.half_size * scale,
+            ..*self
+        };
+        debug_assert!(b.half_size.x >= 0.0 && b.half_size.y >= 0.0);
+        b
+    }
+
+    #[inline(always)]
+    fn translate_by(&mut self, translation: impl Into<Self::Translation>) {
+        self.center += translation.into();
+    }
+
+    /// Rotates the bounding volume around the origin by the given rotation.
+    ///
+    /// Unlike for an [`Aabb2d`], the result fits the rotated shape exactly.
+    #[inline(always)]
+    fn rotate_by(&mut self, rotation: impl Into<Self::Rotation>) {
+        let rotation: Rot2 = rotation.into();
+        self.center = rotation * self.center;
+        self.rotation = (rotation * self.rotation).fast_renormalize();
+    }
+}
+
+impl ContainsPoint2d for Obb2d {
+    #[inline(always)]
+    fn contains_point(&self, point: Vec2) -> bool {
+        let local = self.rotation.inverse_mul_vec(point - self.center);
+        local.abs().cmple(self.half_size).all()
+    }
+}
+
+impl IntersectsVolume<Self> for Obb2d {
+    /// Checks if the boxes intersect using the separating axis theorem.
+    ///
+    /// Boxes with a zero half-size are treated as points.
+    #[inline(always)]
+    fn intersects(&self, other: &Self) -> bool {
+        let offset = other.center - self.center;
+        let [a, b] = self.axes();
+        let [c, d] = other.axes();
+
+        // The boxes are disjoint if and only if one of their edge normals separates them
+        [a, b, c, d].iter().all(|&axis| {
+            ops::abs(offset.dot(axis)) <= self.projected_radius(axis) + other.projected_radius(axis)
+        })
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for Obb2d {
+    #[inline(always)]
+    fn intersects(&self, aabb: &Aabb2d) -> bool {
+        self.intersects(&Obb2d::from(*aabb))
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for Obb2d {
+    #[inline(always)]
+    fn intersects(&self, circle: &BoundingCircle) -> bool {
+        let closest_point = self.closest_point(circle.center);
+        let distance_squared = circle.center.distance_squared(closest_point);
+        distance_squared <= circle.radius().squared()
+    }
+}
+
+impl IntersectsVolume<Obb2d> for Aabb2d {
+    #[inline(always)]
+    fn intersects(&self, obb: &Obb2d) -> bool {
+        obb.intersects(self)
+    }
+}
+
+impl IntersectsVolume<Obb2d> for BoundingCircle {
+    #[inline(always)]
+    fn intersects(&self, obb: &Obb2d) -> bool {
+        obb.intersects(self)
+    }
+}
+
+#[cfg(test)]
+mod obb2d_tests {
+    use approx::assert_relative_eq;
+
+    use super::Obb2d;
+    use crate::{
+        bounding::{Aabb2d, Bounded2d, BoundingCircle, BoundingVolume, IntersectsVolume},
+        ops,
+        primitives::Rectangle,
+        Isometry2d, Rot2, Vec2,
+    };
+
+    #[test]
+    fn rectangle_obb() {
+        let rectangle = Rectangle::new(4.0, 2.0);
+        let isometry = Isometry2d::new(Vec2::new(1.0, 2.0), Rot2::degrees(30.0));
+        let obb = rectangle.obb_2d(isometry);
+        assert_eq!(obb.center, Vec2::new(1.0, 2.0));
+        assert_eq!(obb.half_size, Vec2::new(2.0, 1.0));
+
+        // The AABB of the OBB matches the AABB of the rectangle itself
+        let aabb = obb.aabb_2d();
+        let expected = rectangle.aabb_2d(isometry);
+        assert_relative_eq!(aabb.min, expected.min);
+        assert_relative_eq!(aabb.max, expected.max);
+        assert!(obb.visible_area() < aabb.visible_area());
+    }
+
+    #[test]
+    fn corners() {
+        let obb = Obb2d::new(Vec2::ONE, Vec2::new(2.0, 1.0), Rot2::FRAC_PI_2);
+        let corners = obb.corners();
+        assert_relative_eq!(corners[0], Vec2::new(2.0, -1.0));
+        assert_relative_eq!(corners[1], Vec2::new(2.0, 3.0));
+        assert_relative_eq!(corners[2], Vec2::new(0.0, 3.0));
+        assert_relative_eq!(corners[3], Vec2::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn closest_point() {
+        let obb = Obb2d::new(Vec2::ZERO, Vec2::ONE, Rot2::FRAC_PI_4);
+        assert_relative_eq!(
+            obb.closest_point(Vec2::X * 10.0),
+            Vec2::X * core::f32::consts::SQRT_2
+        );
+        assert_eq!(obb.closest_point(Vec2::new(0.5, 0.5)), Vec2::new(0.5, 0.5));
+    }
+
+    #[test]
+    fn contains_and_merge() {
+        let a = Obb2d::new(Vec2::ZERO, Vec2::new(2.0, 1.0), Rot2::FRAC_PI_4);
+        let b = Obb2d::new(Vec2::ZERO, Vec2::new(0.5, 0.5), Rot2::IDENTITY);
+        assert!(a.contains(&b));
+        assert!(!b.contains(&a));
+
+        let c = Obb2d::new(Vec2::new(3.0, -2.0), Vec2::ONE, Rot2::degrees(10.0));
+        let merged = a.merge(&c);
+        assert_eq!(merged.rotation, a.rotation);
+        assert!(merged.grow(Vec2::splat(0.001)).contains(&a));
+        assert!(merged.grow(Vec2::splat(0.001)).contains(&c));
+    }
+
+    #[test]
+    fn aabb_2d_bounds_rotated_corners() {
+        // The corners rotated with `Rot2 * Vec2` are on the boundary of the AABB
+        let obb = Obb2d::new(
+            Vec2::new(1.0, -2.0),
+            Vec2::new(2.0, 0.5),
+            Rot2::degrees(30.0),
+        );
+        let corners = [
+            Vec2::NEG_ONE,
+            Vec2::new(1.0, -1.0),
+            Vec2::ONE,
+            Vec2::new(-1.0, 1.0),
+        ]
+        .map(|corner| obb.rotation * (corner * obb.half_size) + obb.center);
+        let min = corners.into_iter().reduce(Vec2::min).unwrap();
+        let max = corners.into_iter().reduce(Vec2::max).unwrap();
+        let aabb = obb.aabb_2d();
+        assert_relative_eq!(aabb.min, min, epsilon = 1e-6);
+        assert_relative_eq!(aabb.max, max, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn transform() {
+        let a = Obb2d::new(Vec2::X, Vec2::new(2.0, 1.0), Rot2::IDENTITY);
+        let transformed = a.transformed_by(Vec2::Y, Rot2::FRAC_PI_2);
+        assert_relative_eq!(transformed.center, Vec2::new(0.0, 2.0));
+        assert_eq!(transformed.half_size, a.half_size);
+        assert_relative_eq!(transformed.rotation, Rot2::FRAC_PI_2);
+        let area = transformed.visible_area();
+        assert!(ops::abs(area - a.visible_area()) < f32::EPSILON);
+    }
+
+    #[test]
+    fn intersect_obb() {
+        let a = Obb2d::new(Vec2::ZERO, Vec2::ONE, Rot2::FRAC_PI_4);
+        assert!(a.intersects(&a));
+
+        // The corner of the diamond pokes into the box
+        let b = Obb2d::new(Vec2::X * 2.0, Vec2::ONE, Rot2::IDENTITY);
+        assert!(a.intersects(&b));
+
+        // Separated along one of the diagonal axes, even though the AABBs overlap
+        let b = Obb2d::new(Vec2::new(1.6, 1.6), Vec2::ONE, Rot2::FRAC_PI_4);
+        assert!(a.aabb_2d().intersects(&b.aabb_2d()));
+        assert!(!a.intersects(&b));
+    }
+
+    #[test]
+    fn intersect_aabb() {
+        let obb = Obb2d::new(Vec2::ZERO, Vec2::new(2.0, 0.1), Rot2::FRAC_PI_4);
+        assert!(obb.intersects(&Aabb2d::new(Vec2::ONE, Vec2::splat(0.25))));
+        assert!(!obb.intersects(&Aabb2d::new(Vec2::new(1.0, -1.0), Vec2::splat(0.25))));
+        assert!(Aabb2d::new(Vec2::new(-1.0, -1.0), Vec2::splat(0.25)).intersects(&obb));
+    }
+
+    #[test]
+    fn intersect_bounding_circle() {
+        let obb = Obb2d::new(Vec2::ZERO, Vec2::new(2.0, 0.1), Rot2::FRAC_PI_4);
+        assert!(obb.intersects(&BoundingCircle::new(Vec2::ONE, 0.25)));
+        assert!(!obb.intersects(&BoundingCircle::new(Vec2::new(1.0, -1.0), 0.25)));
+        assert!(BoundingCircle::new(Vec2::new(1.0, -1.0), 1.4).intersects(&obb));
+    }
+
+    #[test]
+    fn intersect_degenerate() {
+        // A box with a zero half-size is a point
+        let point = Obb2d::new(Vec2::new(0.5, 0.5), Vec2::ZERO, Rot2::degrees(20.0));
+        let obb = Obb2d::new(Vec2::ZERO, Vec2::ONE, Rot2::FRAC_PI_4);
+        assert!(point.intersects(&obb));
+        assert!(obb.intersects(&point));
+
+        let point = Obb2d::new(Vec2::new(0.9, 0.9), Vec2::ZERO, Rot2::degrees(20.0));
+        assert!(!point.intersects(&obb));
+        assert!(!obb.intersects(&point));
+
+        assert!(point.intersects(&point));
+    }
+}
@@ -366,13 +366,25 @@ impl Bounded2d for Rectangle {
     }
 }
 
+/// Computes the smallest [`BoundingCircle`] containing the given points, transformed by the given isometry.
+///
+/// Falls back to [`BoundingCircle::from_point_cloud`] for an empty set of points.
+#[inline(always)]
+fn enclosing_circle(isometry: impl Into<Isometry2d>, points: &[Vec2]) -> BoundingCircle {
+    let isometry = isometry.into();
+    BoundingCircle::enclosing(points).map_or_else(
+        || BoundingCircle::from_point_cloud(isometry, points),
+        |circle| BoundingCircle::new(isometry * circle.center, circle.radius()),
+    )
+}
+
 impl<const N: usize> Bounded2d for Polygon<N> {
     fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
         Aabb2d::from_point_cloud(isometry, &self.vertices)
     }
 
     fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
-        BoundingCircle::from_point_cloud(isometry, &self.vertices)
+        enclosing_circle(isometry, &self.vertices)
     }
 }
 
@@ -393,7 +405,7 @@ impl Bounded2d for BoxedPolygon {
     }
 
     fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
-        BoundingCircle::from_point_cloud(isometry, &self.vertices)
+        enclosing_circle(isometry, &self.vertices)
     }
 }
 
@@ -448,7 +460,7 @@ impl Bounded2d for Capsule2d {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 #[expect(clippy::print_stdout, reason = "Allowed in tests.")]
 mod tests {
     use core::f32::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, TAU};
@@ -458,7 +470,7 @@ mod tests {
     use glam::Vec2;
 
     use crate::{
-        bounding::Bounded2d,
+        bounding::{Bounded2d, BoundingVolume},
         ops::{self, FloatPow},
         primitives::{
             Annulus, Arc2d, Capsule2d, Circle, CircularSector, CircularSegment, Ellipse, Line2d,
@@ -891,6 +903,32 @@ mod tests {
         assert_eq!(bounding_circle.radius(), f32::MAX / 2.0);
     }
 
+    #[test]
+    fn unbounded_bounding_circle_from_aabb() {
+        // The diagonal of the bounds of lines and planes overflows when converted to a bounding circle,
+        // which must not trip the validation of the constructors in debug builds
+        let isometry = Isometry2d::from_translation(Vec2::new(2.0, 1.0));
+        let diagonal = Dir2::from_xy(1.0, 1.0).unwrap();
+        for aabb in [
+            Line2d { direction: Dir2::Y }.aabb_2d(isometry),
+            Line2d {
+                direction: diagonal,
+            }
+            .aabb_2d(isometry),
+            Plane2d::new(*diagonal).aabb_2d(isometry),
+        ] {
+            let bounding_circle = aabb.bounding_circle();
+            assert_eq!(bounding_circle.center, aabb.center());
+            assert_eq!(bounding_circle.radius(), f32::INFINITY);
+            assert!(!bounding_circle.is_valid());
+        }
+
+        // Volumes with infinite bounds are also allowed
+        let aabb = crate::bounding::Aabb2d::new(Vec2::ZERO, Vec2::INFINITY);
+        assert!(!aabb.is_valid());
+        assert_eq!(aabb.bounding_circle().radius(), f32::INFINITY);
+    }
+
     #[test]
     fn segment() {
         let segment = Segment2d::new(Vec2::new(-1.0, -0.5), Vec2::new(1.0, 0.5));
@@ -999,6 +1037,31 @@ mod tests {
         assert_eq!(bounding_circle.radius(), core::f32::consts::SQRT_2);
     }
 
+    #[test]
+    fn polygon_smallest_bounding_circle() {
+        // Most of the vertices are bunched up on one side, pulling their centroid off center
+        let vertices = [
+            Vec2::new(-2.0, 0.0),
+            Vec2::new(1.5, -0.5),
+            Vec2::new(1.8, -0.2),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(1.8, 0.2),
+            Vec2::new(1.5, 0.5),
+        ];
+        let polygon = Polygon::<6>::new(vertices);
+        let isometry = Isometry2d::new(Vec2::new(2.0, 1.0), Rot2::degrees(30.0));
+
+        let bounding_circle = polygon.bounding_circle(isometry);
+        assert!((bounding_circle.center - isometry.translation).length() < 1e-6);
+        assert!(ops::abs(bounding_circle.radius() - 2.0) < 1e-6);
+
+        #[cfg(feature = "alloc")]
+        {
+            let boxed = crate::primitives::BoxedPolygon::new(vertices);
+            assert_eq!(boxed.bounding_circle(isometry), bounding_circle);
+        }
+    }
+
     #[test]
     fn regular_polygon() {
         let regular_polygon = RegularPolygon::new(1.0, 5);
@@ -1014,6 +1077,41 @@ mod tests {
         assert_eq!(bounding_circle.radius(), 1.0);
     }
 
+    #[test]
+    fn regular_polygon_tight_aabb() {
+        let hexagon = RegularPolygon::new(2.0, 6);
+        let translation = Vec2::new(-1.0, 3.0);
+
+        for i in 0..16 {
+            let rotation = Rot2::radians(i as f32 * TAU / 16.0);
+            let isometry = Isometry2d::new(translation, rotation);
+
+            let aabb = hexagon.aabb_2d(isometry);
+            let loose = hexagon.bounding_circle(isometry).aabb_2d();
+
+            // The tight AABB is never larger than the box around the circumcircle
+            assert!(aabb.min.cmpge(loose.min - 1e-6).all());
+            assert!(aabb.max.cmple(loose.max + 1e-6).all());
+
+            // A hexagon never touches its circumcircle on all four sides at once
+            assert!(aabb.visible_area() < loose.visible_area());
+
+            // Every vertex is inside the AABB, and every side of the AABB touches a vertex
+            let vertices = || {
+                hexagon
+                    .vertices(rotation.as_radians())
+                    .map(|v| v + translation)
+            };
+            for vertex in vertices() {
+                assert!(vertex.cmpge(aabb.min - 1e-6).all() && vertex.cmple(aabb.max + 1e-6).all());
+            }
+            assert!(vertices().any(|v| ops::abs(v.x - aabb.min.x) < 1e-6));
+            assert!(vertices().any(|v| ops::abs(v.x - aabb.max.x) < 1e-6));
+            assert!(vertices().any(|v| ops::abs(v.y - aabb.min.y) < 1e-6));
+            assert!(vertices().any(|v| ops::abs(v.y - aabb.max.y) < 1e-6));
+        }
+    }
+
     #[test]
     fn capsule() {
         let capsule = Capsule2d::new(0.5, 2.0);
@@ -1027,5 +1125,47 @@ mod tests {
         let bounding_circle = capsule.bounding_circle(isometry);
         assert_eq!(bounding_circle.center, translation);
         assert_eq!(bounding_circle.radius(), 1.5);
+
+        // Rotated by 45 degrees, the end caps are at `±sqrt(2) / 2` on both axes
+        let isometry = Isometry2d::new(translation, Rot2::FRAC_PI_4);
+        let aabb = capsule.aabb_2d(isometry);
+        let half_size = Vec2::splat(core::f32::consts::FRAC_1_SQRT_2 + 0.5);
+        assert_abs_diff_eq!(aabb.min, translation - half_size, epsilon = 1e-6);
+        assert_abs_diff_eq!(aabb.max, translation + half_size, epsilon = 1e-6);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn capsule_rotated_contains_boundary() {
+        use crate::ShapeSample;
+        use rand::{Rng, SeedableRng};
+        use rand_chacha::ChaCha8Rng;
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        for _ in 0..100 {
+            let capsule = Capsule2d::new(rng.gen_range(0.1..2.0), rng.gen_range(0.0..4.0));
+            let isometry = Isometry2d::new(
+                Vec2::new(rng.gen_range(-10.0..10.0), rng.gen_range(-10.0..10.0)),
+                Rot2::radians(rng.gen_range(0.0..TAU)),
+            );
+            let aabb = capsule.aabb_2d(isometry);
+
+            // The AABB of the rectangle enclosing the capsule
+            let naive = Rectangle::new(
+                2.0 * capsule.radius,
+                2.0 * (capsule.half_length + capsule.radius),
+            )
+            .aabb_2d(isometry);
+            assert!(aabb.half_size().cmple(naive.half_size() + 1e-5).all());
+
+            for _ in 0..100 {
+                let point =
+                    isometry.transform_point(ShapeSample::sample_boundary(&capsule, &mut rng));
+                assert!(
+                    aabb.closest_point(point).distance(point) < 1e-5,
+                    "{point} is outside of {aabb:?}"
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,293 @@
+use super::{Aabb2d, BoundingCircle, ContainsPoint2d};
+use crate::{
+    bounding::{BoundingVolume, IntersectsVolume},
+    Dir2, Vec2,
+};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// The result of classifying a bounding volume against a [`HalfPlaneRegion2d`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Hash, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum RegionClassification {
+    /// The volume is entirely inside the region.
+    Inside,
+    /// The volume is entirely outside the region.
+    Outside,
+    /// The volume may be partially inside the region.
+    Intersecting,
+}
+
+/// A convex region in 2D space, defined as the intersection of a set of half-planes.
+///
+/// Each half-plane is given by an outward-facing `normal` and an `offset`,
+/// and contains the points `p` for which `normal.dot(p) <= offset`.
+/// A region without any half-planes contains all of space.
+///
+/// This can be used to cull bounding volumes against convex query regions, like the view cone of a camera.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HalfPlaneRegion2d {
+    /// The half-planes bounding the region, as pairs of an outward-facing normal and an offset
+    pub planes: Vec<(Dir2, f32)>,
+}
+
+impl HalfPlaneRegion2d {
+    /// Constructs a region from the given half-planes,
+    /// each given by an outward-facing normal and an offset.
+    #[inline]
+    pub fn new(planes: impl IntoIterator<Item = (Dir2, f32)>) -> Self {
+        Self {
+            planes: planes.into_iter().collect(),
+        }
+    }
+
+    /// Constructs a region with the same shape as the given [`Aabb2d`].
+    #[inline]
+    pub fn from_aabb(aabb: &Aabb2d) -> Self {
+        Self::new([
+            (Dir2::NEG_X, -aabb.min.x),
+            (Dir2::X, aabb.max.x),
+            (Dir2::NEG_Y, -aabb.min.y),
+            (Dir2::Y, aabb.max.y),
+        ])
+    }
+
+    /// Adds a half-plane with the given outward-facing `normal` and `offset` to the region.
+    #[inline]
+    pub fn push(&mut self, normal: Dir2, offset: f32) {
+        self.planes.push((normal, offset));
+    }
+
+    /// Checks if the given `point` is inside the region.
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.planes
+            .iter()
+            .all(|(normal, offset)| normal.dot(point) <= *offset)
+    }
+
+    /// Checks if the given [`Aabb2d`] is entirely inside the region.
+    #[inline]
+    pub fn contains_aabb(&self, aabb: &Aabb2d) -> bool {
+        self.classify(aabb) == RegionClassification::Inside
+    }
+
+    /// Checks if the given [`Aabb2d`] may intersect the region.
+    ///
+    /// This is conservative: an AABB that is outside the region but close to one of its corners,
+    /// without being entirely outside any single half-plane, is also considered intersecting.
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &Aabb2d) -> bool {
+        self.classify(aabb) != RegionClassification::Outside
+    }
+
+    /// Classifies the given [`Aabb2d`] as inside, outside, or intersecting the region.
+    ///
+    /// Like [`HalfPlaneRegion2d::intersects_aabb`], this is conservative, and may classify
+    /// AABBs close to the corners of the region as [`RegionClassification::Intersecting`]
+    /// even if they are outside of it.
+    pub fn classify(&self, aabb: &Aabb2d) -> RegionClassification {
+        let center = aabb.center();
+        let half_size = aabb.half_size();
+        self.classify_with(|normal| {
+            // The extent of the AABB along the normal
+            let extent = normal.abs().dot(half_size);
+            (normal.dot(center), extent)
+        })
+    }
+
+    /// Classifies the given [`BoundingCircle`] as inside, outside, or intersecting the region.
+    ///
+    /// Like [`HalfPlaneRegion2d::classify`], this is conservative near the corners of the region.
+    pub fn classify_circle(&self, circle: &BoundingCircle) -> RegionClassification {
+        self.classify_with(|normal| (normal.dot(circle.center), circle.radius()))
+    }
+
+    /// Classifies a volume given a function returning the signed distance of its center
+    /// along a normal, and its extent along that normal.
+    #[inline(always)]
+    fn classify_with(&self, projection: impl Fn(Vec2) -> (f32, f32)) -> RegionClassification {
+        let mut classification = RegionClassification::Inside;
+        for (normal, offset) in &self.planes {
+            let (distance, extent) = projection(**normal);
+            let distance = distance - offset;
+            if distance - extent > 0.0 {
+                return RegionClassification::Outside;
+            }
+            if distance + extent > 0.0 {
+                classification = RegionClassification::Intersecting;
+            }
+        }
+        classification
+    }
+}
+
+impl ContainsPoint2d for HalfPlaneRegion2d {
+    #[inline]
+    fn contains_point(&self, point: Vec2) -> bool {
+        HalfPlaneRegion2d::contains_point(self, point)
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for HalfPlaneRegion2d {
+    #[inline(always)]
+    fn intersects(&self, aabb: &Aabb2d) -> bool {
+        self.intersects_aabb(aabb)
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for HalfPlaneRegion2d {
+    #[inline(always)]
+    fn intersects(&self, circle: &BoundingCircle) -> bool {
+        self.classify_circle(circle) != RegionClassification::Outside
+    }
+}
+
+#[cfg(test)]
+mod half_plane_region_tests {
+    use super::{HalfPlaneRegion2d, RegionClassification};
+    use crate::{
+        bounding::{Aabb2d, BoundingCircle, BoundingVolume, IntersectsVolume},
+        Dir2, Vec2,
+    };
+
+    /// A triangular region with vertices at `(0, 0)`, `(4, 0)` and `(0, 4)`.
+    fn triangle() -> HalfPlaneRegion2d {
+        HalfPlaneRegion2d::new([
+            (Dir2::NEG_X, 0.0),
+            (Dir2::NEG_Y, 0.0),
+            (
+                Dir2::new(Vec2::ONE).unwrap(),
+                2.0 * core::f32::consts::SQRT_2,
+            ),
+        ])
+    }
+
+    #[test]
+    fn contains_point() {
+        let region = triangle();
+        assert!(region.contains_point(Vec2::new(1.0, 1.0)));
+        assert!(region.contains_point(Vec2::new(0.0, 0.0)));
+        assert!(!region.contains_point(Vec2::new(3.0, 3.0)));
+        assert!(!region.contains_point(Vec2::new(-1.0, 1.0)));
+
+        // An empty region contains everything
+        assert!(HalfPlaneRegion2d::default().contains_point(Vec2::splat(1e10)));
+    }
+
+    #[test]
+    fn classify() {
+        let region = triangle();
+        for (test, aabb, expected) in &[
+            (
+                "inside",
+                Aabb2d::new(Vec2::new(1.0, 1.0), Vec2::splat(0.5)),
+                RegionClassification::Inside,
+            ),
+            (
+                "crossing the diagonal",
+                Aabb2d::new(Vec2::new(2.0, 2.0), Vec2::splat(0.5)),
+                RegionClassification::Intersecting,
+            ),
+            (
+                "crossing an axis",
+                Aabb2d::new(Vec2::new(1.0, 0.0), Vec2::splat(0.5)),
+                RegionClassification::Intersecting,
+            ),
+            (
+                "enclosing the region",
+                Aabb2d::new(Vec2::new(2.0, 2.0), Vec2::splat(10.0)),
+                RegionClassification::Intersecting,
+            ),
+            (
+                "outside the diagonal",
+                Aabb2d::new(Vec2::new(4.0, 4.0), Vec2::splat(0.5)),
+                RegionClassification::Outside,
+            ),
+            (
+                "outside an axis",
+                Aabb2d::new(Vec2::new(-2.0, 1.0), Vec2::splat(0.5)),
+                RegionClassification::Outside,
+            ),
+        ] {
+            let classification = region.classify(aabb);
+            assert_eq!(
+                classification, *expected,
+                "Case:\n  Test: {test:?}\n  Classification: {classification:?}\n  Expected: {expected:?}",
+            );
+            assert_eq!(
+                region.contains_aabb(aabb),
+                *expected == RegionClassification::Inside,
+                "Case:\n  Test: {test:?}",
+            );
+            assert_eq!(
+                region.intersects_aabb(aabb),
+                *expected != RegionClassification::Outside,
+                "Case:\n  Test: {test:?}",
+            );
+            assert_eq!(region.intersects(aabb), region.intersects_aabb(aabb));
+        }
+    }
+
+    #[test]
+    fn classify_circle() {
+        let region = triangle();
+        let inside = BoundingCircle::new(Vec2::new(1.0, 1.0), 0.5);
+        let intersecting = BoundingCircle::new(Vec2::new(2.0, 2.0), 0.5);
+        let outside = BoundingCircle::new(Vec2::new(1.0, -1.0), 0.5);
+        assert_eq!(
+            region.classify_circle(&inside),
+            RegionClassification::Inside
+        );
+        assert_eq!(
+            region.classify_circle(&intersecting),
+            RegionClassification::Intersecting
+        );
+        assert_eq!(
+            region.classify_circle(&outside),
+            RegionClassification::Outside
+        );
+        assert!(region.intersects(&intersecting));
+        assert!(!region.intersects(&outside));
+    }
+
+    #[test]
+    fn from_aabb() {
+        let aabb = Aabb2d::new(Vec2::new(1.0, -2.0), Vec2::new(2.0, 1.0));
+        let mut region = HalfPlaneRegion2d::from_aabb(&aabb);
+        assert!(region.contains_aabb(&aabb));
+        assert!(region.contains_aabb(&Aabb2d::new(Vec2::new(1.0, -2.0), Vec2::splat(0.5))));
+        assert!(!region.contains_aabb(&aabb.grow(Vec2::splat(0.1))));
+        assert!(!region.intersects_aabb(&Aabb2d::new(Vec2::new(5.0, 0.0), Vec2::ONE)));
+
+        // Cutting off everything right of the center
+        region.push(Dir2::X, 1.0);
+        assert_eq!(region.classify(&aabb), RegionClassification::Intersecting);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{ops, Dir3, Vec3};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A cone of directions in 3D space, bounding a set of directions rather than a set of points.
+///
+/// The cone contains every direction that is at most `half_angle` radians away from its `axis`.
+/// This is useful to cull groups of directional items at once, like clusters of lights
+/// or the normals of a group of triangles facing away from the camera.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_math::{bounding::BoundingCone3d, Dir3, Vec3};
+/// let directions = [Dir3::X, Dir3::Y, Dir3::from_xyz(1.0, 1.0, 0.0).unwrap()];
+/// let cone = BoundingCone3d::from_directions(&directions).unwrap();
+///
+/// assert!(directions.iter().all(|&direction| cone.contains(direction)));
+/// assert!(!cone.contains(Dir3::Z));
+///
+/// assert_eq!(BoundingCone3d::from_directions(&[]), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct BoundingCone3d {
+    /// The direction at the center of the cone
+    pub axis: Dir3,
+    /// The largest angle between the axis and a direction in the cone, in radians.
+    ///
+    /// A half angle of `π` or more contains every direction.
+    pub half_angle: f32,
+}
+
+impl BoundingCone3d {
+    /// Constructs a bounding cone from its axis and half angle in radians.
+    #[inline(always)]
+    pub fn new(axis: Dir3, half_angle: f32) -> Self {
+        debug_assert!(
+            half_angle >= 0.0,
+            "the half angle of a bounding cone must not be negative"
+        );
+        Self { axis, half_angle }
+    }
+
+    /// Computes a bounding cone containing all of the given `directions`.
+    ///
+    /// The axis of the cone is the average of the directions, and the half angle is the largest angle
+    /// between the axis and one of the directions. This is conservative, but not always the smallest cone.
+    /// If the directions cancel out, like two opposite directions, the first direction is used as the axis.
+    ///
+    /// Returns `None` if there are no directions. A single direction gives a cone with a half angle of zero.
+    pub fn from_directions(directions: &[Dir3]) -> Option<Self> {
+        let axis = match directions {
+            [] => return None,
+            [direction] => return Some(Self::new(*direction, 0.0)),
+            [first, ..] => {
+                let sum: Vec3 = directions.iter().map(Dir3::as_vec3).sum();
+                Dir3::new(sum).unwrap_or(*first)
+            }
+        };
+        let half_angle = directions
+            .iter()
+            .map(|&direction| angle_between(axis, direction))
+            .fold(0.0, f32::max);
+        Some(Self::new(axis, half_angle))
+    }
+
+    /// Checks if the given `direction` is within the cone, including its boundary.
+    #[inline(always)]
+    pub fn contains(&self, direction: Dir3) -> bool {
+        angle_between(self.axis, direction) <= self.half_angle
+    }
+}
+
+/// Computes the angle between two directions in radians, between `0` and `π`.
+///
+/// This is more precise than taking the arccosine of the dot product for nearly parallel directions.
+#[inline(always)]
+fn angle_between(a: Dir3, b: Dir3) -> f32 {
+    ops::atan2(a.cross(*b).length(), a.dot(*b))
+}
+
+#[cfg(test)]
+mod bounding_cone_tests {
+    use super::BoundingCone3d;
+    use crate::{Dir3, Quat};
+    use approx::assert_relative_eq;
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn from_directions() {
+        assert_eq!(BoundingCone3d::from_directions(&[]), None);
+
+        let single = Dir3::from_xyz(1.0, 2.0, 3.0).unwrap();
+        let cone = BoundingCone3d::from_directions(&[single]).unwrap();
+        assert_eq!(cone, BoundingCone3d::new(single, 0.0));
+        assert!(cone.contains(single));
+        assert!(!cone.contains(Dir3::X));
+
+        // The directions are spread evenly around the Z axis, so the cone is centered on it
+        let directions: [Dir3; 5] = core::array::from_fn(|i| {
+            Quat::from_rotation_z(i as f32 * 2.0 * PI / 5.0)
+                * Quat::from_rotation_y(FRAC_PI_4)
+                * Dir3::Z
+        });
+        let cone = BoundingCone3d::from_directions(&directions).unwrap();
+        assert_relative_eq!(cone.axis.as_vec3(), Dir3::Z.as_vec3(), epsilon = 1e-6);
+        assert_relative_eq!(cone.half_angle, FRAC_PI_4, epsilon = 1e-6);
+        for direction in directions {
+            assert!(cone.contains(direction));
+        }
+        assert!(cone.contains(Dir3::Z));
+        assert!(!cone.contains(Dir3::X));
+        assert!(!cone.contains(Dir3::NEG_Z));
+    }
+
+    #[test]
+    fn opposite_directions() {
+        // The average is zero, so the cone falls back to the first direction
+        let cone = BoundingCone3d::from_directions(&[Dir3::X, Dir3::NEG_X]).unwrap();
+        assert_eq!(cone.axis, Dir3::X);
+        assert_relative_eq!(cone.half_angle, PI);
+        assert!(cone.contains(Dir3::NEG_X));
+        assert!(cone.contains(Dir3::Y));
+    }
+
+    #[test]
+    fn contains() {
+        let cone = BoundingCone3d::new(Dir3::Y, FRAC_PI_4);
+        assert!(cone.contains(Dir3::Y));
+        assert!(cone.contains(Dir3::from_xyz(0.5, 1.0, 0.0).unwrap()));
+        assert!(!cone.contains(Dir3::from_xyz(1.5, 1.0, 0.0).unwrap()));
+        assert!(!cone.contains(Dir3::NEG_Y));
+
+        let hemisphere = BoundingCone3d::new(Dir3::Y, FRAC_PI_2);
+        assert!(hemisphere.contains(Dir3::X));
+        assert!(!hemisphere.contains(Dir3::from_xyz(1.0, -0.1, 0.0).unwrap()));
+    }
+}
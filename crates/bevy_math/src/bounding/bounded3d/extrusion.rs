@@ -162,10 +162,7 @@ impl BoundedExtrusion for BoxedPolygon {
 impl BoundedExtrusion for RegularPolygon {
     fn extrusion_aabb_3d(&self, half_depth: f32, isometry: impl Into<Isometry3d>) -> Aabb3d {
         let isometry = isometry.into();
-        let aabb = Aabb3d::from_point_cloud(
-            isometry,
-            self.vertices(0.).into_iter().map(|v| v.extend(0.)),
-        );
+        let aabb = Aabb3d::from_point_cloud(isometry, self.vertices(0.).map(|v| v.extend(0.)));
         let depth = isometry.rotation * Vec3A::new(0., 0., half_depth);
 
         aabb.grow(depth.abs())
@@ -447,7 +444,7 @@ mod tests {
         );
         assert_eq!(
             aabb.half_size(),
-            Vec3A::new(1.9498558, 2.7584014, 2.7584019)
+            Vec3A::new(1.9498558, 2.7584014, 2.7584016)
         );
 
         let bounding_sphere = extrusion.bounding_sphere(isometry);
@@ -1,3 +1,4 @@
+mod cone;
 mod extrusion;
 mod primitive_impls;
 
@@ -6,7 +7,7 @@ use glam::Mat3;
 use super::{BoundingVolume, IntersectsVolume};
 use crate::{
     ops::{self, FloatPow},
-    Isometry3d, Quat, Vec3A,
+    Affine3A, BVec3A, Isometry3d, Quat, Vec3A,
 };
 
 #[cfg(feature = "bevy_reflect")]
@@ -16,6 +17,7 @@ use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
+pub use cone::BoundingCone3d;
 pub use extrusion::BoundedExtrusion;
 
 /// Computes the geometric center of the given set of points.
@@ -41,7 +43,27 @@ pub trait Bounded3d {
 }
 
 /// A 3D axis-aligned bounding box
+///
+/// # Memory layout
+///
+/// The type is `#[repr(C)]`, and consists of two [`Vec3A`]s in the order `min`, `max`.
+/// Each is 16 bytes, with the last 4 bytes being padding, for a total size of 32 bytes.
+/// This matches the following WGSL struct:
+///
+/// ```wgsl
+/// struct Aabb3d {
+///     min: vec3<f32>,
+///     max: vec3<f32>,
+/// }
+/// ```
+///
+/// With the `bytemuck` feature, the type implements `bytemuck::AnyBitPattern`
+/// and `bytemuck::Zeroable`, so that it can be read from bytes, for example from a GPU readback buffer.
+/// It does not implement `bytemuck::Pod`, because the fourth lane of a [`Vec3A`] is padding
+/// that may be uninitialized. To upload it to the GPU, convert it to a type without padding, such as [`Vec4`](crate::Vec4)s.
+#[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::AnyBitPattern))]
 #[cfg_attr(
     feature = "bevy_reflect",
     derive(Reflect),
@@ -74,6 +96,8 @@ impl Aabb3d {
     /// Computes the smallest [`Aabb3d`] containing the given set of points,
     /// transformed by the rotation and translation of the given isometry.
     ///
+    /// The points are transformed as they are consumed in a single pass, without collecting the iterator.
+    ///
     /// # Panics
     ///
     /// Panics if the given set of points is empty.
@@ -101,6 +125,17 @@ impl Aabb3d {
         }
     }
 
+    /// Computes the smallest [`Aabb3d`] containing all of the given AABBs, in a single pass.
+    ///
+    /// This is useful for computing the bounds of a hierarchy from the bounds of its children.
+    ///
+    /// Returns `None` if the iterator is empty.
+    #[doc(alias = "merged")]
+    #[inline(always)]
+    pub fn from_volumes(volumes: impl IntoIterator<Item = Aabb3d>) -> Option<Aabb3d> {
+        volumes.into_iter().reduce(|acc, aabb| acc.merge(&aabb))
+    }
+
     /// Computes the smallest [`BoundingSphere`] containing this [`Aabb3d`].
     #[inline(always)]
     pub fn bounding_sphere(&self) -> BoundingSphere {
@@ -117,6 +152,78 @@ impl Aabb3d {
         // Clamp point coordinates to the AABB
         point.into().clamp(self.min, self.max)
     }
+
+    /// Returns the eight corners of the AABB.
+    ///
+    /// The corner at index `i` is on the maximum side of the X axis if bit 0 of `i` is set,
+    /// on the maximum side of the Y axis if bit 1 is set, and on the maximum side of the Z axis
+    /// if bit 2 is set, like the octants returned by [`Aabb3d::octants`]. The first corner is
+    /// [`Aabb3d::min`], and the last one is [`Aabb3d::max`].
+    #[inline]
+    pub fn corners(&self) -> [Vec3A; 8] {
+        core::array::from_fn(|i| {
+            let positive = BVec3A::new(i & 1 != 0, i & 2 != 0, i & 4 != 0);
+            Vec3A::select(positive, self.max, self.min)
+        })
+    }
+
+    /// Splits the AABB into eight equally sized octants that share its center as a corner,
+    /// for example for building an octree.
+    ///
+    /// The octant at index `i` is in the positive half of the X axis if bit 0 of `i` is set,
+    /// in the positive half of the Y axis if bit 1 is set, and in the positive half
+    /// of the Z axis if bit 2 is set. The first octant is the one containing [`Aabb3d::min`],
+    /// and the last one is the one containing [`Aabb3d::max`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::{Aabb3d, BoundingVolume}, Vec3A};
+    /// let aabb = Aabb3d::new(Vec3A::ZERO, Vec3A::ONE);
+    /// let octants = aabb.octants();
+    /// assert_eq!(octants[0].center(), Vec3A::splat(-0.5));
+    /// // The octant with positive X and Z, and negative Y
+    /// assert_eq!(octants[0b101].center(), Vec3A::new(0.5, -0.5, 0.5));
+    /// ```
+    #[inline]
+    pub fn octants(&self) -> [Aabb3d; 8] {
+        let center = self.center();
+        core::array::from_fn(|i| {
+            let positive = BVec3A::new(i & 1 != 0, i & 2 != 0, i & 4 != 0);
+            Aabb3d {
+                min: Vec3A::select(positive, center, self.min),
+                max: Vec3A::select(positive, self.max, center),
+            }
+        })
+    }
+
+    /// Transforms this AABB by the given affine transformation, computing the smallest AABB
+    /// enclosing the transformed box.
+    ///
+    /// Unlike [`BoundingVolume::transformed_by`], this also supports scaling, including
+    /// non-uniform and negative scaling, and shearing. Like for rotations, the result may not
+    /// fit the transformed shape as tightly as the original AABB fit the shape.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::Aabb3d, Affine3A, Quat, Vec3, Vec3A};
+    /// let aabb = Aabb3d::new(Vec3A::ZERO, Vec3A::ONE);
+    /// let affine = Affine3A::from_scale_rotation_translation(
+    ///     Vec3::new(2.0, 1.0, 1.0),
+    ///     Quat::from_rotation_z(core::f32::consts::FRAC_PI_2),
+    ///     Vec3::new(0.0, 0.0, 5.0),
+    /// );
+    /// let transformed = aabb.transformed_by_affine(affine);
+    /// // The X axis is scaled first and then rotated onto the Y axis
+    /// assert!(transformed.max.abs_diff_eq(Vec3A::new(1.0, 2.0, 6.0), 1e-6));
+    /// ```
+    #[inline(always)]
+    pub fn transformed_by_affine(&self, affine: Affine3A) -> Aabb3d {
+        // Each axis of the result is the sum of the extents of the transformed half-size along it
+        let half_size = affine.matrix3.abs() * self.half_size();
+        Self::new(affine.transform_point3a(self.center()), half_size)
+    }
 }
 
 impl BoundingVolume for Aabb3d {
@@ -279,9 +386,40 @@ mod aabb3d_tests {
     use super::Aabb3d;
     use crate::{
         bounding::{BoundingSphere, BoundingVolume, IntersectsVolume},
-        ops, Quat, Vec3, Vec3A,
+        ops, Affine3A, Isometry3d, Quat, Vec3, Vec3A,
     };
 
+    #[test]
+    fn transformed_by_affine() {
+        let a = Aabb3d::new(Vec3A::new(1.0, 0.0, -1.0), Vec3A::new(2.0, 1.0, 0.5));
+
+        // Without scaling, this matches transforming by a translation and rotation
+        let rotation = Quat::from_euler(glam::EulerRot::XYZ, 0.3, -0.7, 1.1);
+        let translation = Vec3::new(0.0, 3.0, 2.0);
+        let transformed =
+            a.transformed_by_affine(Affine3A::from_rotation_translation(rotation, translation));
+        let expected = a.transformed_by(translation, rotation);
+        assert_relative_eq!(transformed.min, expected.min, epsilon = 1e-5);
+        assert_relative_eq!(transformed.max, expected.max, epsilon = 1e-5);
+
+        // Non-uniform and negative scaling
+        let transformed = a.transformed_by_affine(Affine3A::from_scale(Vec3::new(3.0, -0.5, 2.0)));
+        assert_eq!(transformed.min, Vec3A::new(-3.0, -0.5, -3.0));
+        assert_eq!(transformed.max, Vec3A::new(9.0, 0.5, -1.0));
+
+        // The result is the smallest AABB enclosing the transformed corners
+        let affine = Affine3A::from_scale_rotation_translation(
+            Vec3::new(2.0, 0.5, 1.5),
+            rotation,
+            Vec3::ONE,
+        );
+        let transformed = a.transformed_by_affine(affine);
+        let corners = a.corners().map(|corner| affine.transform_point3a(corner));
+        let expected = Aabb3d::from_point_cloud(Isometry3d::IDENTITY, corners.iter().copied());
+        assert_relative_eq!(transformed.min, expected.min, epsilon = 1e-5);
+        assert_relative_eq!(transformed.max, expected.max, epsilon = 1e-5);
+    }
+
     #[test]
     fn center() {
         let aabb = Aabb3d {
@@ -356,6 +494,23 @@ mod aabb3d_tests {
         assert!(!b.contains(&merged));
     }
 
+    #[test]
+    fn from_volumes() {
+        assert_eq!(Aabb3d::from_volumes([]), None);
+
+        let a = Aabb3d::new(Vec3A::ZERO, Vec3A::ONE);
+        assert_eq!(Aabb3d::from_volumes([a]), Some(a));
+
+        let volumes = [
+            a,
+            Aabb3d::new(Vec3A::new(3.0, 1.0, 0.0), Vec3A::splat(0.5)),
+            Aabb3d::new(Vec3A::new(-1.0, -4.0, 2.0), Vec3A::ONE),
+        ];
+        let merged = Aabb3d::from_volumes(volumes).unwrap();
+        assert_eq!(merged.min, Vec3A::new(-2.0, -5.0, -1.0));
+        assert_eq!(merged.max, Vec3A::new(3.5, 1.5, 3.0));
+    }
+
     #[test]
     fn grow() {
         let a = Aabb3d {
@@ -475,12 +630,100 @@ mod aabb3d_tests {
         assert!(aabb.intersects(&BoundingSphere::new(Vec3::NEG_ONE * 1.5, 1.0)));
         assert!(!aabb.intersects(&BoundingSphere::new(Vec3::ONE * 1.75, 1.0)));
     }
+
+    #[test]
+    fn corners() {
+        let aabb = Aabb3d {
+            min: Vec3A::new(-2.0, 1.0, 0.0),
+            max: Vec3A::new(2.0, 3.0, 8.0),
+        };
+        let corners = aabb.corners();
+        assert_eq!(corners[0], aabb.min);
+        assert_eq!(corners[0b001], Vec3A::new(2.0, 1.0, 0.0));
+        assert_eq!(corners[0b010], Vec3A::new(-2.0, 3.0, 0.0));
+        assert_eq!(corners[0b100], Vec3A::new(-2.0, 1.0, 8.0));
+        assert_eq!(corners[0b110], Vec3A::new(-2.0, 3.0, 8.0));
+        assert_eq!(corners[7], aabb.max);
+
+        // Each corner is in the octant with the same index
+        for (corner, octant) in corners.iter().zip(aabb.octants()) {
+            assert_eq!(octant.closest_point(*corner), *corner);
+        }
+    }
+
+    #[test]
+    fn octants() {
+        let aabb = Aabb3d {
+            min: Vec3A::new(-2.0, 1.0, 0.0),
+            max: Vec3A::new(2.0, 3.0, 8.0),
+        };
+        let octants = aabb.octants();
+        for (i, octant) in octants.iter().enumerate() {
+            assert_eq!(octant.half_size(), aabb.half_size() / 2.0);
+            assert!(aabb.contains(octant));
+            let sign = Vec3A::new(
+                (i & 1) as f32 * 2.0 - 1.0,
+                (i & 2) as f32 - 1.0,
+                (i & 4) as f32 / 2.0 - 1.0,
+            );
+            assert_eq!(
+                octant.center(),
+                aabb.center() + sign * aabb.half_size() / 2.0
+            );
+        }
+        assert_eq!(octants[0].min, aabb.min);
+        assert_eq!(octants[7].max, aabb.max);
+        let merged = octants
+            .iter()
+            .skip(1)
+            .fold(octants[0], |acc, octant| acc.merge(octant));
+        assert_eq!(merged, aabb);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_layout() {
+        assert_eq!(size_of::<Aabb3d>(), 32);
+        assert_eq!(core::mem::offset_of!(Aabb3d, max), 16);
+
+        let floats = [1.0_f32, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0];
+        let aabb: Aabb3d = bytemuck::cast(floats);
+        assert_eq!(aabb.min, Vec3A::new(1.0, 2.0, 3.0));
+        assert_eq!(aabb.max, Vec3A::new(4.0, 5.0, 6.0));
+        assert_eq!(
+            <Aabb3d as bytemuck::Zeroable>::zeroed(),
+            Aabb3d {
+                min: Vec3A::ZERO,
+                max: Vec3A::ZERO,
+            }
+        );
+    }
 }
 
 use crate::primitives::Sphere;
 
 /// A bounding sphere
+///
+/// # Memory layout
+///
+/// The type is `#[repr(C)]`, and consists of a [`Vec3A`] `center` followed by an `f32` `radius`.
+/// The center is 16 bytes, with the last 4 bytes being padding, so the radius is at an offset of 16 bytes,
+/// and the total size is 32 bytes. This matches the following WGSL struct:
+///
+/// ```wgsl
+/// struct BoundingSphere {
+///     center: vec3<f32>,
+///     @align(16) radius: f32,
+/// }
+/// ```
+///
+/// With the `bytemuck` feature, the type implements `bytemuck::AnyBitPattern`
+/// and `bytemuck::Zeroable`, so that it can be read from bytes, for example from a GPU readback buffer.
+/// It does not implement `bytemuck::Pod`, because the fourth lane of a [`Vec3A`] is padding
+/// that may be uninitialized. To upload it to the GPU, convert it to a type without padding, such as [`Vec4`](crate::Vec4)s.
+#[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::AnyBitPattern))]
 #[cfg_attr(
     feature = "bevy_reflect",
     derive(Reflect),
@@ -533,6 +776,61 @@ impl BoundingSphere {
         BoundingSphere::new(isometry * center, ops::sqrt(radius_squared))
     }
 
+    /// Computes a [`BoundingSphere`] containing the given set of points, using Ritter's algorithm.
+    ///
+    /// Returns `None` if the set of points is empty, and a sphere with a radius of zero
+    /// if it contains a single point.
+    ///
+    /// The algorithm makes two passes over the points, and is usually much tighter than
+    /// [`BoundingSphere::from_point_cloud`]. However, the sphere is not guaranteed to be the smallest possible,
+    /// and is typically a few percent larger than that.
+    #[inline]
+    pub fn enclosing(points: &[impl Copy + Into<Vec3A>]) -> Option<BoundingSphere> {
+        let first: Vec3A = (*points.first()?).into();
+        let farthest_from = |from: Vec3A| {
+            points
+                .iter()
+                .map(|&point| point.into())
+                .max_by(|a: &Vec3A, b: &Vec3A| {
+                    a.distance_squared(from)
+                        .total_cmp(&b.distance_squared(from))
+                })
+                .unwrap()
+        };
+
+        // Start with a sphere spanning two points that are far apart
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+        let mut center = (a + b) / 2.0;
+        let mut radius = a.distance(b) / 2.0;
+
+        // Grow the sphere just enough to contain each point outside of it
+        for &point in points {
+            let point: Vec3A = point.into();
+            let distance = point.distance(center);
+            if distance > radius {
+                let new_radius = (radius + distance) / 2.0;
+                center += (point - center) * ((new_radius - radius) / distance);
+                radius = new_radius;
+            }
+        }
+
+        Some(BoundingSphere::new(center, radius))
+    }
+
+    /// Computes a [`BoundingSphere`] containing all of the given bounding spheres,
+    /// by merging them pairwise in order.
+    ///
+    /// The bounding sphere is not guaranteed to be the smallest possible.
+    ///
+    /// Returns `None` if the iterator is empty.
+    #[inline(always)]
+    pub fn from_volumes(
+        volumes: impl IntoIterator<Item = BoundingSphere>,
+    ) -> Option<BoundingSphere> {
+        volumes.into_iter().reduce(|acc, sphere| acc.merge(&sphere))
+    }
+
     /// Get the radius of the bounding sphere
     #[inline(always)]
     pub fn radius(&self) -> f32 {
@@ -682,6 +980,49 @@ mod bounding_sphere_tests {
         bounding::{BoundingVolume, IntersectsVolume},
         ops, Quat, Vec3, Vec3A,
     };
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn enclosing() {
+        assert_eq!(BoundingSphere::enclosing(&[] as &[Vec3]), None);
+        assert_eq!(
+            BoundingSphere::enclosing(&[Vec3::new(1.0, 2.0, 3.0)]),
+            Some(BoundingSphere::new(Vec3::new(1.0, 2.0, 3.0), 0.0))
+        );
+        assert_eq!(
+            BoundingSphere::enclosing(&[Vec3::X, Vec3::NEG_X]),
+            Some(BoundingSphere::new(Vec3::ZERO, 1.0))
+        );
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        for _ in 0..20 {
+            let center = Vec3::new(
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+            );
+            // Points in a unit sphere around the center
+            let points: [Vec3; 200] = core::array::from_fn(|_| {
+                let offset = Vec3::new(
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                );
+                center + offset.clamp_length_max(1.0)
+            });
+
+            let sphere = BoundingSphere::enclosing(&points).unwrap();
+            for point in &points {
+                assert!(
+                    Vec3A::from(*point).distance(sphere.center) <= sphere.radius() + 1e-5,
+                    "Sphere: {sphere:?}\n  Point: {point:?}",
+                );
+            }
+            // The sphere is not minimal, but should not be much larger than the unit sphere
+            assert!(sphere.radius() <= 1.2, "Sphere: {sphere:?}");
+        }
+    }
 
     #[test]
     fn area() {
@@ -733,6 +1074,25 @@ mod bounding_sphere_tests {
         assert_eq!(merged.radius(), b.radius());
     }
 
+    #[test]
+    fn from_volumes() {
+        assert_eq!(BoundingSphere::from_volumes([]), None);
+
+        let a = BoundingSphere::new(Vec3::ZERO, 1.0);
+        assert_eq!(BoundingSphere::from_volumes([a]), Some(a));
+
+        let volumes = [
+            a,
+            BoundingSphere::new(Vec3::new(4.0, 0.0, 0.0), 1.0),
+            BoundingSphere::new(Vec3::new(1.0, 0.5, 0.0), 0.5),
+        ];
+        let merged = BoundingSphere::from_volumes(volumes).unwrap();
+        for volume in &volumes {
+            assert!(merged.contains(volume));
+        }
+        assert_relative_eq!(merged.radius(), 3.0);
+    }
+
     #[test]
     fn merge_identical() {
         let a = BoundingSphere::new(Vec3::ONE, 5.);
@@ -804,4 +1164,15 @@ mod bounding_sphere_tests {
         assert!(sphere.intersects(&BoundingSphere::new(Vec3::NEG_ONE * 1.1, 1.0)));
         assert!(!sphere.intersects(&BoundingSphere::new(Vec3::ONE * 1.2, 1.0)));
     }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_layout() {
+        assert_eq!(size_of::<BoundingSphere>(), 32);
+        assert_eq!(core::mem::offset_of!(BoundingSphere, sphere), 16);
+
+        let floats = [1.0_f32, 2.0, 3.0, 0.0, 4.0, 0.0, 0.0, 0.0];
+        let sphere: BoundingSphere = bytemuck::cast(floats);
+        assert_eq!(sphere, BoundingSphere::new(Vec3A::new(1.0, 2.0, 3.0), 4.0));
+    }
 }
@@ -0,0 +1,861 @@
+use crate::{
+    bounding::{Aabb2d, BoundingVolume, IntersectsVolume, RayCast2d},
+    Vec2,
+};
+
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::cmp::Ordering;
+
+/// A handle to a volume stored in a [`DynamicAabbTree2d`].
+///
+/// Handles are only valid for the tree that returned them, and may be reused
+/// for new volumes after the volume they refer to is removed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProxyId(usize);
+
+impl ProxyId {
+    /// Returns the index of the node storing the volume within the tree.
+    #[inline(always)]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Whether [`DynamicAabbTree2d::ray_cast_all`] should keep visiting the volumes along the ray.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RayContinuation {
+    /// Keep visiting the volumes further along the ray.
+    Continue,
+    /// Stop the ray cast, without visiting any more volumes.
+    Stop,
+}
+
+#[derive(Clone, Debug)]
+enum NodeKind<T> {
+    /// A node storing a volume inserted by the user.
+    Leaf(T),
+    /// A node bounding its two children.
+    Internal([usize; 2]),
+    /// An unused node, linking to the next unused node.
+    Free(Option<usize>),
+}
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    /// The bounds of the node. For leaves, this is the fattened AABB.
+    aabb: Aabb2d,
+    parent: Option<usize>,
+    /// The height of the subtree rooted at this node, where leaves have a height of 0.
+    height: u32,
+    kind: NodeKind<T>,
+}
+
+/// A dynamic bounding volume hierarchy of [`Aabb2d`]s, each associated with some data.
+///
+/// This is a binary tree where each leaf stores a volume, and each internal node bounds its children.
+/// Queries only need to visit the parts of the tree that overlap them, which makes it possible to find
+/// intersecting volumes without testing every pair.
+///
+/// To make moving volumes cheap, each leaf stores a "fat" AABB, which is the inserted AABB grown by
+/// a [margin](Self::margin). [Updating](Self::update) a volume only modifies the tree if the new AABB
+/// is no longer contained in the fat AABB. As a consequence, queries test against the fat AABBs,
+/// and return candidates that may not intersect the query themselves.
+///
+/// The tree is kept balanced using tree rotations, so queries stay fast regardless of insertion order.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_math::{bounding::{Aabb2d, broadphase::DynamicAabbTree2d}, Vec2};
+/// let mut tree = DynamicAabbTree2d::new(0.1);
+/// let a = tree.insert(Aabb2d::new(Vec2::ZERO, Vec2::ONE), "a");
+/// let b = tree.insert(Aabb2d::new(Vec2::splat(5.0), Vec2::ONE), "b");
+///
+/// let mut hits = Vec::new();
+/// tree.query(&Aabb2d::new(Vec2::ONE, Vec2::splat(0.25)), |id, data| {
+///     hits.push((id, *data));
+/// });
+/// assert_eq!(hits, [(a, "a")]);
+///
+/// // Move `b` onto the query
+/// tree.update(b, Aabb2d::new(Vec2::splat(2.0), Vec2::ONE));
+/// hits.clear();
+/// tree.query(&Aabb2d::new(Vec2::ONE, Vec2::splat(0.25)), |id, data| {
+///     hits.push((id, *data));
+/// });
+/// assert_eq!(hits.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DynamicAabbTree2d<T> {
+    nodes: Vec<Node<T>>,
+    root: Option<usize>,
+    free_list: Option<usize>,
+    margin: f32,
+    len: usize,
+}
+
+impl<T> Default for DynamicAabbTree2d<T> {
+    /// Creates an empty tree with a margin of `0.1`.
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+impl<T> DynamicAabbTree2d<T> {
+    /// Creates an empty tree, where the AABBs of the leaves are grown by the given `margin`.
+    ///
+    /// A larger margin makes updates for slowly moving volumes cheaper,
+    /// at the cost of returning more false positives from queries.
+    #[inline]
+    pub fn new(margin: f32) -> Self {
+        debug_assert!(margin >= 0.0);
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            free_list: None,
+            margin,
+            len: 0,
+        }
+    }
+
+    /// Returns the margin by which the AABBs of the leaves are grown.
+    #[inline(always)]
+    pub fn margin(&self) -> f32 {
+        self.margin
+    }
+
+    /// Returns the number of volumes in the tree.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree contains no volumes.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the height of the tree, which is `0` for an empty tree or a tree with a single volume.
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.root.map_or(0, |root| self.nodes[root].height)
+    }
+
+    /// Removes all volumes from the tree, invalidating all [`ProxyId`]s.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.root = None;
+        self.free_list = None;
+        self.len = 0;
+    }
+
+    /// Inserts a volume with the given `aabb` and `data` into the tree,
+    /// returning a [`ProxyId`] that can be used to update or remove it.
+    pub fn insert(&mut self, aabb: Aabb2d, data: T) -> ProxyId {
+        let leaf = self.allocate_node(aabb.grow(Vec2::splat(self.margin)), NodeKind::Leaf(data));
+        self.insert_leaf(leaf);
+        self.len += 1;
+        ProxyId(leaf)
+    }
+
+    /// Removes the volume with the given `id` from the tree, returning its data.
+    ///
+    /// Returns `None` if there is no volume with the given `id`.
+    pub fn remove(&mut self, id: ProxyId) -> Option<T> {
+        if !self.is_leaf(id.0) {
+            return None;
+        }
+        self.remove_leaf(id.0);
+        self.len -= 1;
+        match self.free_node(id.0) {
+            NodeKind::Leaf(data) => Some(data),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Moves the volume with the given `id` to a new `aabb`.
+    ///
+    /// The tree is only restructured if the new AABB is not contained in the fat AABB of the volume,
+    /// or if it has shrunk enough that the fat AABB is no longer a good fit.
+    /// Returns `true` if the tree was restructured.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no volume with the given `id`.
+    pub fn update(&mut self, id: ProxyId, aabb: Aabb2d) -> bool {
+        assert!(self.is_leaf(id.0), "invalid proxy {id:?}");
+
+        let fat_aabb = self.nodes[id.0].aabb;
+        if fat_aabb.contains(&aabb)
+            && aabb
+                .grow(Vec2::splat(4.0 * self.margin))
+                .contains(&fat_aabb)
+        {
+            return false;
+        }
+
+        self.remove_leaf(id.0);
+        self.nodes[id.0].aabb = aabb.grow(Vec2::splat(self.margin));
+        self.insert_leaf(id.0);
+        true
+    }
+
+    /// Returns a reference to the data of the volume with the given `id`.
+    #[inline]
+    pub fn get(&self, id: ProxyId) -> Option<&T> {
+        match &self.nodes.get(id.0)?.kind {
+            NodeKind::Leaf(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the data of the volume with the given `id`.
+    #[inline]
+    pub fn get_mut(&mut self, id: ProxyId) -> Option<&mut T> {
+        match &mut self.nodes.get_mut(id.0)?.kind {
+            NodeKind::Leaf(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns the fat AABB of the volume with the given `id`,
+    /// which is the AABB it was last restructured with, grown by the [margin](Self::margin).
+    #[inline]
+    pub fn fat_aabb(&self, id: ProxyId) -> Option<Aabb2d> {
+        self.is_leaf(id.0).then(|| self.nodes[id.0].aabb)
+    }
+
+    /// Returns an iterator over the [`ProxyId`]s and data of all volumes in the tree.
+    pub fn iter(&self) -> impl Iterator<Item = (ProxyId, &T)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, node)| match &node.kind {
+                NodeKind::Leaf(data) => Some((ProxyId(index), data)),
+                _ => None,
+            })
+    }
+
+    /// Calls `callback` for every volume whose fat AABB intersects the given `aabb`.
+    pub fn query(&self, aabb: &Aabb2d, mut callback: impl FnMut(ProxyId, &T)) {
+        let Some(root) = self.root else {
+            return;
+        };
+
+        let mut stack = Vec::new();
+        stack.push(root);
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !node.aabb.intersects(aabb) {
+                continue;
+            }
+            match &node.kind {
+                NodeKind::Leaf(data) => callback(ProxyId(index), data),
+                NodeKind::Internal(children) => stack.extend(children),
+                NodeKind::Free(_) => unreachable!(),
+            }
+        }
+    }
+
+    /// Casts a ray through the tree, calling `callback` for every volume whose fat AABB is hit.
+    ///
+    /// The callback should perform an exact intersection test against the volume, and return the
+    /// distance of the hit, if any. Only volumes whose fat AABB is closer than the closest hit so far
+    /// are visited, and the closest hit is returned.
+    ///
+    /// The fat AABBs are always treated as solid, regardless of [`RayCast2d::solid`],
+    /// since a ray starting inside a fat AABB can still hit the volume inside of it.
+    pub fn ray_cast(
+        &self,
+        ray: &RayCast2d,
+        mut callback: impl FnMut(ProxyId, &T) -> Option<f32>,
+    ) -> Option<(ProxyId, f32)> {
+        let root = self.root?;
+
+        let mut ray = ray.clone().with_solid(true);
+        let mut closest = None;
+        let mut stack = Vec::new();
+        stack.push(root);
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if ray.aabb_intersection_at(&node.aabb).is_none() {
+                continue;
+            }
+            match &node.kind {
+                NodeKind::Leaf(data) => {
+                    if let Some(distance) = callback(ProxyId(index), data) {
+                        if distance <= ray.max {
+                            ray.max = distance;
+                            closest = Some((ProxyId(index), distance));
+                        }
+                    }
+                }
+                NodeKind::Internal(children) => stack.extend(children),
+                NodeKind::Free(_) => unreachable!(),
+            }
+        }
+        closest
+    }
+
+    /// Casts a ray through the tree, calling `callback` for every volume whose fat AABB is hit,
+    /// in order of the distance at which the ray enters the fat AABB.
+    ///
+    /// The callback receives the entry distance, and returns whether to [continue](RayContinuation::Continue)
+    /// visiting the volumes further along the ray, or to [stop](RayContinuation::Stop), for example
+    /// after the first volume that is actually hit. Subtrees whose bounds are missed by the ray are skipped.
+    ///
+    /// As with [`Self::ray_cast`], the fat AABBs are always treated as solid, so a volume whose fat AABB
+    /// contains the origin of the ray is visited at a distance of zero. The order of volumes at
+    /// the same distance is unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::{Aabb2d, RayCast2d, broadphase::{DynamicAabbTree2d, RayContinuation}}, Dir2, Vec2};
+    /// let mut tree = DynamicAabbTree2d::new(0.0);
+    /// tree.insert(Aabb2d::new(Vec2::new(10.0, 0.0), Vec2::ONE), "far");
+    /// tree.insert(Aabb2d::new(Vec2::new(5.0, 0.0), Vec2::ONE), "near");
+    /// tree.insert(Aabb2d::new(Vec2::new(5.0, 5.0), Vec2::ONE), "missed");
+    ///
+    /// let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, 100.0);
+    /// let mut hits = Vec::new();
+    /// tree.ray_cast_all(&ray, |id, distance| {
+    ///     hits.push((*tree.get(id).unwrap(), distance));
+    ///     RayContinuation::Continue
+    /// });
+    /// assert_eq!(hits, [("near", 4.0), ("far", 9.0)]);
+    /// ```
+    pub fn ray_cast_all(
+        &self,
+        ray: &RayCast2d,
+        mut callback: impl FnMut(ProxyId, f32) -> RayContinuation,
+    ) {
+        let Some(root) = self.root else {
+            return;
+        };
+
+        let ray = ray.clone().with_solid(true);
+        // Visit the nodes from nearest to farthest. Children are contained in their parent,
+        // so they are never entered before it, and the leaves are reached in order.
+        let mut queue = BinaryHeap::new();
+        let push = |queue: &mut BinaryHeap<QueuedNode>, index: usize| {
+            if let Some(distance) = ray.aabb_intersection_at(&self.nodes[index].aabb) {
+                queue.push(QueuedNode { distance, index });
+            }
+        };
+        push(&mut queue, root);
+        while let Some(QueuedNode { distance, index }) = queue.pop() {
+            match &self.nodes[index].kind {
+                NodeKind::Leaf(_) => {
+                    if callback(ProxyId(index), distance) == RayContinuation::Stop {
+                        return;
+                    }
+                }
+                NodeKind::Internal(children) => {
+                    for &child in children {
+                        push(&mut queue, child);
+                    }
+                }
+                NodeKind::Free(_) => unreachable!(),
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn is_leaf(&self, index: usize) -> bool {
+        matches!(
+            self.nodes.get(index),
+            Some(Node {
+                kind: NodeKind::Leaf(_),
+                ..
+            })
+        )
+    }
+
+    #[inline(always)]
+    fn children(&self, index: usize) -> [usize; 2] {
+        match self.nodes[index].kind {
+            NodeKind::Internal(children) => children,
+            _ => unreachable!("node {index} is not an internal node"),
+        }
+    }
+
+    #[inline(always)]
+    fn set_children(&mut self, index: usize, children: [usize; 2]) {
+        self.nodes[index].kind = NodeKind::Internal(children);
+    }
+
+    /// Replaces `old_child` with `new_child` in the children of `parent`,
+    /// or makes `new_child` the root if `parent` is `None`.
+    fn replace_child(&mut self, parent: Option<usize>, old_child: usize, new_child: usize) {
+        match parent {
+            Some(parent) => {
+                let mut children = self.children(parent);
+                let slot = if children[0] == old_child { 0 } else { 1 };
+                children[slot] = new_child;
+                self.set_children(parent, children);
+            }
+            None => self.root = Some(new_child),
+        }
+        self.nodes[new_child].parent = parent;
+    }
+
+    fn allocate_node(&mut self, aabb: Aabb2d, kind: NodeKind<T>) -> usize {
+        let node = Node {
+            aabb,
+            parent: None,
+            height: 0,
+            kind,
+        };
+        match self.free_list {
+            Some(index) => {
+                let NodeKind::Free(next) = self.nodes[index].kind else {
+                    unreachable!("node {index} in the free list is in use")
+                };
+                self.free_list = next;
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn free_node(&mut self, index: usize) -> NodeKind<T> {
+        let kind = core::mem::replace(&mut self.nodes[index].kind, NodeKind::Free(self.free_list));
+        self.nodes[index].parent = None;
+        self.free_list = Some(index);
+        kind
+    }
+
+    fn insert_leaf(&mut self, leaf: usize) {
+        let Some(root) = self.root else {
+            self.root = Some(leaf);
+            self.nodes[leaf].parent = None;
+            return;
+        };
+
+        // Find the best sibling for the new leaf, using the perimeter as a cost metric
+        let leaf_aabb = self.nodes[leaf].aabb;
+        let mut index = root;
+        while let NodeKind::Internal(children) = self.nodes[index].kind {
+            let perimeter = self.nodes[index].aabb.perimeter();
+            let combined_perimeter = self.nodes[index].aabb.merge(&leaf_aabb).perimeter();
+
+            // The cost of creating a new parent for this node and the new leaf
+            let cost = 2.0 * combined_perimeter;
+
+            // The minimum cost of pushing the leaf further down the tree
+            let inheritance_cost = 2.0 * (combined_perimeter - perimeter);
+            let [cost1, cost2] = children.map(|child| {
+                let child_node = &self.nodes[child];
+                let combined = child_node.aabb.merge(&leaf_aabb).perimeter();
+                match child_node.kind {
+                    NodeKind::Leaf(_) => combined + inheritance_cost,
+                    _ => combined - child_node.aabb.perimeter() + inheritance_cost,
+                }
+            });
+
+            if cost < cost1 && cost < cost2 {
+                break;
+            }
+            index = if cost1 < cost2 {
+                children[0]
+            } else {
+                children[1]
+            };
+        }
+
+        // Create a new parent for the sibling and the new leaf
+        let sibling = index;
+        let old_parent = self.nodes[sibling].parent;
+        let new_parent = self.allocate_node(
+            self.nodes[sibling].aabb.merge(&leaf_aabb),
+            NodeKind::Internal([sibling, leaf]),
+        );
+        self.nodes[new_parent].height = self.nodes[sibling].height + 1;
+        self.replace_child(old_parent, sibling, new_parent);
+        self.nodes[sibling].parent = Some(new_parent);
+        self.nodes[leaf].parent = Some(new_parent);
+
+        self.refit_ancestors(old_parent);
+    }
+
+    fn remove_leaf(&mut self, leaf: usize) {
+        let Some(parent) = self.nodes[leaf].parent else {
+            self.root = None;
+            return;
+        };
+
+        // Replace the parent with the sibling of the leaf
+        let grandparent = self.nodes[parent].parent;
+        let [child1, child2] = self.children(parent);
+        let sibling = if child1 == leaf { child2 } else { child1 };
+        self.replace_child(grandparent, parent, sibling);
+        self.free_node(parent);
+        self.nodes[leaf].parent = None;
+
+        self.refit_ancestors(grandparent);
+    }
+
+    /// Walks up the tree from `index`, rebalancing the nodes and updating their heights and AABBs.
+    fn refit_ancestors(&mut self, mut index: Option<usize>) {
+        while let Some(current) = index {
+            let current = self.balance(current);
+            self.refit(current);
+            index = self.nodes[current].parent;
+        }
+    }
+
+    /// Updates the height and AABB of an internal node from its children.
+    #[inline(always)]
+    fn refit(&mut self, index: usize) {
+        let [child1, child2] = self.children(index);
+        self.nodes[index].height = 1 + self.nodes[child1].height.max(self.nodes[child2].height);
+        self.nodes[index].aabb = self.nodes[child1].aabb.merge(&self.nodes[child2].aabb);
+    }
+
+    /// Performs a tree rotation at `a` if the heights of its children differ by more than one,
+    /// returning the node that takes the place of `a`.
+    fn balance(&mut self, a: usize) -> usize {
+        if self.nodes[a].height < 2 {
+            return a;
+        }
+
+        let [b, c] = self.children(a);
+        let balance = self.nodes[c].height as i64 - self.nodes[b].height as i64;
+        if balance > 1 {
+            // Rotate `c` up
+            let [f, g] = self.children(c);
+            let parent = self.nodes[a].parent;
+            self.replace_child(parent, a, c);
+            self.nodes[a].parent = Some(c);
+
+            // Keep the taller grandchild under `c`, and move the shorter one under `a`
+            let (tall, short) = if self.nodes[f].height > self.nodes[g].height {
+                (f, g)
+            } else {
+                (g, f)
+            };
+            self.set_children(c, [a, tall]);
+            self.set_children(a, [b, short]);
+            self.nodes[short].parent = Some(a);
+            self.refit(a);
+            self.refit(c);
+            c
+        } else if balance < -1 {
+            // Rotate `b` up
+            let [d, e] = self.children(b);
+            let parent = self.nodes[a].parent;
+            self.replace_child(parent, a, b);
+            self.nodes[a].parent = Some(b);
+
+            // Keep the taller grandchild under `b`, and move the shorter one under `a`
+            let (tall, short) = if self.nodes[d].height > self.nodes[e].height {
+                (d, e)
+            } else {
+                (e, d)
+            };
+            self.set_children(b, [a, tall]);
+            self.set_children(a, [short, c]);
+            self.nodes[short].parent = Some(a);
+            self.refit(a);
+            self.refit(b);
+            b
+        } else {
+            a
+        }
+    }
+}
+
+/// A node waiting to be visited by [`DynamicAabbTree2d::ray_cast_all`].
+///
+/// The ordering is reversed, so that [`BinaryHeap`] pops the nearest node first.
+#[derive(Clone, Copy, Debug)]
+struct QueuedNode {
+    distance: f32,
+    index: usize,
+}
+
+impl PartialEq for QueuedNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueuedNode {}
+
+impl PartialOrd for QueuedNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(test)]
+mod dynamic_aabb_tree_tests {
+    use super::{DynamicAabbTree2d, NodeKind, ProxyId, RayContinuation};
+    use crate::{
+        bounding::{Aabb2d, BoundingVolume, IntersectsVolume, RayCast2d},
+        Dir2, Vec2,
+    };
+    use alloc::vec::Vec;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    /// Checks the structural invariants of the tree.
+    fn validate<T>(tree: &DynamicAabbTree2d<T>) {
+        let Some(root) = tree.root else {
+            assert_eq!(tree.len(), 0);
+            return;
+        };
+        assert_eq!(tree.nodes[root].parent, None);
+
+        let mut leaves = 0;
+        let mut stack = Vec::from([root]);
+        while let Some(index) = stack.pop() {
+            let node = &tree.nodes[index];
+            match node.kind {
+                NodeKind::Leaf(_) => {
+                    assert_eq!(node.height, 0);
+                    leaves += 1;
+                }
+                NodeKind::Internal(children) => {
+                    let [child1, child2] = children.map(|child| &tree.nodes[child]);
+                    for child in children {
+                        assert_eq!(tree.nodes[child].parent, Some(index));
+                        assert!(node.aabb.contains(&tree.nodes[child].aabb));
+                    }
+                    assert_eq!(node.height, 1 + child1.height.max(child2.height));
+                    assert!(
+                        child1.height.abs_diff(child2.height) <= 1,
+                        "unbalanced tree"
+                    );
+                    stack.extend(children);
+                }
+                NodeKind::Free(_) => panic!("free node {index} is reachable"),
+            }
+        }
+        assert_eq!(leaves, tree.len());
+    }
+
+    fn random_aabb(rng: &mut ChaCha8Rng) -> Aabb2d {
+        let center = Vec2::new(rng.gen_range(-50.0..50.0), rng.gen_range(-50.0..50.0));
+        let half_size = Vec2::new(rng.gen_range(0.1..3.0), rng.gen_range(0.1..3.0));
+        Aabb2d::new(center, half_size)
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut tree = DynamicAabbTree2d::new(0.0);
+        assert!(tree.is_empty());
+
+        let a = tree.insert(Aabb2d::new(Vec2::ZERO, Vec2::ONE), 'a');
+        let b = tree.insert(Aabb2d::new(Vec2::splat(4.0), Vec2::ONE), 'b');
+        let c = tree.insert(Aabb2d::new(Vec2::splat(-4.0), Vec2::ONE), 'c');
+        validate(&tree);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.get(b), Some(&'b'));
+
+        assert_eq!(tree.remove(b), Some('b'));
+        validate(&tree);
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.get(b), None);
+        assert_eq!(tree.remove(b), None);
+
+        // Removed slots are reused
+        let d = tree.insert(Aabb2d::new(Vec2::X * 4.0, Vec2::ONE), 'd');
+        validate(&tree);
+        let mut all: Vec<_> = tree.iter().map(|(_, data)| *data).collect();
+        all.sort();
+        assert_eq!(all, ['a', 'c', 'd']);
+
+        *tree.get_mut(d).unwrap() = 'e';
+        assert_eq!(tree.remove(a), Some('a'));
+        assert_eq!(tree.remove(c), Some('c'));
+        assert_eq!(tree.remove(d), Some('e'));
+        validate(&tree);
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 0);
+
+        // Ids that never existed are rejected
+        assert_eq!(tree.remove(ProxyId(100)), None);
+    }
+
+    #[test]
+    fn fat_aabb_update() {
+        let mut tree = DynamicAabbTree2d::new(0.5);
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        let id = tree.insert(aabb, ());
+        assert_eq!(tree.fat_aabb(id), Some(aabb.grow(Vec2::splat(0.5))));
+
+        // Small movements stay within the fat AABB
+        assert!(!tree.update(id, Aabb2d::new(Vec2::splat(0.25), Vec2::ONE)));
+        assert_eq!(tree.fat_aabb(id), Some(aabb.grow(Vec2::splat(0.5))));
+
+        // Larger movements restructure the tree
+        let moved = Aabb2d::new(Vec2::splat(2.0), Vec2::splat(3.0));
+        assert!(tree.update(id, moved));
+        assert_eq!(tree.fat_aabb(id), Some(moved.grow(Vec2::splat(0.5))));
+
+        // Shrinking a lot also refits the fat AABB
+        let shrunk = Aabb2d::new(Vec2::splat(2.0), Vec2::splat(0.01));
+        assert!(tree.update(id, shrunk));
+        assert_eq!(tree.fat_aabb(id), Some(shrunk.grow(Vec2::splat(0.5))));
+    }
+
+    #[test]
+    fn query_matches_brute_force() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut tree = DynamicAabbTree2d::new(0.2);
+        let mut volumes = Vec::new();
+
+        for i in 0..300 {
+            let aabb = random_aabb(&mut rng);
+            volumes.push((tree.insert(aabb, i), aabb));
+        }
+        validate(&tree);
+
+        // Move some volumes and remove others
+        for _ in 0..200 {
+            let index = rng.gen_range(0..volumes.len());
+            let aabb = random_aabb(&mut rng);
+            tree.update(volumes[index].0, aabb);
+            volumes[index].1 = aabb;
+        }
+        for _ in 0..100 {
+            let (id, _) = volumes.swap_remove(rng.gen_range(0..volumes.len()));
+            tree.remove(id);
+        }
+        validate(&tree);
+        assert_eq!(tree.len(), volumes.len());
+
+        // A balanced tree with 200 leaves is far shallower than a list
+        assert!(tree.height() < 20, "height: {}", tree.height());
+
+        for _ in 0..50 {
+            let query = random_aabb(&mut rng).grow(Vec2::splat(5.0));
+            let mut candidates = Vec::new();
+            tree.query(&query, |id, _| candidates.push(id));
+
+            for (id, aabb) in &volumes {
+                let fat_aabb = tree.fat_aabb(*id).unwrap();
+                // Every intersecting volume is found, and every candidate overlaps in its fat AABB
+                if aabb.intersects(&query) {
+                    assert!(candidates.contains(id));
+                }
+                assert_eq!(candidates.contains(id), fat_aabb.intersects(&query));
+            }
+        }
+    }
+
+    #[test]
+    fn ray_cast_closest() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut tree = DynamicAabbTree2d::new(0.2);
+        let mut volumes = Vec::new();
+        for i in 0..200 {
+            let aabb = random_aabb(&mut rng);
+            volumes.push((tree.insert(aabb, aabb), i));
+        }
+
+        for _ in 0..50 {
+            let origin = Vec2::new(rng.gen_range(-60.0..60.0), rng.gen_range(-60.0..60.0));
+            let direction = Dir2::from_xy(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0));
+            let Ok(direction) = direction else {
+                continue;
+            };
+            let ray = RayCast2d::new(origin, direction, 80.0);
+
+            let hit = tree.ray_cast(&ray, |_, aabb| ray.aabb_intersection_at(aabb));
+            let expected = volumes
+                .iter()
+                .filter_map(|(id, _)| {
+                    let aabb = tree.get(*id).unwrap();
+                    ray.aabb_intersection_at(aabb)
+                        .map(|distance| (*id, distance))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+
+            assert_eq!(
+                hit.map(|(_, distance)| distance),
+                expected.map(|(_, distance)| distance)
+            );
+        }
+
+        // Rays that miss everything return nothing
+        let ray = RayCast2d::new(Vec2::splat(100.0), Dir2::X, 10.0);
+        assert_eq!(
+            tree.ray_cast(&ray, |_, aabb| ray.aabb_intersection_at(aabb)),
+            None
+        );
+    }
+
+    #[test]
+    fn ray_cast_all_in_order() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut tree = DynamicAabbTree2d::new(0.2);
+        let ids: Vec<_> = (0..200)
+            .map(|_| tree.insert(random_aabb(&mut rng), ()))
+            .collect();
+
+        for _ in 0..50 {
+            let origin = Vec2::new(rng.gen_range(-60.0..60.0), rng.gen_range(-60.0..60.0));
+            let direction = Dir2::from_xy(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0));
+            let Ok(direction) = direction else {
+                continue;
+            };
+            let ray = RayCast2d::new(origin, direction, 80.0);
+
+            let mut hits = Vec::new();
+            tree.ray_cast_all(&ray, |id, distance| {
+                hits.push((id, distance));
+                RayContinuation::Continue
+            });
+            assert!(hits.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+
+            let solid = ray.clone().with_solid(true);
+            let mut expected: Vec<_> = ids
+                .iter()
+                .filter_map(|&id| {
+                    let fat_aabb = tree.fat_aabb(id).unwrap();
+                    solid
+                        .aabb_intersection_at(&fat_aabb)
+                        .map(|distance| (id, distance))
+                })
+                .collect();
+            expected.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+            hits.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+            assert_eq!(hits, expected);
+
+            // Stopping after the first hit only visits the nearest volume
+            let mut visited = Vec::new();
+            tree.ray_cast_all(&ray, |id, distance| {
+                visited.push((id, distance));
+                RayContinuation::Stop
+            });
+            assert_eq!(visited.len(), expected.len().min(1));
+            assert_eq!(
+                visited.first().map(|hit| hit.1),
+                expected.first().map(|hit| hit.1)
+            );
+        }
+
+        // Empty trees have nothing to hit
+        let empty = DynamicAabbTree2d::<()>::default();
+        let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, 10.0);
+        empty.ray_cast_all(&ray, |_, _| panic!("the tree is empty"));
+    }
+}
@@ -0,0 +1,18 @@
+//! Acceleration structures for finding potentially intersecting bounding volumes.
+//!
+//! Testing every pair of volumes against each other with [`IntersectsVolume`](super::IntersectsVolume)
+//! scales quadratically with the number of volumes. The structures in this module organize volumes
+//! spatially, so that only volumes near a query need to be tested.
+//!
+//! - [`DynamicAabbTree2d`] is a bounding volume hierarchy that is updated incrementally
+//!   as volumes are inserted, moved, and removed.
+//! - [`SpatialHash2d`] is a uniform grid that is cheap to clear and rebuild every frame.
+//! - [`SweepAndPrune2d`] keeps volumes sorted along an axis, and reports the pairs that overlap.
+
+mod aabb_tree;
+mod spatial_hash;
+mod sweep_and_prune;
+
+pub use aabb_tree::{DynamicAabbTree2d, ProxyId, RayContinuation};
+pub use spatial_hash::SpatialHash2d;
+pub use sweep_and_prune::SweepAndPrune2d;
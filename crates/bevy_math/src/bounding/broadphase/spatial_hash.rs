@@ -0,0 +1,275 @@
+use crate::{bounding::Aabb2d, ops, IVec2, Vec2};
+
+use alloc::{vec, vec::Vec};
+
+/// A value stored in a [`SpatialHash2d`], along with the range of cells it covers.
+#[derive(Clone, Debug)]
+struct Entry<T> {
+    min_cell: IVec2,
+    max_cell: IVec2,
+    value: T,
+}
+
+/// A uniform grid of cells for quickly finding [`Aabb2d`]s near a query,
+/// with values stored in a fixed number of buckets by hashing the coordinates of their cells.
+///
+/// Each value is added to the bucket of every cell its AABB overlaps, and queries only look at
+/// the buckets of the cells overlapped by the query. Compared to a [`DynamicAabbTree2d`],
+/// this is much cheaper to build, which makes it well suited for being cleared and rebuilt every frame.
+///
+/// # Choosing a cell size
+///
+/// The cell size should be around the size of a typical volume, or slightly larger.
+/// If the cells are much smaller than the volumes, every volume is added to many cells,
+/// which makes insertion and queries slower. If the cells are much larger than the volumes,
+/// many unrelated volumes end up in the same cell, and queries return more false positives.
+/// Use a [`DynamicAabbTree2d`] instead if the sizes of the volumes vary a lot.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_math::{bounding::{Aabb2d, broadphase::SpatialHash2d}, Vec2};
+/// let mut grid = SpatialHash2d::new(2.0);
+/// grid.insert(Aabb2d::new(Vec2::ZERO, Vec2::ONE), "a");
+/// grid.insert(Aabb2d::new(Vec2::splat(10.0), Vec2::ONE), "b");
+///
+/// let candidates: Vec<_> = grid
+///     .query_aabb(Aabb2d::new(Vec2::splat(1.5), Vec2::splat(0.25)))
+///     .collect();
+/// assert_eq!(candidates, [&"a"]);
+///
+/// // Rebuild the grid for the next frame
+/// grid.clear();
+/// assert!(grid.is_empty());
+/// ```
+///
+/// [`DynamicAabbTree2d`]: super::DynamicAabbTree2d
+#[derive(Clone, Debug)]
+pub struct SpatialHash2d<T> {
+    cell_size: f32,
+    entries: Vec<Entry<T>>,
+    /// Indices into `entries`, for every cell hashing to the bucket.
+    buckets: Vec<Vec<usize>>,
+    /// The indices of the buckets that are not empty, so that clearing doesn't need to visit every bucket.
+    occupied_buckets: Vec<usize>,
+}
+
+impl<T> SpatialHash2d<T> {
+    /// The number of buckets used by [`SpatialHash2d::new`].
+    pub const DEFAULT_BUCKET_COUNT: usize = 1024;
+
+    /// Creates an empty spatial hash with square cells of the given `cell_size`,
+    /// using [`Self::DEFAULT_BUCKET_COUNT`] buckets.
+    #[inline]
+    pub fn new(cell_size: f32) -> Self {
+        Self::with_bucket_count(cell_size, Self::DEFAULT_BUCKET_COUNT)
+    }
+
+    /// Creates an empty spatial hash with square cells of the given `cell_size`,
+    /// and the given number of buckets.
+    ///
+    /// Cells whose coordinates hash to the same bucket share their values, so queries against them
+    /// need to skip the values of the other cells. Using roughly as many buckets as there are
+    /// occupied cells keeps these collisions rare.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not positive and finite, or if `bucket_count` is zero.
+    pub fn with_bucket_count(cell_size: f32, bucket_count: usize) -> Self {
+        assert!(
+            cell_size > 0.0 && cell_size.is_finite(),
+            "the cell size must be positive and finite"
+        );
+        assert!(bucket_count > 0, "there must be at least one bucket");
+        Self {
+            cell_size,
+            entries: Vec::new(),
+            buckets: vec![Vec::new(); bucket_count],
+            occupied_buckets: Vec::new(),
+        }
+    }
+
+    /// Returns the size of the cells.
+    #[inline(always)]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Returns the number of values in the spatial hash.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the spatial hash contains no values.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all values from the spatial hash.
+    ///
+    /// This only visits the buckets that are in use, and keeps the allocated memory for reuse.
+    pub fn clear(&mut self) {
+        for bucket in self.occupied_buckets.drain(..) {
+            self.buckets[bucket].clear();
+        }
+        self.entries.clear();
+    }
+
+    /// Inserts a `value` covering the given `aabb` into every cell the AABB overlaps.
+    pub fn insert(&mut self, aabb: Aabb2d, value: T) {
+        let index = self.entries.len();
+        let (min_cell, max_cell) = self.cell_range(&aabb);
+        self.entries.push(Entry {
+            min_cell,
+            max_cell,
+            value,
+        });
+
+        for y in min_cell.y..=max_cell.y {
+            for x in min_cell.x..=max_cell.x {
+                let bucket = self.bucket(IVec2::new(x, y));
+                match self.buckets[bucket].last() {
+                    // Several cells of this value hash to the same bucket
+                    Some(&last) if last == index => continue,
+                    Some(_) => {}
+                    None => self.occupied_buckets.push(bucket),
+                }
+                self.buckets[bucket].push(index);
+            }
+        }
+    }
+
+    /// Returns an iterator over the values whose cells overlap the cells of the given `aabb`.
+    ///
+    /// Each value is returned at most once. The values are only candidates,
+    /// and their AABBs may not actually intersect the query.
+    pub fn query_aabb(&self, aabb: Aabb2d) -> impl Iterator<Item = &T> {
+        let (query_min, query_max) = self.cell_range(&aabb);
+        (query_min.y..=query_max.y)
+            .flat_map(move |y| (query_min.x..=query_max.x).map(move |x| IVec2::new(x, y)))
+            .flat_map(move |cell| {
+                self.buckets[self.bucket(cell)]
+                    .iter()
+                    .map(|&index| &self.entries[index])
+                    .filter(move |entry| {
+                        // Skip values from other cells that share the bucket
+                        let in_cell =
+                            cell.cmpge(entry.min_cell).all() && cell.cmple(entry.max_cell).all();
+                        // Only return each value from the first cell it shares with the query
+                        in_cell && cell == entry.min_cell.max(query_min)
+                    })
+                    .map(|entry| &entry.value)
+            })
+    }
+
+    /// Returns an iterator over all values in the spatial hash.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.entries.iter().map(|entry| &entry.value)
+    }
+
+    /// Returns the coordinates of the cell containing the given `point`.
+    #[inline(always)]
+    fn cell(&self, point: Vec2) -> IVec2 {
+        let scaled = point / self.cell_size;
+        IVec2::new(ops::floor(scaled.x) as i32, ops::floor(scaled.y) as i32)
+    }
+
+    /// Returns the minimum and maximum coordinates of the cells overlapped by the given `aabb`.
+    #[inline(always)]
+    fn cell_range(&self, aabb: &Aabb2d) -> (IVec2, IVec2) {
+        (self.cell(aabb.min), self.cell(aabb.max))
+    }
+
+    /// Returns the index of the bucket for the given `cell`.
+    #[inline(always)]
+    fn bucket(&self, cell: IVec2) -> usize {
+        // Large primes from "Optimized Spatial Hashing for Collision Detection of Deformable Objects"
+        // by Teschner et al.
+        let hash =
+            (cell.x as u32).wrapping_mul(73_856_093) ^ (cell.y as u32).wrapping_mul(19_349_663);
+        hash as usize % self.buckets.len()
+    }
+}
+
+#[cfg(test)]
+mod spatial_hash_tests {
+    use super::SpatialHash2d;
+    use crate::{
+        bounding::{Aabb2d, IntersectsVolume},
+        Vec2,
+    };
+    use alloc::vec::Vec;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    fn random_aabb(rng: &mut ChaCha8Rng) -> Aabb2d {
+        let center = Vec2::new(rng.gen_range(-50.0..50.0), rng.gen_range(-50.0..50.0));
+        let half_size = Vec2::new(rng.gen_range(0.1..3.0), rng.gen_range(0.1..3.0));
+        Aabb2d::new(center, half_size)
+    }
+
+    #[test]
+    fn insert_query_clear() {
+        let mut grid = SpatialHash2d::new(1.0);
+        grid.insert(Aabb2d::new(Vec2::ZERO, Vec2::splat(0.4)), 0);
+        grid.insert(Aabb2d::new(Vec2::new(3.0, 0.0), Vec2::splat(2.0)), 1);
+        grid.insert(Aabb2d::new(Vec2::new(-10.0, 5.0), Vec2::splat(0.4)), 2);
+        assert_eq!(grid.len(), 3);
+
+        let mut candidates: Vec<_> = grid
+            .query_aabb(Aabb2d::new(Vec2::new(1.0, 0.0), Vec2::splat(0.5)))
+            .copied()
+            .collect();
+        candidates.sort();
+        assert_eq!(candidates, [0, 1]);
+
+        // A query spanning many cells of a value only returns it once
+        let candidates: Vec<_> = grid
+            .query_aabb(Aabb2d::new(Vec2::new(3.0, 0.0), Vec2::splat(3.0)))
+            .copied()
+            .collect();
+        assert_eq!(candidates.iter().filter(|&&value| value == 1).count(), 1);
+
+        grid.clear();
+        assert!(grid.is_empty());
+        assert_eq!(
+            grid.query_aabb(Aabb2d::new(Vec2::ZERO, Vec2::splat(100.0)))
+                .count(),
+            0
+        );
+        assert!(grid.buckets.iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn query_matches_brute_force() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        // Use few buckets to provoke collisions between cells
+        let mut grid = SpatialHash2d::with_bucket_count(4.0, 7);
+        let volumes: Vec<_> = (0..500).map(|_| random_aabb(&mut rng)).collect();
+        for (index, aabb) in volumes.iter().enumerate() {
+            grid.insert(*aabb, index);
+        }
+
+        for _ in 0..100 {
+            let query = random_aabb(&mut rng);
+            let mut candidates: Vec<_> = grid.query_aabb(query).copied().collect();
+            let count = candidates.len();
+            candidates.sort();
+            candidates.dedup();
+            assert_eq!(count, candidates.len(), "duplicate candidates");
+
+            let (query_min, query_max) = grid.cell_range(&query);
+            for (index, aabb) in volumes.iter().enumerate() {
+                let (min, max) = grid.cell_range(aabb);
+                let cells_overlap = min.cmple(query_max).all() && max.cmpge(query_min).all();
+                assert_eq!(candidates.binary_search(&index).is_ok(), cells_overlap);
+                if aabb.intersects(&query) {
+                    assert!(cells_overlap);
+                }
+            }
+        }
+    }
+}
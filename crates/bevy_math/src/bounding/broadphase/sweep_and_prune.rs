@@ -0,0 +1,279 @@
+use crate::bounding::Aabb2d;
+
+use alloc::vec::Vec;
+
+/// A broadphase that finds the pairs of overlapping [`Aabb2d`]s by sorting them along the X axis.
+///
+/// The volumes are kept sorted by the minimum X coordinate of their AABBs, so finding the pairs
+/// only needs to compare each volume with the following ones until they start past its maximum X
+/// coordinate. Updating the volumes restores the order with an insertion sort, which takes linear time
+/// when they only moved a little, like objects moving smoothly from one frame to the next.
+///
+/// This is simpler than a [`DynamicAabbTree2d`] or a [`SpatialHash2d`], and works well for a modest
+/// number of volumes. It degrades when many volumes overlap on the X axis, like a tall stack of objects.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_math::{bounding::{Aabb2d, broadphase::SweepAndPrune2d}, Vec2};
+/// let mut broadphase = SweepAndPrune2d::new();
+/// broadphase.push(Aabb2d::new(Vec2::ZERO, Vec2::ONE));
+/// broadphase.push(Aabb2d::new(Vec2::new(1.5, 0.0), Vec2::ONE));
+/// broadphase.push(Aabb2d::new(Vec2::new(10.0, 0.0), Vec2::ONE));
+/// assert_eq!(broadphase.pairs().collect::<Vec<_>>(), [(0, 1)]);
+///
+/// // The third volume moves next to the first one
+/// broadphase.update(&[
+///     Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+///     Aabb2d::new(Vec2::new(1.5, 0.0), Vec2::ONE),
+///     Aabb2d::new(Vec2::new(-1.5, 0.0), Vec2::ONE),
+/// ]);
+/// let mut pairs: Vec<_> = broadphase.pairs().collect();
+/// pairs.sort();
+/// assert_eq!(pairs, [(0, 1), (0, 2)]);
+/// ```
+///
+/// [`DynamicAabbTree2d`]: super::DynamicAabbTree2d
+/// [`SpatialHash2d`]: super::SpatialHash2d
+#[derive(Clone, Debug, Default)]
+pub struct SweepAndPrune2d {
+    aabbs: Vec<Aabb2d>,
+    /// Indices into `aabbs`, sorted by the minimum X coordinate of their AABBs.
+    order: Vec<usize>,
+}
+
+impl SweepAndPrune2d {
+    /// Creates an empty broadphase.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of volumes in the broadphase.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.aabbs.len()
+    }
+
+    /// Returns `true` if the broadphase contains no volumes.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.aabbs.is_empty()
+    }
+
+    /// Returns the volumes in the broadphase, in the order they were added.
+    #[inline(always)]
+    pub fn aabbs(&self) -> &[Aabb2d] {
+        &self.aabbs
+    }
+
+    /// Removes all volumes from the broadphase, keeping the allocated memory for reuse.
+    pub fn clear(&mut self) {
+        self.aabbs.clear();
+        self.order.clear();
+    }
+
+    /// Adds a volume to the broadphase, returning its index.
+    ///
+    /// Indices are assigned in the order the volumes are added, starting at zero.
+    pub fn push(&mut self, aabb: Aabb2d) -> usize {
+        let index = self.aabbs.len();
+        self.aabbs.push(aabb);
+        self.order.push(index);
+        self.sift_down(index);
+        index
+    }
+
+    /// Replaces the volumes of the broadphase with the given `aabbs`, in the same order they were added,
+    /// and restores the sorted order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of `aabbs` is not the number of volumes in the broadphase.
+    pub fn update(&mut self, aabbs: &[Aabb2d]) {
+        assert_eq!(
+            aabbs.len(),
+            self.aabbs.len(),
+            "the number of volumes must not change when updating them"
+        );
+        self.aabbs.copy_from_slice(aabbs);
+
+        // Insertion sort, which only moves the volumes that changed places since the last update
+        for position in 1..self.order.len() {
+            self.sift_down(position);
+        }
+    }
+
+    /// Replaces the volume at the given `index`, and moves it to its place in the sorted order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no volume at the given `index`.
+    pub fn set(&mut self, index: usize, aabb: Aabb2d) {
+        self.aabbs[index] = aabb;
+        let position = self.order.iter().position(|&other| other == index).unwrap();
+        let moved_down = position > 0 && self.aabbs[self.order[position - 1]].min.x > aabb.min.x;
+        if moved_down {
+            self.sift_down(position);
+        } else {
+            self.sift_up(position);
+        }
+    }
+
+    /// Returns an iterator over the pairs of indices of the volumes that intersect, including if they are only touching.
+    ///
+    /// Each pair is returned once, with the smaller index first, but the pairs are in no particular order.
+    pub fn pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.order
+            .iter()
+            .enumerate()
+            .flat_map(move |(position, &index)| {
+                let aabb = self.aabbs[index];
+                self.order[position + 1..]
+                    .iter()
+                    .map(|&other| (other, self.aabbs[other]))
+                    // The following volumes start further along the X axis, so once one starts past
+                    // the end of this volume, so do all the others
+                    .take_while(move |(_, other)| other.min.x <= aabb.max.x)
+                    .filter(move |(_, other)| aabb.intersects_aabb(other))
+                    .map(move |(other, _)| (index.min(other), index.max(other)))
+            })
+    }
+
+    /// Moves the volume at the given `position` in the sorted order towards the start,
+    /// until it is in its sorted place.
+    #[inline]
+    fn sift_down(&mut self, mut position: usize) {
+        let index = self.order[position];
+        let key = self.aabbs[index].min.x;
+        while position > 0 && self.aabbs[self.order[position - 1]].min.x > key {
+            self.order[position] = self.order[position - 1];
+            position -= 1;
+        }
+        self.order[position] = index;
+    }
+
+    /// Moves the volume at the given `position` in the sorted order towards the end,
+    /// until it is in its sorted place.
+    #[inline]
+    fn sift_up(&mut self, mut position: usize) {
+        let index = self.order[position];
+        let key = self.aabbs[index].min.x;
+        while position + 1 < self.order.len() && self.aabbs[self.order[position + 1]].min.x < key {
+            self.order[position] = self.order[position + 1];
+            position += 1;
+        }
+        self.order[position] = index;
+    }
+}
+
+#[cfg(test)]
+mod sweep_and_prune_tests {
+    use super::SweepAndPrune2d;
+    use crate::{
+        bounding::{Aabb2d, BoundingVolume, IntersectsVolume},
+        Vec2,
+    };
+    use alloc::vec::Vec;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    fn random_aabb(rng: &mut ChaCha8Rng) -> Aabb2d {
+        let center = Vec2::new(rng.gen_range(-50.0..50.0), rng.gen_range(-50.0..50.0));
+        let half_size = Vec2::new(rng.gen_range(0.1..5.0), rng.gen_range(0.1..5.0));
+        Aabb2d::new(center, half_size)
+    }
+
+    fn sorted_pairs(broadphase: &SweepAndPrune2d) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<_> = broadphase.pairs().collect();
+        pairs.sort();
+        pairs
+    }
+
+    fn brute_force_pairs(aabbs: &[Aabb2d]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (a, first) in aabbs.iter().enumerate() {
+            for (b, second) in aabbs.iter().enumerate().skip(a + 1) {
+                if first.intersects(second) {
+                    pairs.push((a, b));
+                }
+            }
+        }
+        pairs
+    }
+
+    fn is_sorted(broadphase: &SweepAndPrune2d) -> bool {
+        broadphase
+            .order
+            .windows(2)
+            .all(|window| broadphase.aabbs[window[0]].min.x <= broadphase.aabbs[window[1]].min.x)
+    }
+
+    #[test]
+    fn push_set_clear() {
+        let mut broadphase = SweepAndPrune2d::new();
+        assert_eq!(broadphase.push(Aabb2d::new(Vec2::ZERO, Vec2::ONE)), 0);
+        assert_eq!(
+            broadphase.push(Aabb2d::new(Vec2::new(-2.0, 0.0), Vec2::ONE)),
+            1
+        );
+        assert_eq!(
+            broadphase.push(Aabb2d::new(Vec2::new(2.0, 5.0), Vec2::ONE)),
+            2
+        );
+        assert_eq!(broadphase.len(), 3);
+        assert!(is_sorted(&broadphase));
+        // Touching volumes are reported
+        assert_eq!(sorted_pairs(&broadphase), [(0, 1)]);
+
+        broadphase.set(1, Aabb2d::new(Vec2::new(3.0, 4.0), Vec2::ONE));
+        assert!(is_sorted(&broadphase));
+        assert_eq!(sorted_pairs(&broadphase), [(1, 2)]);
+
+        broadphase.set(2, Aabb2d::new(Vec2::new(-5.0, 0.0), Vec2::splat(5.0)));
+        assert!(is_sorted(&broadphase));
+        assert_eq!(sorted_pairs(&broadphase), [(0, 2)]);
+
+        broadphase.clear();
+        assert!(broadphase.is_empty());
+        assert_eq!(broadphase.pairs().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not change")]
+    fn update_with_wrong_length() {
+        let mut broadphase = SweepAndPrune2d::new();
+        broadphase.push(Aabb2d::new(Vec2::ZERO, Vec2::ONE));
+        broadphase.update(&[]);
+    }
+
+    #[test]
+    fn pairs_match_brute_force() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut aabbs: Vec<_> = (0..300).map(|_| random_aabb(&mut rng)).collect();
+        let mut broadphase = SweepAndPrune2d::new();
+        for aabb in &aabbs {
+            broadphase.push(*aabb);
+        }
+        assert!(is_sorted(&broadphase));
+        assert_eq!(sorted_pairs(&broadphase), brute_force_pairs(&aabbs));
+
+        // Move the volumes a little every frame, like a simulation would
+        for _ in 0..20 {
+            for aabb in &mut aabbs {
+                let offset = Vec2::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0));
+                *aabb = Aabb2d::new(aabb.center() + offset, aabb.half_size());
+            }
+            broadphase.update(&aabbs);
+            assert!(is_sorted(&broadphase));
+
+            let pairs = broadphase.pairs().collect::<Vec<_>>();
+            assert!(pairs.iter().all(|(a, b)| a < b));
+            let mut sorted = pairs.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), pairs.len(), "duplicate pairs");
+            assert_eq!(sorted, brute_force_pairs(&aabbs));
+        }
+    }
+}
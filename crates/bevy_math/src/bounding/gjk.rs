@@ -0,0 +1,414 @@
+//! The Gilbert–Johnson–Keerthi (GJK) algorithm, for computing the distance between convex shapes.
+//!
+//! GJK only needs a [`SupportFunction`] for each shape, which finds the point of the shape
+//! that is farthest along a given direction. This makes it possible to query the distance
+//! between any pair of convex shapes with a single algorithm, including custom shapes
+//! that implement [`SupportFunction`].
+//!
+//! Primitive shapes like [`Triangle2d`] and [`RegularPolygon`] are positioned with an [`Isometry2d`]
+//! by pairing them with one, as a `(shape, isometry)` tuple.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_math::{bounding::{gjk, Aabb2d, BoundingCircle}, primitives::RegularPolygon, Isometry2d, Vec2};
+//! let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+//! let circle = BoundingCircle::new(Vec2::new(4.0, 0.0), 1.0);
+//! assert!((gjk::distance(&aabb, &circle) - 2.0).abs() < 1e-4);
+//!
+//! let other = Aabb2d::new(Vec2::new(4.0, 5.0), Vec2::ONE);
+//! let (on_aabb, on_other) = gjk::closest_points(&aabb, &other).unwrap();
+//! assert!(on_aabb.distance(Vec2::new(1.0, 1.0)) < 1e-4);
+//! assert!(on_other.distance(Vec2::new(3.0, 4.0)) < 1e-4);
+//!
+//! // A hexagon with a circumradius of 1, centered at (0, 3)
+//! let hexagon = (RegularPolygon::new(1.0, 6), Isometry2d::from_xy(0.0, 3.0));
+//! assert!((gjk::distance(&aabb, &hexagon) - 1.0).abs() < 1e-4);
+//! ```
+
+use super::{Aabb2d, BoundingCircle};
+use crate::{
+    ops,
+    primitives::{RegularPolygon, Triangle2d},
+    Isometry2d, Vec2,
+};
+use core::f32::consts::FRAC_PI_2;
+
+/// A convex shape that can be queried for its farthest point along a direction,
+/// which is all that is needed to compute distances between shapes with [GJK](self).
+pub trait SupportFunction {
+    /// Finds the point of the shape that is farthest along the given `direction`.
+    ///
+    /// The direction is not necessarily normalized. If several points are equally far along it,
+    /// any of them may be returned.
+    fn support_point(&self, direction: Vec2) -> Vec2;
+}
+
+impl SupportFunction for Aabb2d {
+    #[inline(always)]
+    fn support_point(&self, direction: Vec2) -> Vec2 {
+        Aabb2d::support_point(self, direction)
+    }
+}
+
+impl SupportFunction for BoundingCircle {
+    #[inline(always)]
+    fn support_point(&self, direction: Vec2) -> Vec2 {
+        BoundingCircle::support_point(self, direction)
+    }
+}
+
+impl SupportFunction for Triangle2d {
+    #[inline]
+    fn support_point(&self, direction: Vec2) -> Vec2 {
+        let [a, b, c] = self.vertices;
+        let farthest = if a.dot(direction) >= b.dot(direction) {
+            a
+        } else {
+            b
+        };
+        if farthest.dot(direction) >= c.dot(direction) {
+            farthest
+        } else {
+            c
+        }
+    }
+}
+
+impl SupportFunction for RegularPolygon {
+    #[inline]
+    fn support_point(&self, direction: Vec2) -> Vec2 {
+        // The vertices are spaced evenly around the circumcircle, starting from the top,
+        // so the farthest one is the one closest in angle to the direction
+        let step = self.external_angle_radians();
+        let angle = ops::atan2(direction.y, direction.x) - FRAC_PI_2;
+        let (sin, cos) = ops::sin_cos(ops::round(angle / step) * step + FRAC_PI_2);
+        Vec2::new(cos, sin) * self.circumcircle.radius
+    }
+}
+
+impl<T: SupportFunction> SupportFunction for (T, Isometry2d) {
+    #[inline]
+    fn support_point(&self, direction: Vec2) -> Vec2 {
+        let (shape, isometry) = self;
+        isometry.transform_point(shape.support_point(isometry.rotation.inverse_mul_vec(direction)))
+    }
+}
+
+/// Computes the distance between two convex shapes.
+///
+/// Returns `0.0` if the shapes are touching or overlapping.
+#[inline]
+pub fn distance(a: &impl SupportFunction, b: &impl SupportFunction) -> f32 {
+    closest_points(a, b).map_or(0.0, |(on_a, on_b)| on_a.distance(on_b))
+}
+
+/// Computes the pair of points on two convex shapes that are closest to each other,
+/// with the point on `a` first and the point on `b` second.
+///
+/// Returns `None` if the shapes are overlapping, in which case there is no unique pair of closest points.
+/// Shapes that are only touching may be considered either overlapping or separated by a distance close to zero.
+///
+/// The distance between the points converges faster than the points themselves. When the closest features
+/// of the shapes are curved or parallel, the points may be off by a small fraction of the size of the shapes
+/// even when their distance is accurate.
+pub fn closest_points(a: &impl SupportFunction, b: &impl SupportFunction) -> Option<(Vec2, Vec2)> {
+    /// The maximum number of iterations, which is only reached for curved shapes
+    /// when the distance converges slowly.
+    const MAX_ITERATIONS: usize = 64;
+    /// The relative improvement of the squared distance below which the algorithm stops.
+    const TOLERANCE: f32 = 1e-6;
+
+    // GJK finds the point of the Minkowski difference `a - b` closest to the origin,
+    // by iteratively refining a simplex of points of the difference towards the origin
+    let mut simplex = Simplex::new(SupportVertex::new(a, b, Vec2::X));
+    let mut closest = simplex.vertices[0].point();
+
+    for _ in 0..MAX_ITERATIONS {
+        let distance_squared = closest.length_squared();
+        if distance_squared <= f32::MIN_POSITIVE {
+            return None;
+        }
+
+        let vertex = SupportVertex::new(a, b, -closest);
+        // Stop when no point of the difference is meaningfully closer to the origin
+        // along the search direction than the current closest point
+        if distance_squared - closest.dot(vertex.point()) <= TOLERANCE * distance_squared {
+            break;
+        }
+
+        simplex.push(vertex);
+        closest = simplex.reduce()?;
+    }
+
+    Some(simplex.closest_points())
+}
+
+/// A point of the Minkowski difference of two shapes, along with the points of each shape it is made of.
+#[derive(Clone, Copy, Debug, Default)]
+struct SupportVertex {
+    a: Vec2,
+    b: Vec2,
+}
+
+impl SupportVertex {
+    /// Finds the point of the Minkowski difference of `a` and `b` that is farthest along `direction`.
+    #[inline(always)]
+    fn new(a: &impl SupportFunction, b: &impl SupportFunction, direction: Vec2) -> Self {
+        Self {
+            a: a.support_point(direction),
+            b: b.support_point(-direction),
+        }
+    }
+
+    #[inline(always)]
+    fn point(&self) -> Vec2 {
+        self.a - self.b
+    }
+}
+
+/// A point, segment, or triangle of points of the Minkowski difference,
+/// with the barycentric weights of its point closest to the origin.
+#[derive(Clone, Copy, Debug)]
+struct Simplex {
+    vertices: [SupportVertex; 3],
+    weights: [f32; 3],
+    len: usize,
+}
+
+impl Simplex {
+    #[inline(always)]
+    fn new(vertex: SupportVertex) -> Self {
+        Self {
+            vertices: [vertex; 3],
+            weights: [1.0, 0.0, 0.0],
+            len: 1,
+        }
+    }
+
+    #[inline(always)]
+    fn push(&mut self, vertex: SupportVertex) {
+        self.vertices[self.len] = vertex;
+        self.len += 1;
+    }
+
+    /// Reduces the simplex to the smallest subset containing its point closest to the origin,
+    /// and returns that point.
+    ///
+    /// Returns `None` if the simplex is a triangle containing the origin.
+    fn reduce(&mut self) -> Option<Vec2> {
+        match self.len {
+            1 => Some(self.vertices[0].point()),
+            2 => Some(self.reduce_segment(0, 1)),
+            _ => self.reduce_triangle(),
+        }
+    }
+
+    /// Reduces the simplex to the subset of the segment between the vertices at indices `i` and `j`
+    /// containing its point closest to the origin, and returns that point.
+    fn reduce_segment(&mut self, i: usize, j: usize) -> Vec2 {
+        let (start, end) = (self.vertices[i], self.vertices[j]);
+        let edge = end.point() - start.point();
+        let length_squared = edge.length_squared();
+        let t = if length_squared > 0.0 {
+            (-start.point().dot(edge) / length_squared).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        if t == 0.0 {
+            *self = Self::new(start);
+            start.point()
+        } else if t == 1.0 {
+            *self = Self::new(end);
+            end.point()
+        } else {
+            self.vertices[..2].copy_from_slice(&[start, end]);
+            self.weights = [1.0 - t, t, 0.0];
+            self.len = 2;
+            start.point() + edge * t
+        }
+    }
+
+    /// Reduces the simplex to the edge or vertex of the triangle containing its point closest to the origin,
+    /// and returns that point.
+    ///
+    /// Returns `None` if the triangle contains the origin.
+    fn reduce_triangle(&mut self) -> Option<Vec2> {
+        let [a, b, c] = self.vertices.map(|vertex| vertex.point());
+
+        // The signed areas of the triangles formed by the origin and each edge
+        // are the unnormalized barycentric coordinates of the origin
+        let area = (b - a).perp_dot(c - a);
+        let weights = [b.perp_dot(c), c.perp_dot(a), a.perp_dot(b)];
+        if area != 0.0 && weights.iter().all(|weight| weight * area >= 0.0) {
+            self.weights = weights.map(|weight| weight / area);
+            return None;
+        }
+
+        // Otherwise, the closest point is on one of the edges
+        let triangle = *self;
+        [(0, 1), (1, 2), (2, 0)]
+            .into_iter()
+            .map(|(i, j)| {
+                let mut edge = triangle;
+                let closest = edge.reduce_segment(i, j);
+                (edge, closest)
+            })
+            .min_by(|(_, p), (_, q)| p.length_squared().total_cmp(&q.length_squared()))
+            .map(|(edge, closest)| {
+                *self = edge;
+                closest
+            })
+    }
+
+    /// Computes the closest points on the two shapes from the barycentric weights of the simplex.
+    fn closest_points(&self) -> (Vec2, Vec2) {
+        self.vertices[..self.len]
+            .iter()
+            .zip(self.weights)
+            .fold((Vec2::ZERO, Vec2::ZERO), |(a, b), (vertex, weight)| {
+                (a + vertex.a * weight, b + vertex.b * weight)
+            })
+    }
+}
+
+#[cfg(test)]
+mod gjk_tests {
+    use super::{closest_points, distance, SupportFunction};
+    use crate::{
+        bounding::{Aabb2d, BoundingCircle},
+        ops,
+        primitives::{RegularPolygon, Triangle2d},
+        Isometry2d, Rot2, Vec2,
+    };
+    use approx::assert_relative_eq;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    const EPSILON: f32 = 1e-4;
+
+    #[test]
+    fn aabb_aabb() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        for (test, other, expected) in &[
+            ("beside", Aabb2d::new(Vec2::new(4.0, 0.5), Vec2::ONE), 2.0),
+            ("diagonal", Aabb2d::new(Vec2::new(5.0, 6.0), Vec2::ONE), 5.0),
+            (
+                "overlapping",
+                Aabb2d::new(Vec2::new(1.0, 1.5), Vec2::ONE),
+                0.0,
+            ),
+            ("containing", Aabb2d::new(Vec2::ZERO, Vec2::splat(3.0)), 0.0),
+        ] {
+            let distance = distance(&aabb, other);
+            assert!(
+                ops::abs(distance - expected) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Distance: {distance}\n  Expected: {expected}",
+            );
+        }
+
+        let other = Aabb2d::new(Vec2::new(5.0, 6.0), Vec2::ONE);
+        let (on_aabb, on_other) = closest_points(&aabb, &other).unwrap();
+        assert_relative_eq!(on_aabb, Vec2::ONE, epsilon = EPSILON);
+        assert_relative_eq!(on_other, Vec2::new(4.0, 5.0), epsilon = EPSILON);
+        assert!(closest_points(&aabb, &Aabb2d::new(Vec2::ONE, Vec2::ONE)).is_none());
+    }
+
+    #[test]
+    fn circle_circle() {
+        let circle = BoundingCircle::new(Vec2::new(1.0, 2.0), 1.5);
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        for _ in 0..100 {
+            let other = BoundingCircle::new(
+                Vec2::new(rng.gen_range(-10.0..10.0), rng.gen_range(-10.0..10.0)),
+                rng.gen_range(0.0..3.0),
+            );
+            let expected =
+                (circle.center.distance(other.center) - circle.radius() - other.radius()).max(0.0);
+            let distance = distance(&circle, &other);
+            assert!(
+                ops::abs(distance - expected) < 1e-3,
+                "Circle: {other:?}\n  Distance: {distance}\n  Expected: {expected}",
+            );
+
+            if let Some((on_circle, on_other)) = closest_points(&circle, &other) {
+                assert!(ops::abs(on_circle.distance(circle.center) - circle.radius()) < 1e-3);
+                assert!(ops::abs(on_other.distance(other.center) - other.radius()) < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn aabb_circle() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        let circle = BoundingCircle::new(Vec2::new(4.0, 5.0), 1.0);
+        // The closest point on the AABB is its corner at (1, 1)
+        assert!(ops::abs(distance(&aabb, &circle) - 4.0) < 1e-3);
+        assert!(ops::abs(distance(&circle, &aabb) - 4.0) < 1e-3);
+
+        let (on_aabb, on_circle) = closest_points(&aabb, &circle).unwrap();
+        assert_relative_eq!(on_aabb, Vec2::ONE, epsilon = 1e-3);
+        assert_relative_eq!(on_circle, Vec2::new(3.4, 4.2), epsilon = 1e-3);
+
+        assert_eq!(
+            distance(&aabb, &BoundingCircle::new(Vec2::X * 1.5, 1.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn triangle() {
+        let triangle = Triangle2d::new(Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::new(0.0, 2.0));
+        // The hypotenuse is at a distance of `sqrt(2)` from the origin
+        let circle = BoundingCircle::new(Vec2::splat(3.0), 1.0);
+        let expected = 3.0 * core::f32::consts::SQRT_2 - core::f32::consts::SQRT_2 - 1.0;
+        assert!(ops::abs(distance(&triangle, &circle) - expected) < 1e-3);
+
+        let aabb = Aabb2d::new(Vec2::new(-3.0, 1.0), Vec2::ONE);
+        assert!(ops::abs(distance(&triangle, &aabb) - 2.0) < EPSILON);
+        assert_eq!(
+            distance(&triangle, &Aabb2d::new(Vec2::splat(0.5), Vec2::splat(0.1))),
+            0.0
+        );
+    }
+
+    #[test]
+    fn regular_polygon() {
+        let hexagon = RegularPolygon::new(1.0, 6);
+        // The support points of the polygon are its vertices
+        for direction in [
+            Vec2::X,
+            Vec2::Y,
+            Vec2::new(-1.0, -3.0),
+            Vec2::new(0.3, -1.0),
+        ] {
+            let support = hexagon.support_point(direction);
+            let farthest = hexagon
+                .vertices(0.0)
+                .max_by(|a, b| a.dot(direction).total_cmp(&b.dot(direction)))
+                .unwrap();
+            assert_relative_eq!(support, farthest, epsilon = EPSILON);
+        }
+
+        // A vertex at the top, placed below an AABB
+        let aabb = Aabb2d::new(Vec2::new(0.0, 5.0), Vec2::ONE);
+        assert!(ops::abs(distance(&hexagon, &aabb) - 3.0) < EPSILON);
+
+        // Rotated so that a flat side is at the top, at the inradius
+        let rotated = (
+            hexagon,
+            Isometry2d::from_rotation(Rot2::radians(core::f32::consts::FRAC_PI_6)),
+        );
+        let expected = 4.0 - hexagon.inradius();
+        assert!(ops::abs(distance(&rotated, &aabb) - expected) < EPSILON);
+    }
+
+    #[test]
+    fn translated() {
+        let triangle = Triangle2d::new(Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::new(0.0, 2.0));
+        let translated = (triangle, Isometry2d::from_xy(10.0, 0.0));
+        assert!(ops::abs(distance(&triangle, &translated) - 8.0) < EPSILON);
+    }
+}
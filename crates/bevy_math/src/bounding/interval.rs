@@ -0,0 +1,204 @@
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A closed interval of `f32` values, which may be empty.
+///
+/// This is the building block of slab tests: the distances at which a ray or a moving volume
+/// is between the two sides of a [`Aabb2d`](super::Aabb2d) on one axis form an interval,
+/// and the distances at which it is inside the AABB are the [intersection](Self::intersect)
+/// of the intervals of every axis.
+///
+/// Unlike [`curve::Interval`](crate::curve::Interval), this can be empty:
+/// the interval is empty if `min` is greater than `max`, or if either of them is NaN.
+///
+/// # Example
+///
+/// A slab test of a ray against the unit square:
+///
+/// ```
+/// # use bevy_math::{bounding::Interval1d, Vec2};
+/// let origin = Vec2::new(-2.0, -0.25);
+/// let direction_recip = Vec2::new(1.0, 4.0);
+///
+/// let x = Interval1d::new((0.0 - origin.x) * direction_recip.x, (1.0 - origin.x) * direction_recip.x);
+/// let y = Interval1d::new((0.0 - origin.y) * direction_recip.y, (1.0 - origin.y) * direction_recip.y);
+///
+/// // Only look for hits in front of the origin, up to a distance of 10
+/// let hit = x.intersect(y).clamp_to(0.0, 10.0);
+/// assert!(!hit.is_empty());
+/// assert_eq!(hit.min, 2.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Interval1d {
+    /// The lower bound of the interval
+    pub min: f32,
+    /// The upper bound of the interval
+    pub max: f32,
+}
+
+impl Interval1d {
+    /// An empty interval, which is the identity of [`Interval1d::merge`].
+    pub const EMPTY: Self = Self::new(f32::INFINITY, f32::NEG_INFINITY);
+
+    /// An interval containing every value, which is the identity of [`Interval1d::intersect`].
+    pub const EVERYTHING: Self = Self::new(f32::NEG_INFINITY, f32::INFINITY);
+
+    /// Creates a new interval from its bounds.
+    ///
+    /// The interval is empty if `min` is greater than `max`.
+    #[inline(always)]
+    pub const fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+
+    /// Computes the intersection of this interval and `other`.
+    ///
+    /// If a bound of one of the intervals is NaN, the bound of the other interval is used,
+    /// following [`f32::max`] and [`f32::min`]. Slab tests rely on this to ignore the axes
+    /// that a ray is parallel to.
+    #[inline(always)]
+    #[must_use]
+    pub fn intersect(self, other: Self) -> Self {
+        Self::new(self.min.max(other.min), self.max.min(other.max))
+    }
+
+    /// Computes the smallest interval containing both this interval and `other`.
+    ///
+    /// Empty intervals don't contribute to the result.
+    #[inline(always)]
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        if self.is_empty() {
+            other
+        } else if other.is_empty() {
+            self
+        } else {
+            Self::new(self.min.min(other.min), self.max.max(other.max))
+        }
+    }
+
+    /// Restricts this interval to the values between `min` and `max`.
+    ///
+    /// This is the same as intersecting it with `Interval1d::new(min, max)`,
+    /// for example to only keep the part of a ray that is between its origin and its max distance.
+    #[inline(always)]
+    #[must_use]
+    pub fn clamp_to(self, min: f32, max: f32) -> Self {
+        self.intersect(Self::new(min, max))
+    }
+
+    /// Checks if the interval contains no values.
+    ///
+    /// An interval with equal bounds contains exactly one value, and is not empty.
+    #[inline(always)]
+    pub fn is_empty(self) -> bool {
+        self.min > self.max || self.min.is_nan() || self.max.is_nan()
+    }
+
+    /// Checks if the given `value` is within the interval, including its bounds.
+    #[inline(always)]
+    pub fn contains(self, value: f32) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Returns the length of the interval, or `0.0` if it is empty.
+    #[inline(always)]
+    pub fn length(self) -> f32 {
+        if self.is_empty() {
+            0.0
+        } else {
+            self.max - self.min
+        }
+    }
+}
+
+#[cfg(test)]
+mod interval_tests {
+    use super::Interval1d;
+
+    #[test]
+    fn intersect() {
+        let a = Interval1d::new(0.0, 2.0);
+        for (test, b, expected) in &[
+            (
+                "overlapping",
+                Interval1d::new(1.0, 3.0),
+                Interval1d::new(1.0, 2.0),
+            ),
+            (
+                "inside",
+                Interval1d::new(0.5, 1.0),
+                Interval1d::new(0.5, 1.0),
+            ),
+            (
+                "touching",
+                Interval1d::new(2.0, 3.0),
+                Interval1d::new(2.0, 2.0),
+            ),
+            ("everything", Interval1d::EVERYTHING, a),
+            ("NaN bounds", Interval1d::new(f32::NAN, f32::NAN), a),
+        ] {
+            assert_eq!(a.intersect(*b), *expected, "Case:\n  Test: {test:?}");
+        }
+
+        let disjoint = a.intersect(Interval1d::new(3.0, 4.0));
+        assert!(disjoint.is_empty());
+        assert!(a.intersect(Interval1d::EMPTY).is_empty());
+    }
+
+    #[test]
+    fn merge() {
+        let a = Interval1d::new(0.0, 2.0);
+        assert_eq!(
+            a.merge(Interval1d::new(3.0, 4.0)),
+            Interval1d::new(0.0, 4.0)
+        );
+        assert_eq!(a.merge(Interval1d::EMPTY), a);
+        assert_eq!(Interval1d::EMPTY.merge(a), a);
+        // Empty intervals with finite bounds are ignored too
+        assert_eq!(a.merge(Interval1d::new(10.0, 5.0)), a);
+    }
+
+    #[test]
+    fn contains_and_length() {
+        let a = Interval1d::new(-1.0, 2.0);
+        assert!(a.contains(-1.0));
+        assert!(a.contains(2.0));
+        assert!(!a.contains(2.5));
+        assert!(!a.contains(f32::NAN));
+        assert_eq!(a.length(), 3.0);
+
+        let point = Interval1d::new(1.0, 1.0);
+        assert!(!point.is_empty());
+        assert!(point.contains(1.0));
+        assert_eq!(point.length(), 0.0);
+
+        let empty = Interval1d::new(2.0, -1.0);
+        assert!(empty.is_empty());
+        assert!(!empty.contains(0.0));
+        assert_eq!(empty.length(), 0.0);
+        assert!(Interval1d::new(f32::NAN, 1.0).is_empty());
+    }
+
+    #[test]
+    fn clamp_to() {
+        let a = Interval1d::new(-3.0, 5.0);
+        assert_eq!(a.clamp_to(0.0, 2.0), Interval1d::new(0.0, 2.0));
+        assert_eq!(a.clamp_to(0.0, 10.0), Interval1d::new(0.0, 5.0));
+        assert!(a.clamp_to(6.0, 10.0).is_empty());
+    }
+}
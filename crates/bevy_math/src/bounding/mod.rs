@@ -1,9 +1,26 @@
 //! This module contains traits and implements for working with bounding shapes
 //!
-//! There are four traits used:
+//! There are five traits used:
 //! - [`BoundingVolume`] is a generic abstraction for any bounding volume
 //! - [`IntersectsVolume`] abstracts intersection tests against a [`BoundingVolume`]
+//! - [`VolumeDistance`] abstracts measuring the distance to a [`BoundingVolume`]
 //! - [`Bounded2d`]/[`Bounded3d`] are abstractions for shapes to generate [`BoundingVolume`]s
+//!
+//! # `no_std` support
+//!
+//! The bounding volumes, their intersection tests and the ray and volume casts only depend on `core`,
+//! and use [`ops`](crate::ops) for floating point math, so they are available without the `std` feature.
+//! Enable the `libm` or `nostd-libm` feature to provide the math functions in that case.
+//!
+//! Functions returning a [`Vec`](alloc::vec::Vec), like [`RayCast2d::cast_all`],
+//! and types storing a varying number of volumes, like [`CompoundVolume2d`] and the [`broadphase`] structures,
+//! require the `alloc` feature.
+//!
+//! The unit tests of the crate also run without either feature, which checks that everything else compiles:
+//!
+//! ```text
+//! cargo test -p bevy_math --lib --no-default-features --features libm
+//! ```
 
 /// A trait that generalizes different bounding volumes.
 /// Bounding volumes are simplified shapes that are used to get simpler ways to check for
@@ -106,12 +123,268 @@ pub trait IntersectsVolume<Volume: BoundingVolume> {
     fn intersects(&self, volume: &Volume) -> bool;
 }
 
+/// A trait that generalizes measuring the distance between two volumes.
+///
+/// Unlike [`IntersectsVolume`], this also tells how far apart volumes are when they don't intersect,
+/// which is useful for proximity triggers or for steering away from obstacles before touching them.
+pub trait VolumeDistance<Volume: BoundingVolume> {
+    /// Computes the squared distance between the closest points of this volume and the given volume,
+    /// or `0.0` if they intersect.
+    fn distance_squared(&self, volume: &Volume) -> f32;
+
+    /// Computes the distance between the closest points of this volume and the given volume,
+    /// or `0.0` if they intersect.
+    #[inline]
+    fn distance(&self, volume: &Volume) -> f32 {
+        crate::ops::sqrt(self.distance_squared(volume))
+    }
+}
+
 mod bounded2d;
 pub use bounded2d::*;
 mod bounded3d;
 pub use bounded3d::*;
+#[cfg(feature = "alloc")]
+pub mod broadphase;
+pub mod gjk;
+mod interval;
+pub use interval::Interval1d;
 
 mod raycast2d;
 pub use raycast2d::*;
 mod raycast3d;
 pub use raycast3d::*;
+
+#[cfg(all(test, feature = "deterministic"))]
+mod determinism_tests {
+    use super::*;
+    use crate::{
+        ops,
+        primitives::{Capsule2d, Cuboid, Rectangle, RegularPolygon, Sphere},
+        Dir2, Dir3A, Isometry2d, Isometry3d, Quat, Rot2, Vec2, Vec3, Vec3A,
+    };
+
+    /// A 32-bit FNV-1a hasher for the bits of floating-point results.
+    struct Fnv1a(u32);
+
+    impl Fnv1a {
+        fn write_f32(&mut self, value: f32) {
+            for byte in value.to_bits().to_le_bytes() {
+                self.0 = (self.0 ^ u32::from(byte)).wrapping_mul(0x0100_0193);
+            }
+        }
+
+        fn write_opt(&mut self, value: Option<f32>) {
+            self.write_f32(value.unwrap_or(f32::NAN));
+        }
+    }
+
+    /// Hashes the results of a fixed scenario exercising the bounding volume math,
+    /// which must be bit-identical on every platform when the `deterministic` feature is enabled.
+    #[test]
+    fn bounding_math_matches_golden_hash() {
+        let mut hasher = Fnv1a(0x811c_9dc5);
+
+        for i in 0..64 {
+            let angle = i as f32 * 0.37;
+            let rotation = Rot2::radians(angle);
+            let translation = Vec2::new(ops::cos(angle * 1.3), ops::sin(angle * 0.7)) * 5.0;
+            let isometry = Isometry2d::new(translation, rotation);
+
+            let aabb = Rectangle::new(2.0, 1.0).aabb_2d(isometry);
+            let circle = RegularPolygon::new(1.5, 6).bounding_circle(isometry);
+            let capsule = Capsule2d::new(0.5, 2.0).aabb_2d(isometry);
+
+            let direction = Dir2::new(-translation).unwrap_or(Dir2::X);
+            let origin = Vec2::new(ops::sin(angle), ops::cos(angle)) * 20.0;
+            let ray = RayCast2d::new(origin, direction, 100.0);
+            let aabb_cast =
+                AabbCast2d::new(Aabb2d::new(Vec2::ZERO, Vec2::ONE), origin, direction, 100.0);
+            let circle_cast = BoundingCircleCast::new(
+                BoundingCircle::new(Vec2::ZERO, 0.5),
+                origin,
+                direction,
+                100.0,
+            );
+
+            for value in [aabb.min, aabb.max, capsule.min, capsule.max, circle.center] {
+                hasher.write_f32(value.x);
+                hasher.write_f32(value.y);
+            }
+            hasher.write_f32(circle.radius());
+            hasher.write_opt(ray.aabb_intersection_at(&aabb));
+            hasher.write_opt(ray.circle_intersection_at(&circle));
+            hasher.write_opt(aabb_cast.aabb_collision_at(aabb));
+            hasher.write_opt(aabb_cast.circle_collision_at(&circle));
+            hasher.write_opt(circle_cast.circle_collision_at(circle));
+            hasher.write_opt(circle_cast.aabb_collision_at(&aabb));
+
+            let rotation = Quat::from_euler(crate::EulerRot::XYZ, angle, angle * 0.5, angle * 0.25);
+            let translation = Vec3::new(
+                ops::cos(angle),
+                ops::sin(angle * 1.1),
+                ops::sin(angle * 0.3),
+            ) * 5.0;
+            let isometry = Isometry3d::new(translation, rotation);
+            let aabb = Cuboid::new(2.0, 1.0, 0.5).aabb_3d(isometry);
+            let sphere = Sphere::new(1.25).bounding_sphere(isometry);
+
+            let direction = Dir3A::new(Vec3A::from(-translation)).unwrap_or(Dir3A::X);
+            let origin = Vec3A::new(ops::sin(angle), ops::cos(angle), 0.5) * 20.0;
+            let ray = RayCast3d::new(origin, direction, 100.0);
+            let sphere_cast = BoundingSphereCast::new(
+                BoundingSphere::new(Vec3A::ZERO, 0.5),
+                origin,
+                direction,
+                100.0,
+            );
+
+            for value in [aabb.min, aabb.max, sphere.center] {
+                hasher.write_f32(value.x);
+                hasher.write_f32(value.y);
+                hasher.write_f32(value.z);
+            }
+            hasher.write_opt(ray.aabb_intersection_at(&aabb));
+            hasher.write_opt(ray.sphere_intersection_at(&sphere));
+            hasher.write_opt(sphere_cast.sphere_collision_at(sphere));
+            hasher.write_opt(sphere_cast.aabb_collision_at(&aabb));
+        }
+
+        // If this fails after an intentional change to the math, update the hash.
+        // It must never differ between platforms.
+        assert_eq!(hasher.0, 0x9208_7b3e);
+    }
+}
+
+#[cfg(all(test, not(feature = "alloc")))]
+mod no_std_tests {
+    use super::*;
+    use crate::{primitives::Rectangle, Dir2, Isometry2d, Rot2, Vec2};
+
+    /// Exercises the 2D volumes and ray casts without `alloc`.
+    #[test]
+    fn bounding_without_alloc() {
+        let isometry = Isometry2d::new(Vec2::new(2.0, 0.0), Rot2::degrees(30.0));
+        let aabb = Rectangle::new(2.0, 1.0).aabb_2d(isometry);
+        let circle = Rectangle::new(2.0, 1.0).bounding_circle(isometry);
+        assert!(aabb.intersects(&circle));
+        assert!(circle.contains(&BoundingCircle::new(Vec2::new(2.0, 0.0), 0.5)));
+
+        let merged = Aabb2d::from_volumes([aabb, aabb.translated_by(Vec2::Y)]).unwrap();
+        assert!(merged.contains(&aabb));
+
+        let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, 10.0);
+        assert!(ray.intersects(&aabb));
+        assert!(ray.circle_intersection_at(&circle).is_some());
+        assert_eq!(
+            ray.cast_nearest(&[circle], RayCast2d::circle_intersection_at)
+                .map(|(index, _)| index),
+            Some(0)
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod serialize_tests {
+    use super::*;
+    use crate::{Dir2, Dir3A, Vec2, Vec3A};
+    use serde::{de::DeserializeOwned, Serialize};
+
+    fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+        let json = serde_json::to_string(value).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn bounding_volumes() {
+        let aabb = Aabb2d::new(Vec2::new(1.0, -2.0), Vec2::new(0.5, 3.0));
+        assert_eq!(round_trip(&aabb), aabb);
+        let circle = BoundingCircle::new(Vec2::new(1.0, -2.0), 0.5);
+        assert_eq!(round_trip(&circle), circle);
+        let aabb = Aabb3d::new(Vec3A::new(1.0, -2.0, 3.0), Vec3A::new(0.5, 3.0, 1.0));
+        assert_eq!(round_trip(&aabb), aabb);
+        let sphere = BoundingSphere::new(Vec3A::new(1.0, -2.0, 3.0), 0.5);
+        assert_eq!(round_trip(&sphere), sphere);
+
+        // Only the stored fields are serialized
+        assert_eq!(
+            serde_json::to_string(&Aabb2d::new(Vec2::ZERO, Vec2::ONE)).unwrap(),
+            r#"{"min":[-1.0,-1.0],"max":[1.0,1.0]}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&BoundingCircle::new(Vec2::ZERO, 1.0)).unwrap(),
+            r#"{"center":[0.0,0.0],"circle":{"radius":1.0}}"#
+        );
+    }
+
+    #[test]
+    fn ray_casts_2d() {
+        let direction = Dir2::new(Vec2::new(1.0, 2.0)).unwrap();
+        let ray = RayCast2d::new(Vec2::new(1.0, -2.0), direction, 10.0).with_solid(false);
+        let json = serde_json::to_string(&ray).unwrap();
+        assert!(!json.contains("direction_recip"));
+        assert!(!json.contains("direction_positive"));
+
+        // The cached inverse direction and sign mask are recomputed
+        let deserialized: RayCast2d = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.ray, ray.ray);
+        assert_eq!(deserialized.max, ray.max);
+        assert_eq!(deserialized.solid, ray.solid);
+        assert_eq!(deserialized.direction_recip(), ray.direction_recip());
+        assert_eq!(deserialized.direction_positive(), ray.direction_positive());
+
+        let ray = DRayCast2d::new(Vec2::new(1.0, -2.0).as_dvec2(), direction, 10.0);
+        let deserialized = round_trip(&ray);
+        assert_eq!(deserialized.origin, ray.origin);
+        assert_eq!(deserialized.direction(), ray.direction());
+        assert_eq!(deserialized.max, ray.max);
+        assert_eq!(deserialized.solid, ray.solid);
+        assert_eq!(deserialized.direction_recip(), ray.direction_recip());
+
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        let cast = AabbCast2d::new(aabb, Vec2::new(-5.0, 0.0), Dir2::X, 10.0);
+        let deserialized = round_trip(&cast);
+        assert_eq!(deserialized.aabb, cast.aabb);
+        assert_eq!(deserialized.ray.ray, cast.ray.ray);
+        assert_eq!(deserialized.aabb_collision_at(aabb), Some(3.0));
+
+        let circle = BoundingCircle::new(Vec2::ZERO, 1.0);
+        let cast = BoundingCircleCast::new(circle, Vec2::new(-5.0, 0.0), Dir2::X, 10.0);
+        let deserialized = round_trip(&cast);
+        assert_eq!(deserialized.circle, cast.circle);
+        assert_eq!(deserialized.ray.ray, cast.ray.ray);
+        assert_eq!(deserialized.circle_collision_at(circle), Some(3.0));
+
+        let hit = RayHit2d {
+            distance: 2.0,
+            normal: Dir2::NEG_X,
+        };
+        assert_eq!(round_trip(&hit), hit);
+    }
+
+    #[test]
+    fn ray_casts_3d() {
+        let direction = Dir3A::new(Vec3A::new(1.0, 2.0, -3.0)).unwrap();
+        let ray = RayCast3d::new(Vec3A::new(1.0, -2.0, 3.0), direction, 10.0);
+        let json = serde_json::to_string(&ray).unwrap();
+        assert!(!json.contains("direction_recip"));
+
+        let deserialized: RayCast3d = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.origin, ray.origin);
+        assert_eq!(deserialized.direction, ray.direction);
+        assert_eq!(deserialized.max, ray.max);
+        assert_eq!(deserialized.direction_recip(), ray.direction_recip());
+
+        let aabb = Aabb3d::new(Vec3A::ZERO, Vec3A::ONE);
+        let cast = AabbCast3d::new(aabb, Vec3A::new(-5.0, 0.0, 0.0), Dir3A::X, 10.0);
+        let deserialized = round_trip(&cast);
+        assert_eq!(deserialized.aabb, cast.aabb);
+        assert_eq!(deserialized.aabb_collision_at(aabb), Some(3.0));
+
+        let sphere = BoundingSphere::new(Vec3A::ZERO, 1.0);
+        let cast = BoundingSphereCast::new(sphere, Vec3A::new(-5.0, 0.0, 0.0), Dir3A::X, 10.0);
+        let deserialized = round_trip(&cast);
+        assert_eq!(deserialized.sphere, cast.sphere);
+        assert_eq!(deserialized.sphere_collision_at(sphere), Some(3.0));
+    }
+}
@@ -1,22 +1,117 @@
-use super::{Aabb2d, BoundingCircle, IntersectsVolume};
+use super::{
+    Aabb2d, BoundingCapsule2d, BoundingCircle, DAabb2d, DBoundingCircle, IntersectsVolume,
+    Interval1d, Obb2d,
+};
 use crate::{
     ops::{self, FloatPow},
-    Dir2, Ray2d, Vec2,
+    primitives::{Annulus, Plane2d, Segment2d},
+    BVec2, DVec2, Dir2, Ray2d, Vec2,
 };
+use smallvec::SmallVec;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// The result of a successful [`RayCast2d`] against a bounding volume.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct RayHit2d {
+    /// The distance along the ray at which the volume was hit
+    pub distance: f32,
+    /// The outward surface normal of the volume at the hit point
+    pub normal: Dir2,
+}
+
+/// The result of a successful swept cast, like an [`AabbCast2d`], against a bounding volume.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SweepHit2d {
+    /// The distance along the ray at which the swept volume first touches the target
+    pub toi: f32,
+    /// The point of contact between the swept volume and the target at the time of impact
+    pub point: Vec2,
+    /// The outward surface normal of the target at the point of contact
+    pub normal: Dir2,
+}
 
 /// A raycast intersection test for 2D bounding volumes
+///
+/// The inverse of the ray direction is computed once on construction, and reused by every test
+/// against an AABB, so a single ray cast is best reused across many volumes.
+/// For one-off casts, [`Aabb2d::cast_ray`] is more convenient.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, Clone))]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Serialize),
+    derive(Deserialize),
+    serde(from = "RayCast2dFields")
+)]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct RayCast2d {
     /// The ray for the test
     pub ray: Ray2d,
     /// The maximum distance for the ray
     pub max: f32,
+    /// Whether volumes are treated as solid.
+    ///
+    /// If `true`, a ray that starts inside a volume hits it at a distance of `0.0`.
+    /// If `false`, the ray only hits the boundary of the volume, so a ray that starts inside
+    /// a volume hits it where it exits the volume instead.
+    ///
+    /// Defaults to `true`.
+    pub solid: bool,
     /// The multiplicative inverse direction of the ray
+    #[cfg_attr(feature = "serialize", serde(skip_serializing))]
     direction_recip: Vec2,
+    /// Whether each component of the direction of the ray has a positive sign,
+    /// which determines whether the slabs of an AABB are entered through their minimum or maximum side
+    #[cfg_attr(feature = "serialize", serde(skip_serializing))]
+    direction_positive: BVec2,
+}
+
+/// The serialized fields of a [`RayCast2d`], without the cached inverse direction and sign mask.
+#[cfg(feature = "serialize")]
+#[derive(Deserialize)]
+struct RayCast2dFields {
+    ray: Ray2d,
+    max: f32,
+    solid: bool,
+}
+
+#[cfg(feature = "serialize")]
+impl From<RayCast2dFields> for RayCast2d {
+    fn from(fields: RayCast2dFields) -> Self {
+        Self::from_ray(fields.ray, fields.max).with_solid(fields.solid)
+    }
 }
 
 impl RayCast2d {
@@ -30,51 +125,223 @@ impl RayCast2d {
         Self {
             ray,
             direction_recip: ray.direction.recip(),
+            // The sign bit is used rather than a comparison, so that `-0.0` is treated as negative
+            direction_positive: BVec2::new(
+                ray.direction.x.is_sign_positive(),
+                ray.direction.y.is_sign_positive(),
+            ),
             max,
+            solid: true,
         }
     }
 
+    /// Returns this [`RayCast2d`] with [`solid`](Self::solid) set to the given value.
+    pub fn with_solid(mut self, solid: bool) -> Self {
+        self.solid = solid;
+        self
+    }
+
     /// Get the cached multiplicative inverse of the direction of the ray.
     pub fn direction_recip(&self) -> Vec2 {
         self.direction_recip
     }
 
+    /// Get the cached mask of which components of the direction of the ray have a positive sign.
+    pub fn direction_positive(&self) -> BVec2 {
+        self.direction_positive
+    }
+
+    /// Checks if a chord of a circle centered `center_distance` along the ray, with a squared half-length
+    /// of `half_chord_squared`, starts beyond the max distance, without taking a square root.
+    #[inline(always)]
+    fn is_beyond_max(&self, center_distance: f32, half_chord_squared: f32) -> bool {
+        // `center_distance - sqrt(half_chord_squared) > max`
+        let beyond_max = center_distance - self.max;
+        beyond_max > 0. && beyond_max.squared() > half_chord_squared
+    }
+
+    /// Computes the distance of a hit from the interval of distances at which the ray is inside a volume,
+    /// taking [`solid`](Self::solid) and the max distance into account.
+    #[inline(always)]
+    fn hit_distance(&self, inside: Interval1d) -> Option<f32> {
+        let clamped = inside.clamp_to(0., self.max);
+
+        if clamped.is_empty() {
+            None
+        } else if inside.min < 0. && !self.solid {
+            // The ray starts inside the volume, and only hits it on the way out
+            (inside.max <= self.max).then_some(inside.max)
+        } else {
+            Some(clamped.min)
+        }
+    }
+
     /// Get the distance of an intersection with an [`Aabb2d`], if any.
     pub fn aabb_intersection_at(&self, aabb: &Aabb2d) -> Option<f32> {
-        let (min_x, max_x) = if self.ray.direction.x.is_sign_positive() {
-            (aabb.min.x, aabb.max.x)
-        } else {
-            (aabb.max.x, aabb.min.x)
-        };
-        let (min_y, max_y) = if self.ray.direction.y.is_sign_positive() {
-            (aabb.min.y, aabb.max.y)
-        } else {
-            (aabb.max.y, aabb.min.y)
-        };
+        self.hit_distance(self.aabb_slabs(aabb))
+    }
 
-        // Calculate the minimum/maximum time for each axis based on how much the direction goes that
-        // way. These values can get arbitrarily large, or even become NaN, which is handled by the
-        // min/max operations below
-        let tmin_x = (min_x - self.ray.origin.x) * self.direction_recip.x;
-        let tmin_y = (min_y - self.ray.origin.y) * self.direction_recip.y;
-        let tmax_x = (max_x - self.ray.origin.x) * self.direction_recip.x;
-        let tmax_y = (max_y - self.ray.origin.y) * self.direction_recip.y;
-
-        // An axis that is not relevant to the ray direction will be NaN. When one of the arguments
-        // to min/max is NaN, the other argument is used.
+    /// Get the distances at which the ray enters and exits an [`Aabb2d`], if it hits it.
+    ///
+    /// Like for [`RayCast2d::circle_intersections`], the distances are sorted and clamped to the range of the ray,
+    /// and [`solid`](Self::solid) is ignored.
+    pub fn aabb_intersections(&self, aabb: &Aabb2d) -> Option<(f32, f32)> {
+        let clamped = self.aabb_slabs(aabb).clamp_to(0., self.max);
+        (!clamped.is_empty()).then_some((clamped.min, clamped.max))
+    }
+
+    /// Get the unclamped interval of distances at which the ray line is inside the slabs of an [`Aabb2d`].
+    ///
+    /// The interval is empty if the line misses the AABB.
+    #[inline(always)]
+    fn aabb_slabs(&self, aabb: &Aabb2d) -> Interval1d {
+        let [x, y] = self.aabb_axis_slabs(aabb);
+
+        // An axis that is not relevant to the ray direction will be NaN. When one of the bounds
+        // of an intersected interval is NaN, the other interval's bound is used.
         // An axis for which the direction is the wrong way will return an arbitrarily large
         // negative value.
-        let tmin = tmin_x.max(tmin_y).max(0.);
-        let tmax = tmax_y.min(tmax_x).min(self.max);
+        x.intersect(y)
+    }
 
-        if tmin <= tmax {
-            Some(tmin)
+    /// Get the unclamped intervals of distances at which the ray line is inside the slab of an [`Aabb2d`]
+    /// along the X axis and along the Y axis.
+    #[inline(always)]
+    fn aabb_axis_slabs(&self, aabb: &Aabb2d) -> [Interval1d; 2] {
+        let near = Vec2::select(self.direction_positive, aabb.min, aabb.max);
+        let far = Vec2::select(self.direction_positive, aabb.max, aabb.min);
+
+        // Calculate the minimum/maximum time for each axis based on how much the direction goes that
+        // way. These values can get arbitrarily large, or even become NaN, which is handled by the
+        // intersection of the intervals in `aabb_slabs`
+        let t_near = (near - self.ray.origin) * self.direction_recip;
+        let t_far = (far - self.ray.origin) * self.direction_recip;
+        [
+            Interval1d::new(t_near.x, t_far.x),
+            Interval1d::new(t_near.y, t_far.y),
+        ]
+    }
+
+    /// Get the distance of an intersection with an [`Obb2d`], if any.
+    ///
+    /// The ray is rotated into the local space of the box, where it is tested like an [`Aabb2d`].
+    /// Rotations preserve distances, so the returned distance is along the original ray.
+    pub fn obb_intersection_at(&self, obb: &Obb2d) -> Option<f32> {
+        let inverse_rotation = obb.rotation.inverse();
+        let local = RayCast2d::new(
+            inverse_rotation * (self.ray.origin - obb.center),
+            inverse_rotation * self.ray.direction,
+            self.max,
+        )
+        .with_solid(self.solid);
+        local.aabb_intersection_at(&Aabb2d::new(Vec2::ZERO, obb.half_size))
+    }
+
+    /// Get the distance and surface normal of an intersection with an [`Aabb2d`], if any.
+    ///
+    /// The normal is the outward normal of the face through which the ray enters the AABB.
+    /// If the ray starts inside the AABB, the normal is instead that of the face through which
+    /// the ray exits the AABB. The distance is then `0.0` if the ray cast is [`solid`](Self::solid),
+    /// like for [`Self::aabb_intersection_at`].
+    pub fn aabb_intersection(&self, aabb: &Aabb2d) -> Option<RayHit2d> {
+        let inside = self.aabb_slabs(aabb);
+        let distance = self.hit_distance(inside)?;
+
+        // The slab that is crossed last on entry, or first on exit, is the one that was hit.
+        // Normals point against the ray on entry, and along the ray on exit.
+        let [x, y] = self.aabb_axis_slabs(aabb);
+        let sign = Vec2::select(self.direction_positive, Vec2::ONE, Vec2::NEG_ONE);
+        let normal = if inside.min < 0. {
+            if x.max <= y.max || y.max.is_nan() {
+                Vec2::new(sign.x, 0.)
+            } else {
+                Vec2::new(0., sign.y)
+            }
+        } else if x.min >= y.min || y.min.is_nan() {
+            Vec2::new(-sign.x, 0.)
         } else {
-            None
-        }
+            Vec2::new(0., -sign.y)
+        };
+
+        Some(RayHit2d {
+            distance,
+            normal: Dir2::new_unchecked(normal),
+        })
+    }
+
+    /// Get the distances of intersections with each [`Aabb2d`] in a slice.
+    ///
+    /// `out` is cleared, and then filled with the same values that [`Self::aabb_intersection_at`]
+    /// would return for each of the `aabbs`, in the same order.
+    ///
+    /// The inverse direction and the per-axis ordering of the slabs only depend on the ray,
+    /// and are cached on construction, leaving a tight loop body that is easy to vectorize.
+    #[cfg(feature = "alloc")]
+    pub fn intersect_many(&self, aabbs: &[Aabb2d], out: &mut Vec<Option<f32>>) {
+        out.clear();
+        out.extend(aabbs.iter().map(|aabb| self.aabb_intersection_at(aabb)));
+    }
+
+    /// Casts the ray against each of the `volumes`, using `intersection_at` to get the distance
+    /// of the intersection with each of them, and returns the index and distance of every hit,
+    /// sorted from nearest to farthest.
+    ///
+    /// Hits at the same distance are kept in the order of the `volumes`.
+    /// Use [`Self::cast_nearest`] if only the nearest hit is needed, which avoids allocating and sorting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::{Aabb2d, RayCast2d}, Dir2, Vec2};
+    /// let ray_cast = RayCast2d::new(Vec2::ZERO, Dir2::X, 100.0);
+    /// let volumes = [
+    ///     Aabb2d::new(Vec2::new(10.0, 0.0), Vec2::ONE),
+    ///     Aabb2d::new(Vec2::new(5.0, 0.0), Vec2::ONE),
+    ///     Aabb2d::new(Vec2::new(5.0, 5.0), Vec2::ONE),
+    /// ];
+    ///
+    /// let hits = ray_cast.cast_all(&volumes, RayCast2d::aabb_intersection_at);
+    /// assert_eq!(hits, [(1, 4.0), (0, 9.0)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn cast_all<T>(
+        &self,
+        volumes: impl IntoIterator<Item = T>,
+        mut intersection_at: impl FnMut(&Self, T) -> Option<f32>,
+    ) -> Vec<(usize, f32)> {
+        let mut hits: Vec<_> = volumes
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, volume)| {
+                intersection_at(self, volume).map(|distance| (index, distance))
+            })
+            .collect();
+        hits.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        hits
+    }
+
+    /// Casts the ray against each of the `volumes`, using `intersection_at` to get the distance
+    /// of the intersection with each of them, and returns the index and distance of the nearest hit.
+    ///
+    /// If several volumes are hit at the nearest distance, the first of them is returned.
+    pub fn cast_nearest<T>(
+        &self,
+        volumes: impl IntoIterator<Item = T>,
+        mut intersection_at: impl FnMut(&Self, T) -> Option<f32>,
+    ) -> Option<(usize, f32)> {
+        volumes
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, volume)| {
+                intersection_at(self, volume).map(|distance| (index, distance))
+            })
+            .reduce(|nearest, hit| if hit.1 < nearest.1 { hit } else { nearest })
     }
 
     /// Get the distance of an intersection with a [`BoundingCircle`], if any.
+    ///
+    /// Circles that are beside the ray, behind it, or beyond its max distance
+    /// are rejected without taking a square root.
     pub fn circle_intersection_at(&self, circle: &BoundingCircle) -> Option<f32> {
         let offset = self.ray.origin - circle.center;
         let projected = offset.dot(*self.ray.direction);
@@ -82,16 +349,289 @@ impl RayCast2d {
         let distance_squared = circle.radius().squared() - closest_point.length_squared();
         if distance_squared < 0.
             || ops::copysign(projected.squared(), -projected) < -distance_squared
+            || self.is_beyond_max(-projected, distance_squared)
         {
             None
         } else {
-            let toi = -projected - ops::sqrt(distance_squared);
-            if toi > self.max {
-                None
-            } else {
-                Some(toi.max(0.))
+            let half_chord = ops::sqrt(distance_squared);
+            self.hit_distance(Interval1d::new(
+                -projected - half_chord,
+                -projected + half_chord,
+            ))
+        }
+    }
+
+    /// Get the distances at which the ray enters and exits a [`BoundingCircle`], if it hits it.
+    ///
+    /// The distances are sorted and clamped to the range of the ray, so a ray that starts inside the circle
+    /// enters it at a distance of `0.0`, and a ray that ends inside the circle exits it at its max distance.
+    /// A ray that is tangent to the circle returns the same distance twice.
+    ///
+    /// This ignores [`solid`](Self::solid), since both the entry and exit are always returned.
+    pub fn circle_intersections(&self, circle: &BoundingCircle) -> Option<(f32, f32)> {
+        let offset = self.ray.origin - circle.center;
+        let projected = offset.dot(*self.ray.direction);
+        let closest_point = offset - projected * *self.ray.direction;
+        let distance_squared = circle.radius().squared() - closest_point.length_squared();
+        if distance_squared < 0. {
+            return None;
+        }
+
+        let half_chord = ops::sqrt(distance_squared);
+        let entry = -projected - half_chord;
+        let exit = -projected + half_chord;
+        if exit < 0. || entry > self.max {
+            None
+        } else {
+            Some((entry.max(0.), exit.min(self.max)))
+        }
+    }
+
+    /// Get the distances at which the ray crosses the boundary of the given [`Annulus`] centered at `center`.
+    ///
+    /// A ray can cross an annulus up to four times: entering and exiting through the outer circle,
+    /// and exiting and entering again through the inner circle, if it passes through the hole.
+    /// The distances are sorted, and only those within the range of the ray are returned,
+    /// so a ray that starts in the hole only returns the crossings in front of it.
+    /// A ray that is tangent to one of the circles returns the distance of the tangent point twice.
+    ///
+    /// This ignores [`solid`](Self::solid), since all crossings are returned.
+    pub fn annulus_intersections(&self, center: Vec2, annulus: &Annulus) -> SmallVec<[f32; 4]> {
+        let offset = self.ray.origin - center;
+        let projected = offset.dot(*self.ray.direction);
+        let closest_point = offset - projected * *self.ray.direction;
+        let closest_distance_squared = closest_point.length_squared();
+
+        // Returns the half of the chord the ray cuts through a circle of the given radius, if it hits it
+        let half_chord = |radius: f32| {
+            let distance_squared = radius.squared() - closest_distance_squared;
+            (distance_squared >= 0.).then(|| ops::sqrt(distance_squared))
+        };
+
+        let mut distances = SmallVec::new();
+        let Some(outer) = half_chord(annulus.outer_circle.radius) else {
+            return distances;
+        };
+        let inner = half_chord(annulus.inner_circle.radius);
+
+        // The crossings of the inner circle are always between those of the outer circle
+        let crossings = [
+            Some(-projected - outer),
+            inner.map(|inner| -projected - inner),
+            inner.map(|inner| -projected + inner),
+            Some(-projected + outer),
+        ];
+        distances.extend(
+            crossings
+                .into_iter()
+                .flatten()
+                .filter(|distance| (0.0..=self.max).contains(distance)),
+        );
+        distances
+    }
+
+    /// Get the distance and surface normal of an intersection with a [`BoundingCircle`], if any.
+    ///
+    /// The normal points from the center of the circle to the hit point.
+    /// If the ray starts inside the circle, the normal is instead that of the point where the ray
+    /// exits the circle. The distance is then `0.0` if the ray cast is [`solid`](Self::solid),
+    /// like for [`Self::circle_intersection_at`].
+    pub fn circle_intersection(&self, circle: &BoundingCircle) -> Option<RayHit2d> {
+        let offset = self.ray.origin - circle.center;
+        let projected = offset.dot(*self.ray.direction);
+        let closest_point = offset - projected * *self.ray.direction;
+        let distance_squared = circle.radius().squared() - closest_point.length_squared();
+        if distance_squared < 0.
+            || ops::copysign(projected.squared(), -projected) < -distance_squared
+            || self.is_beyond_max(-projected, distance_squared)
+        {
+            return None;
+        }
+
+        let half_chord = ops::sqrt(distance_squared);
+        let entry = -projected - half_chord;
+        let exit = -projected + half_chord;
+        let distance = self.hit_distance(Interval1d::new(entry, exit))?;
+
+        let normal = if entry < 0. {
+            Dir2::new(offset + *self.ray.direction * exit).unwrap_or(self.ray.direction)
+        } else {
+            Dir2::new(offset + *self.ray.direction * entry).unwrap_or(-self.ray.direction)
+        };
+
+        Some(RayHit2d { distance, normal })
+    }
+
+    /// Get the distance of an intersection with a [`Segment2d`], if any.
+    ///
+    /// The normal of the segment is its [left normal](Segment2d::left_normal). If `cull_backface` is `true`,
+    /// hits on the back of the segment, where the normal faces the same direction as the ray, are rejected.
+    ///
+    /// A ray that is parallel to the segment never hits it, even if it is collinear with the segment.
+    pub fn segment_intersection_at(&self, segment: &Segment2d, cull_backface: bool) -> Option<f32> {
+        let scaled_direction = segment.scaled_direction();
+        let denominator = self.ray.direction.perp_dot(scaled_direction);
+        if denominator == 0. {
+            return None;
+        }
+        if cull_backface && segment.scaled_left_normal().dot(*self.ray.direction) > 0. {
+            return None;
+        }
+
+        // Solve `ray.origin + ray.direction * distance = segment.point1() + scaled_direction * t`
+        let offset = segment.point1() - self.ray.origin;
+        let distance = offset.perp_dot(scaled_direction) / denominator;
+        let t = offset.perp_dot(*self.ray.direction) / denominator;
+
+        ((0.0..=1.0).contains(&t) && (0.0..=self.max).contains(&distance)).then_some(distance)
+    }
+
+    /// Get the index of the first segment hit in the polyline through the given `points`,
+    /// along with the distance of the intersection, if any.
+    ///
+    /// Segment `i` connects `points[i]` and `points[i + 1]`. Segments are hit from either side,
+    /// like for [`RayCast2d::segment_intersection_at`] without backface culling.
+    /// If several segments are hit at the same distance, like at a shared vertex, the first one is returned.
+    pub fn polyline_intersection_at(&self, points: &[Vec2]) -> Option<(usize, f32)> {
+        points
+            .windows(2)
+            .enumerate()
+            .filter_map(|(index, segment)| {
+                self.segment_intersection_at(&Segment2d::new(segment[0], segment[1]), false)
+                    .map(|distance| (index, distance))
+            })
+            .reduce(|nearest, hit| if hit.1 < nearest.1 { hit } else { nearest })
+    }
+
+    /// Get the distance of an intersection with a [`Plane2d`] placed at `plane_origin`, if any.
+    ///
+    /// The plane is hit from either side, and [`RayCast2d::solid`] is ignored.
+    /// A ray that is parallel to the plane never hits it, even if it lies in the plane.
+    pub fn plane_intersection_at(&self, plane_origin: Vec2, plane: &Plane2d) -> Option<f32> {
+        let denominator = plane.normal.dot(*self.ray.direction);
+        if denominator == 0. {
+            return None;
+        }
+        let distance = -plane.signed_distance(plane_origin, self.ray.origin) / denominator;
+        (0.0..=self.max).contains(&distance).then_some(distance)
+    }
+
+    /// Get the distance of an intersection with a [`BoundingCapsule2d`], if any.
+    pub fn capsule_intersection_at(&self, capsule: &BoundingCapsule2d) -> Option<f32> {
+        let [start, end] = capsule.segment.vertices;
+        let radius = capsule.radius;
+
+        // The capsule is the union of the circles at its ends and the rectangle between them.
+        // It is convex, so the ray is inside it during the smallest interval containing all three.
+        let ends = self
+            .circle_chord(start, radius)
+            .merge(self.circle_chord(end, radius));
+        let inside = match Dir2::new(end - start) {
+            Ok(axis) => {
+                let offset = self.ray.origin - start;
+                let normal = axis.perp();
+                let along = slab(
+                    offset.dot(*axis),
+                    self.ray.direction.dot(*axis),
+                    0.,
+                    start.distance(end),
+                );
+                let across = slab(
+                    offset.dot(normal),
+                    self.ray.direction.dot(normal),
+                    -radius,
+                    radius,
+                );
+                ends.merge(along.intersect(across))
             }
+            // A capsule with a degenerate segment is a circle
+            Err(_) => ends,
+        };
+        self.hit_distance(inside)
+    }
+
+    /// Get the unclamped interval of distances at which the ray line is inside the circle
+    /// of the given `radius` around `center`.
+    #[inline(always)]
+    fn circle_chord(&self, center: Vec2, radius: f32) -> Interval1d {
+        let offset = self.ray.origin - center;
+        let projected = offset.dot(*self.ray.direction);
+        let closest_point = offset - projected * *self.ray.direction;
+        let distance_squared = radius.squared() - closest_point.length_squared();
+        if distance_squared < 0. {
+            return Interval1d::EMPTY;
         }
+        let half_chord = ops::sqrt(distance_squared);
+        Interval1d::new(-projected - half_chord, -projected + half_chord)
+    }
+
+    /// Get the distance of an intersection with the [`Aabb2d`] expanded by `radius` in all directions,
+    /// with its corners rounded, if there is one.
+    ///
+    /// This is the Minkowski sum of the box and a circle, used for sweeping circles against boxes.
+    ///
+    /// Like for the other volumes, [`solid`](Self::solid) only matters if the ray starts inside
+    /// the rounded box, in which case a non-solid ray hits it where it exits.
+    fn rounded_aabb_intersection_at(&self, aabb: &Aabb2d, radius: f32) -> Option<f32> {
+        self.hit_distance(self.rounded_aabb_chord(aabb, radius))
+    }
+
+    /// Get the unclamped interval of distances at which the ray line is inside the [`Aabb2d`]
+    /// expanded by `radius` in all directions, with its corners rounded.
+    #[inline(always)]
+    fn rounded_aabb_chord(&self, aabb: &Aabb2d, radius: f32) -> Interval1d {
+        let expanded = Aabb2d {
+            min: aabb.min - radius,
+            max: aabb.max + radius,
+        };
+        let slabs = self.aabb_slabs(&expanded);
+        if slabs.is_empty() {
+            return Interval1d::EMPTY;
+        }
+
+        // The rounded corners are not covered by the expanded box. Where the line enters or exits
+        // the expanded box in one of the corner regions, it has to enter or exit the rounded box
+        // through the circle around that corner, and misses the rounded box if it misses the circle.
+        let corner_chord = |distance: f32| {
+            let point = self.ray.get_point(distance);
+            let below = point.cmplt(aabb.min);
+            ((below | point.cmpgt(aabb.max)).all())
+                .then(|| self.circle_chord(Vec2::select(below, aabb.min, aabb.max), radius))
+        };
+        let min = match corner_chord(slabs.min) {
+            Some(chord) if chord.is_empty() => return Interval1d::EMPTY,
+            Some(chord) => chord.min,
+            None => slabs.min,
+        };
+        let max = match corner_chord(slabs.max) {
+            Some(chord) if chord.is_empty() => return Interval1d::EMPTY,
+            Some(chord) => chord.max,
+            None => slabs.max,
+        };
+        Interval1d::new(min, max)
+    }
+}
+
+/// Get the unclamped interval of distances at which a point that starts at `origin` on an axis,
+/// and moves along it by `speed` per unit of distance, is between `min` and `max`.
+#[inline(always)]
+fn slab(origin: f32, speed: f32, min: f32, max: f32) -> Interval1d {
+    if speed == 0. {
+        if (min..=max).contains(&origin) {
+            Interval1d::EVERYTHING
+        } else {
+            Interval1d::EMPTY
+        }
+    } else {
+        let to_min = (min - origin) / speed;
+        let to_max = (max - origin) / speed;
+        Interval1d::new(to_min.min(to_max), to_min.max(to_max))
+    }
+}
+
+impl IntersectsVolume<BoundingCapsule2d> for RayCast2d {
+    fn intersects(&self, volume: &BoundingCapsule2d) -> bool {
+        self.capsule_intersection_at(volume).is_some()
     }
 }
 
@@ -107,9 +647,220 @@ impl IntersectsVolume<BoundingCircle> for RayCast2d {
     }
 }
 
+impl IntersectsVolume<Obb2d> for RayCast2d {
+    fn intersects(&self, volume: &Obb2d) -> bool {
+        self.obb_intersection_at(volume).is_some()
+    }
+}
+
+/// Casts the `ray` against the AABB of each of the `items`, skipping the items rejected by `filter`,
+/// and returns the payload and distance of the nearest hit.
+///
+/// This is the usual way to cast a ray while ignoring some objects, like the ones on a different
+/// collision layer. The `filter` is checked before the AABB, so rejected items cost nothing
+/// more than the call to `filter`. If several items are hit at the nearest distance,
+/// the first of them is returned.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_math::{bounding::{ray_cast_first, Aabb2d, RayCast2d}, Dir2, Vec2};
+/// let ray_cast = RayCast2d::new(Vec2::ZERO, Dir2::X, 100.0);
+/// let items = [
+///     (Aabb2d::new(Vec2::new(5.0, 0.0), Vec2::ONE), "glass"),
+///     (Aabb2d::new(Vec2::new(10.0, 0.0), Vec2::ONE), "wall"),
+/// ];
+///
+/// let hit = ray_cast_first(&ray_cast, items.into_iter(), |_| true);
+/// assert_eq!(hit, Some(("glass", 4.0)));
+///
+/// // See through the glass
+/// let hit = ray_cast_first(&ray_cast, items.into_iter(), |material| *material != "glass");
+/// assert_eq!(hit, Some(("wall", 9.0)));
+/// ```
+pub fn ray_cast_first<T>(
+    ray: &RayCast2d,
+    items: impl Iterator<Item = (Aabb2d, T)>,
+    filter: impl Fn(&T) -> bool,
+) -> Option<(T, f32)> {
+    items
+        .filter(|(_, item)| filter(item))
+        .filter_map(|(aabb, item)| {
+            ray.aabb_intersection_at(&aabb)
+                .map(|distance| (item, distance))
+        })
+        .reduce(|nearest, hit| if hit.1 < nearest.1 { hit } else { nearest })
+}
+
+/// Returns the square root of an `f64`.
+///
+/// [`ops`] only covers `f32`, so this forwards to the same backends for `f64`.
+#[inline(always)]
+fn sqrt_f64(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        f64::sqrt(x)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sqrt(x)
+    }
+}
+
+/// A raycast intersection test for 2D double-precision bounding volumes
+///
+/// This is the `f64` counterpart of [`RayCast2d`], for testing against [`DAabb2d`] and [`DBoundingCircle`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, Clone))]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Serialize),
+    derive(Deserialize),
+    serde(from = "DRayCast2dFields")
+)]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DRayCast2d {
+    /// The origin of the ray
+    pub origin: DVec2,
+    /// The normalized direction of the ray
+    direction: DVec2,
+    /// The maximum distance for the ray
+    pub max: f64,
+    /// Whether volumes are treated as solid.
+    ///
+    /// See [`RayCast2d::solid`] for details. Defaults to `true`.
+    pub solid: bool,
+    /// The multiplicative inverse direction of the ray
+    #[cfg_attr(feature = "serialize", serde(skip_serializing))]
+    direction_recip: DVec2,
+}
+
+/// The serialized fields of a [`DRayCast2d`], without the cached inverse direction.
+#[cfg(feature = "serialize")]
+#[derive(Deserialize)]
+struct DRayCast2dFields {
+    origin: DVec2,
+    direction: DVec2,
+    max: f64,
+    solid: bool,
+}
+
+#[cfg(feature = "serialize")]
+impl From<DRayCast2dFields> for DRayCast2d {
+    fn from(fields: DRayCast2dFields) -> Self {
+        Self {
+            origin: fields.origin,
+            direction: fields.direction,
+            max: fields.max,
+            solid: fields.solid,
+            direction_recip: fields.direction.recip(),
+        }
+    }
+}
+
+impl DRayCast2d {
+    /// Construct a [`DRayCast2d`] from an origin, [`Dir2`], and max distance.
+    pub fn new(origin: DVec2, direction: Dir2, max: f64) -> Self {
+        // Renormalize in double precision
+        let direction = direction.as_dvec2().normalize();
+        Self {
+            origin,
+            direction,
+            max,
+            solid: true,
+            direction_recip: direction.recip(),
+        }
+    }
+
+    /// Returns this [`DRayCast2d`] with [`solid`](Self::solid) set to the given value.
+    pub fn with_solid(mut self, solid: bool) -> Self {
+        self.solid = solid;
+        self
+    }
+
+    /// Get the normalized direction of the ray.
+    pub fn direction(&self) -> DVec2 {
+        self.direction
+    }
+
+    /// Get the cached multiplicative inverse of the direction of the ray.
+    pub fn direction_recip(&self) -> DVec2 {
+        self.direction_recip
+    }
+
+    /// Get the point at the given distance along the ray.
+    pub fn get_point(&self, distance: f64) -> DVec2 {
+        self.origin + self.direction * distance
+    }
+
+    /// See [`RayCast2d::hit_distance`].
+    #[inline(always)]
+    fn hit_distance(&self, entry: f64, exit: f64) -> Option<f64> {
+        let tmin = entry.max(0.);
+        let tmax = exit.min(self.max);
+
+        if tmin > tmax {
+            None
+        } else if entry < 0. && !self.solid {
+            (exit <= self.max).then_some(exit)
+        } else {
+            Some(tmin)
+        }
+    }
+
+    /// Get the distance of an intersection with a [`DAabb2d`], if any.
+    pub fn aabb_intersection_at(&self, aabb: &DAabb2d) -> Option<f64> {
+        let positive = BVec2::new(
+            self.direction.x.is_sign_positive(),
+            self.direction.y.is_sign_positive(),
+        );
+        let near = DVec2::select(positive, aabb.min, aabb.max);
+        let far = DVec2::select(positive, aabb.max, aabb.min);
+
+        // See `RayCast2d::aabb_intersection_at` for how NaN and infinite values are handled
+        let t_near = (near - self.origin) * self.direction_recip;
+        let t_far = (far - self.origin) * self.direction_recip;
+        self.hit_distance(t_near.x.max(t_near.y), t_far.y.min(t_far.x))
+    }
+
+    /// Get the distance of an intersection with a [`DBoundingCircle`], if any.
+    pub fn circle_intersection_at(&self, circle: &DBoundingCircle) -> Option<f64> {
+        let offset = self.origin - circle.center;
+        let projected = offset.dot(self.direction);
+        let closest_point = offset - projected * self.direction;
+        let distance_squared = circle.radius() * circle.radius() - closest_point.length_squared();
+        if distance_squared < 0. || (projected > 0. && projected * projected > distance_squared) {
+            None
+        } else {
+            let half_chord = sqrt_f64(distance_squared);
+            self.hit_distance(-projected - half_chord, -projected + half_chord)
+        }
+    }
+}
+
+impl IntersectsVolume<DAabb2d> for DRayCast2d {
+    fn intersects(&self, volume: &DAabb2d) -> bool {
+        self.aabb_intersection_at(volume).is_some()
+    }
+}
+
+impl IntersectsVolume<DBoundingCircle> for DRayCast2d {
+    fn intersects(&self, volume: &DBoundingCircle) -> bool {
+        self.circle_intersection_at(volume).is_some()
+    }
+}
+
 /// An intersection test that casts an [`Aabb2d`] along a ray.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, Clone))]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct AabbCast2d {
     /// The ray along which to cast the bounding volume
     pub ray: RayCast2d,
@@ -137,6 +888,58 @@ impl AabbCast2d {
         aabb.max -= self.aabb.min;
         self.ray.aabb_intersection_at(&aabb)
     }
+
+    /// Get the distance, point of contact and normal at which the [`Aabb2d`]s collide, if at all.
+    ///
+    /// The normal is that of the face of the target `aabb` that the swept AABB first touches,
+    /// and the point is the center of the region where the two AABBs touch at the time of impact.
+    ///
+    /// If the AABBs already overlap at the origin of the ray, the distance is zero, the point is
+    /// the center of their overlap, and the normal is that of the face through which
+    /// the swept AABB would leave the target, like for [`RayCast2d::aabb_intersection`].
+    pub fn aabb_collision(&self, aabb: &Aabb2d) -> Option<SweepHit2d> {
+        let offset = Aabb2d {
+            min: aabb.min - self.aabb.max,
+            max: aabb.max - self.aabb.min,
+        };
+        let hit = self.ray.aabb_intersection(&offset)?;
+
+        let translation = self.ray.ray.get_point(hit.distance);
+        let min = aabb.min.max(self.aabb.min + translation);
+        let max = aabb.max.min(self.aabb.max + translation);
+        Some(SweepHit2d {
+            toi: hit.distance,
+            point: (min + max) / 2.,
+            normal: hit.normal,
+        })
+    }
+
+    /// Get the distances at which the [`Aabb2d`]s start and stop overlapping, if at all.
+    ///
+    /// The distances are sorted and clamped to the range of the ray, so an [`Aabb2d`] that already overlaps
+    /// the target at the origin of the ray enters it at `0.0`, and one that is still overlapping it at
+    /// the max distance exits it at the max distance. If the entry is `0.0` and the exit is the max distance,
+    /// the AABBs overlap during the whole sweep.
+    pub fn aabb_interval(&self, aabb: &Aabb2d) -> Option<(f32, f32)> {
+        let offset = Aabb2d {
+            min: aabb.min - self.aabb.max,
+            max: aabb.max - self.aabb.min,
+        };
+        self.ray.aabb_intersections(&offset)
+    }
+
+    /// Get the distance at which the [`Aabb2d`] collides with the given [`BoundingCircle`], if at all.
+    ///
+    /// If the [`Aabb2d`] already overlaps the circle at the origin of the ray, the distance is zero,
+    /// or the distance at which they stop overlapping if the ray is not [`solid`](RayCast2d::solid).
+    pub fn circle_collision_at(&self, circle: &BoundingCircle) -> Option<f32> {
+        let offset = Aabb2d {
+            min: circle.center - self.aabb.max,
+            max: circle.center - self.aabb.min,
+        };
+        self.ray
+            .rounded_aabb_intersection_at(&offset, circle.radius())
+    }
 }
 
 impl IntersectsVolume<Aabb2d> for AabbCast2d {
@@ -145,9 +948,20 @@ impl IntersectsVolume<Aabb2d> for AabbCast2d {
     }
 }
 
+impl IntersectsVolume<BoundingCircle> for AabbCast2d {
+    fn intersects(&self, volume: &BoundingCircle) -> bool {
+        self.circle_collision_at(volume).is_some()
+    }
+}
+
 /// An intersection test that casts a [`BoundingCircle`] along a ray.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, Clone))]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct BoundingCircleCast {
     /// The ray along which to cast the bounding volume
     pub ray: RayCast2d,
@@ -175,6 +989,19 @@ impl BoundingCircleCast {
         circle.circle.radius += self.circle.radius();
         self.ray.circle_intersection_at(&circle)
     }
+
+    /// Get the distance at which the [`BoundingCircle`] collides with the given [`Aabb2d`], if at all.
+    ///
+    /// If the [`BoundingCircle`] already overlaps the AABB at the origin of the ray, the distance is zero,
+    /// or the distance at which they stop overlapping if the ray is not [`solid`](RayCast2d::solid).
+    pub fn aabb_collision_at(&self, aabb: &Aabb2d) -> Option<f32> {
+        let offset = Aabb2d {
+            min: aabb.min - self.circle.center,
+            max: aabb.max - self.circle.center,
+        };
+        self.ray
+            .rounded_aabb_intersection_at(&offset, self.circle.radius())
+    }
 }
 
 impl IntersectsVolume<BoundingCircle> for BoundingCircleCast {
@@ -183,9 +1010,109 @@ impl IntersectsVolume<BoundingCircle> for BoundingCircleCast {
     }
 }
 
+impl IntersectsVolume<Aabb2d> for BoundingCircleCast {
+    fn intersects(&self, volume: &Aabb2d) -> bool {
+        self.aabb_collision_at(volume).is_some()
+    }
+}
+
+/// A [`BoundingCapsule2d`] swept along a [`RayCast2d`], like a moving character controller
+///
+/// Like for the other casts, the capsule is translated by the point of the ray at each distance,
+/// so distances are along the ray.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, Clone))]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Capsule2dCast {
+    /// The ray along which to cast the bounding volume
+    pub ray: RayCast2d,
+    /// The capsule that is being cast
+    pub capsule: BoundingCapsule2d,
+}
+
+impl Capsule2dCast {
+    /// Construct a [`Capsule2dCast`] from a [`BoundingCapsule2d`], origin, [`Dir2`], and max distance.
+    pub fn new(capsule: BoundingCapsule2d, origin: Vec2, direction: Dir2, max: f32) -> Self {
+        Self::from_ray(capsule, Ray2d { origin, direction }, max)
+    }
+
+    /// Construct a [`Capsule2dCast`] from a [`BoundingCapsule2d`], [`Ray2d`], and max distance.
+    pub fn from_ray(capsule: BoundingCapsule2d, ray: Ray2d, max: f32) -> Self {
+        Self {
+            ray: RayCast2d::from_ray(ray, max),
+            capsule,
+        }
+    }
+
+    /// Get the distance at which the [`BoundingCapsule2d`] collides with the given [`BoundingCircle`], if at all.
+    pub fn circle_collision_at(&self, circle: &BoundingCircle) -> Option<f32> {
+        let [start, end] = self.capsule.segment.vertices;
+        let offset = BoundingCapsule2d::new(
+            circle.center - end,
+            circle.center - start,
+            self.capsule.radius + circle.radius(),
+        );
+        self.ray.capsule_intersection_at(&offset)
+    }
+
+    /// Get the distance at which the [`BoundingCapsule2d`] collides with the given [`Aabb2d`], if at all.
+    ///
+    /// If the capsule already overlaps the AABB at the origin of the ray, the distance is zero.
+    pub fn aabb_collision_at(&self, aabb: &Aabb2d) -> Option<f32> {
+        let [start, end] = self.capsule.segment.vertices;
+        let radius = self.capsule.radius;
+        let origin = self.ray.ray.origin;
+        if self.ray.max >= 0.
+            && BoundingCapsule2d::new(start + origin, end + origin, radius).intersects(aabb)
+        {
+            return Some(0.);
+        }
+
+        // The ray hits the box swept backwards along the segment, and rounded by the radius.
+        // Its boundary is made of the rounded boxes at both ends of the sweep,
+        // and of capsules connecting their corners, so the first hit is on one of them.
+        // The ray starts outside all of them, so it is solid to report entries.
+        let ray = self.ray.clone().with_solid(true);
+        let ends = [start, end].map(|point| {
+            let offset = Aabb2d {
+                min: aabb.min - point,
+                max: aabb.max - point,
+            };
+            ray.rounded_aabb_intersection_at(&offset, radius)
+        });
+        let sides = aabb.corners().map(|corner| {
+            ray.capsule_intersection_at(&BoundingCapsule2d::new(
+                corner - end,
+                corner - start,
+                radius,
+            ))
+        });
+        ends.into_iter().chain(sides).flatten().reduce(f32::min)
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for Capsule2dCast {
+    fn intersects(&self, volume: &BoundingCircle) -> bool {
+        self.circle_collision_at(volume).is_some()
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for Capsule2dCast {
+    fn intersects(&self, volume: &Aabb2d) -> bool {
+        self.aabb_collision_at(volume).is_some()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{bounding::BoundingVolume, Rot2};
+    use approx::assert_relative_eq;
+    use core::f32::consts::SQRT_2;
 
     const EPSILON: f32 = 0.001;
 
@@ -273,6 +1200,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ray_intersection_circle_max() {
+        let circle = BoundingCircle::new(Vec2::new(5., 0.), 2.);
+        for (max, expected) in [(2.9, None), (3., Some(3.)), (90., Some(3.))] {
+            let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, max);
+            assert_eq!(ray.circle_intersection_at(&circle), expected, "Max: {max}");
+            assert_eq!(
+                ray.circle_intersection(&circle).map(|hit| hit.distance),
+                expected,
+                "Max: {max}"
+            );
+        }
+
+        // Rays that don't treat volumes as solid hit the exit, which is beyond the max distance
+        let ray = RayCast2d::new(Vec2::new(4., 0.), Dir2::X, 2.).with_solid(false);
+        assert_eq!(ray.circle_intersection_at(&circle), None);
+        let ray = RayCast2d::new(Vec2::new(4., 0.), Dir2::X, 3.).with_solid(false);
+        assert_eq!(ray.circle_intersection_at(&circle), Some(3.));
+    }
+
     #[test]
     fn test_ray_intersection_circle_inside() {
         let volume = BoundingCircle::new(Vec2::splat(0.5), 1.);
@@ -382,15 +1329,127 @@ mod tests {
     }
 
     #[test]
-    fn test_ray_intersection_aabb_inside() {
-        let volume = Aabb2d::new(Vec2::splat(0.5), Vec2::ONE);
-        for origin in &[Vec2::X, Vec2::Y, Vec2::ONE, Vec2::ZERO] {
-            for direction in &[Dir2::X, Dir2::Y, -Dir2::X, -Dir2::Y] {
-                for max in &[0., 1., 900.] {
-                    let test = RayCast2d::new(*origin, *direction, *max);
-
-                    assert!(
-                        test.intersects(&volume),
+    fn test_ray_intersection_aabb_axis_aligned() {
+        // Rays parallel to an axis have an infinite inverse direction along the other axis,
+        // which must not turn into NaN distances, even for negative zero components
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        for (test, ray, expected) in &[
+            (
+                "through the middle",
+                RayCast2d::new(Vec2::new(0., -3.), Dir2::Y, 90.),
+                Some(2.),
+            ),
+            (
+                "with a negative zero component",
+                RayCast2d::new(Vec2::new(0.5, -3.), Dir2::from_xy(-0., 1.).unwrap(), 90.),
+                Some(2.),
+            ),
+            (
+                "along an edge",
+                RayCast2d::new(Vec2::new(1., -3.), Dir2::Y, 90.),
+                Some(2.),
+            ),
+            (
+                "along an edge with a negative zero component",
+                RayCast2d::new(Vec2::new(-1., 3.), Dir2::from_xy(-0., -1.).unwrap(), 90.),
+                Some(2.),
+            ),
+            (
+                "beside the AABB",
+                RayCast2d::new(Vec2::new(1.5, -3.), Dir2::Y, 90.),
+                None,
+            ),
+            (
+                "beside the AABB with a negative zero component",
+                RayCast2d::new(Vec2::new(-3., -1.5), Dir2::from_xy(1., -0.).unwrap(), 90.),
+                None,
+            ),
+        ] {
+            let distance = ray.aabb_intersection_at(&aabb);
+            assert_eq!(
+                distance, *expected,
+                "Case:\n  Test: {test:?}\n  Distance: {distance:?}\n  Expected: {expected:?}",
+            );
+            assert_eq!(
+                ray.aabb_intersection(&aabb).map(|hit| hit.distance),
+                distance
+            );
+        }
+    }
+
+    #[test]
+    fn test_ray_cached_direction() {
+        for (direction, positive) in [
+            (Dir2::from_xy(1., 1.).unwrap(), BVec2::new(true, true)),
+            (Dir2::from_xy(-1., 2.).unwrap(), BVec2::new(false, true)),
+            (Dir2::from_xy(-0., -1.).unwrap(), BVec2::new(false, false)),
+            (Dir2::X, BVec2::new(true, true)),
+        ] {
+            let ray = RayCast2d::new(Vec2::ZERO, direction, 90.);
+            assert_eq!(
+                ray.direction_positive(),
+                positive,
+                "Case:\n  Direction: {direction:?}",
+            );
+            assert_eq!(ray.direction_recip(), direction.recip());
+        }
+    }
+
+    #[test]
+    fn test_ray_intersection_obb() {
+        // A 4x2 box rotated by 45 degrees, crossing the X axis at `5 ± SQRT_2`
+        let obb = Obb2d::new(Vec2::new(5., 0.), Vec2::new(2., 1.), Rot2::FRAC_PI_4);
+        for (test, ray, expected) in &[
+            (
+                "towards the center",
+                RayCast2d::new(Vec2::ZERO, Dir2::X, 90.),
+                Some(5. - SQRT_2),
+            ),
+            (
+                "from the other side",
+                RayCast2d::new(Vec2::new(10., 0.), -Dir2::X, 90.),
+                Some(5. - SQRT_2),
+            ),
+            (
+                "inside the box",
+                RayCast2d::new(Vec2::new(5., 0.), Dir2::Y, 90.),
+                Some(0.),
+            ),
+            (
+                "beside the box, but inside its AABB",
+                RayCast2d::new(Vec2::new(3., 0.), Dir2::Y, 90.),
+                None,
+            ),
+            ("beyond max", RayCast2d::new(Vec2::ZERO, Dir2::X, 2.), None),
+        ] {
+            let distance = ray.obb_intersection_at(&obb);
+            assert!(
+                match (distance, expected) {
+                    (Some(distance), Some(expected)) => ops::abs(distance - expected) < EPSILON,
+                    (None, None) => true,
+                    _ => false,
+                },
+                "Case:\n  Test: {test:?}\n  Distance: {distance:?}\n  Expected: {expected:?}",
+            );
+            assert_eq!(ray.intersects(&obb), distance.is_some());
+        }
+
+        // A ray starting inside the box exits it when not solid
+        let ray = RayCast2d::new(Vec2::new(5., 0.), Dir2::Y, 90.).with_solid(false);
+        let distance = ray.obb_intersection_at(&obb).unwrap();
+        assert!(ops::abs(distance - SQRT_2) < EPSILON);
+    }
+
+    #[test]
+    fn test_ray_intersection_aabb_inside() {
+        let volume = Aabb2d::new(Vec2::splat(0.5), Vec2::ONE);
+        for origin in &[Vec2::X, Vec2::Y, Vec2::ONE, Vec2::ZERO] {
+            for direction in &[Dir2::X, Dir2::Y, -Dir2::X, -Dir2::Y] {
+                for max in &[0., 1., 900.] {
+                    let test = RayCast2d::new(*origin, *direction, *max);
+
+                    assert!(
+                        test.intersects(&volume),
                         "Case:\n  origin: {origin:?}\n  Direction: {direction:?}\n  Max: {max}",
                     );
 
@@ -405,6 +1464,474 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ray_intersection_annulus() {
+        let center = Vec2::new(5., 0.);
+        let annulus = Annulus::new(1., 2.);
+
+        for (test, ray, expected) in &[
+            (
+                "through the hole",
+                RayCast2d::new(Vec2::ZERO, Dir2::X, 90.),
+                &[3., 4., 6., 7.][..],
+            ),
+            (
+                "beside the hole",
+                RayCast2d::new(Vec2::new(0., 1.5), Dir2::X, 90.),
+                &[5. - ops::sqrt(1.75), 5. + ops::sqrt(1.75)][..],
+            ),
+            (
+                "tangent to the hole",
+                RayCast2d::new(Vec2::new(0., -1.), Dir2::X, 90.),
+                &[5. - ops::sqrt(3.), 5., 5., 5. + ops::sqrt(3.)][..],
+            ),
+            (
+                "tangent to the outer circle",
+                RayCast2d::new(Vec2::new(0., 2.), Dir2::X, 90.),
+                &[5., 5.][..],
+            ),
+            (
+                "starting in the ring",
+                RayCast2d::new(Vec2::new(3.5, 0.), Dir2::X, 90.),
+                &[0.5, 2.5, 3.5][..],
+            ),
+            (
+                "starting in the hole",
+                RayCast2d::new(center, -Dir2::X, 90.),
+                &[1., 2.][..],
+            ),
+            (
+                "ending in the hole",
+                RayCast2d::new(Vec2::ZERO, Dir2::X, 5.),
+                &[3., 4.][..],
+            ),
+            (
+                "beside the annulus",
+                RayCast2d::new(Vec2::new(0., 2.1), Dir2::X, 90.),
+                &[][..],
+            ),
+            (
+                "pointing away",
+                RayCast2d::new(Vec2::new(8., 0.), Dir2::X, 90.),
+                &[][..],
+            ),
+        ] {
+            let distances = ray.annulus_intersections(center, &annulus);
+            assert_eq!(
+                distances.len(),
+                expected.len(),
+                "Case:\n  Test: {test:?}\n  Distances: {distances:?}\n  Expected: {expected:?}",
+            );
+            for (distance, expected) in distances.iter().zip(expected.iter()) {
+                assert_relative_eq!(*distance, *expected, epsilon = EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ray_intersection_circle_entry_exit() {
+        let circle = BoundingCircle::new(Vec2::new(5., 0.), 2.);
+
+        let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, 90.);
+        assert_eq!(ray.circle_intersections(&circle), Some((3., 7.)));
+
+        // Starting inside, and ending inside
+        let ray = RayCast2d::new(Vec2::new(4., 0.), Dir2::X, 90.);
+        assert_eq!(ray.circle_intersections(&circle), Some((0., 3.)));
+        let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, 4.);
+        assert_eq!(ray.circle_intersections(&circle), Some((3., 4.)));
+
+        // A tangent ray hits the circle at a single point
+        let ray = RayCast2d::new(Vec2::new(0., 2.), Dir2::X, 90.);
+        assert_eq!(ray.circle_intersections(&circle), Some((5., 5.)));
+
+        // Misses, by being beside the circle, pointing away from it, or being too short
+        for ray in [
+            RayCast2d::new(Vec2::new(0., 2.1), Dir2::X, 90.),
+            RayCast2d::new(Vec2::new(8., 0.), Dir2::X, 90.),
+            RayCast2d::new(Vec2::ZERO, Dir2::X, 2.9),
+        ] {
+            assert_eq!(ray.circle_intersections(&circle), None, "Ray: {ray:?}");
+        }
+    }
+
+    #[test]
+    fn test_ray_intersection_plane() {
+        let plane = Plane2d::new(Vec2::new(-1., 1.));
+        let origin = Vec2::new(2., 0.);
+
+        for (test, ray, expected) in &[
+            (
+                "towards the front",
+                RayCast2d::new(Vec2::ZERO, Dir2::X, 90.),
+                Some(2.),
+            ),
+            (
+                "towards the back",
+                RayCast2d::new(Vec2::new(2., -2.), Dir2::Y, 90.),
+                Some(2.),
+            ),
+            (
+                "starting on the plane",
+                RayCast2d::new(origin, Dir2::X, 90.),
+                Some(0.),
+            ),
+            (
+                "pointing away",
+                RayCast2d::new(Vec2::ZERO, -Dir2::X, 90.),
+                None,
+            ),
+            ("beyond max", RayCast2d::new(Vec2::ZERO, Dir2::X, 1.5), None),
+            (
+                "parallel",
+                RayCast2d::new(Vec2::ZERO, Dir2::from_xy(1., 1.).unwrap(), 90.),
+                None,
+            ),
+            (
+                "in the plane",
+                RayCast2d::new(origin, Dir2::from_xy(1., 1.).unwrap(), 90.),
+                None,
+            ),
+        ] {
+            let distance = ray.plane_intersection_at(origin, &plane);
+            assert!(
+                match (distance, expected) {
+                    (Some(distance), Some(expected)) => ops::abs(distance - expected) < EPSILON,
+                    (None, None) => true,
+                    _ => false,
+                },
+                "Case:\n  Test: {test:?}\n  Distance: {distance:?}\n  Expected: {expected:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_ray_intersection_segment() {
+        // The left normal of this segment points in the negative X direction
+        let segment = Segment2d::new(Vec2::new(2., -1.), Vec2::new(2., 1.));
+
+        let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, 90.);
+        assert_eq!(ray.segment_intersection_at(&segment, false), Some(2.));
+        assert_eq!(ray.segment_intersection_at(&segment, true), Some(2.));
+
+        // Hitting the back of the segment is only allowed without culling
+        let ray = RayCast2d::new(Vec2::new(4., 0.5), -Dir2::X, 90.);
+        assert_eq!(ray.segment_intersection_at(&segment, false), Some(2.));
+        assert_eq!(ray.segment_intersection_at(&segment, true), None);
+
+        // Hit an endpoint diagonally
+        let ray = RayCast2d::new(Vec2::new(1., 0.), Dir2::from_xy(1., 1.).unwrap(), 90.);
+        let distance = ray.segment_intersection_at(&segment, true).unwrap();
+        assert!(ops::abs(distance - ops::sqrt(2.)) < EPSILON);
+
+        for ray in [
+            // Pass beside the segment
+            RayCast2d::new(Vec2::new(0., 1.5), Dir2::X, 90.),
+            // The segment is beyond the max distance
+            RayCast2d::new(Vec2::ZERO, Dir2::X, 1.5),
+            // The segment is behind the ray
+            RayCast2d::new(Vec2::new(3., 0.), Dir2::X, 90.),
+            // Parallel and collinear rays
+            RayCast2d::new(Vec2::ZERO, Dir2::Y, 90.),
+            RayCast2d::new(Vec2::new(2., -3.), Dir2::Y, 90.),
+        ] {
+            assert_eq!(
+                ray.segment_intersection_at(&segment, false),
+                None,
+                "Ray: {ray:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ray_intersection_polyline() {
+        // A zigzag along the X axis
+        let points = [
+            Vec2::new(0., 2.),
+            Vec2::new(2., -2.),
+            Vec2::new(4., 2.),
+            Vec2::new(6., -2.),
+        ];
+
+        for (test, ray, expected) in &[
+            (
+                "hitting the second segment",
+                RayCast2d::new(Vec2::new(3., -5.), Dir2::Y, 90.),
+                Some((1, 5.)),
+            ),
+            (
+                "hitting the nearest of several segments",
+                RayCast2d::new(Vec2::new(8., 0.), -Dir2::X, 90.),
+                Some((2, 3.)),
+            ),
+            (
+                "hitting a shared vertex",
+                RayCast2d::new(Vec2::new(2., -5.), Dir2::Y, 90.),
+                Some((0, 3.)),
+            ),
+            (
+                "passing below the polyline",
+                RayCast2d::new(Vec2::new(-1., -3.), Dir2::X, 90.),
+                None,
+            ),
+            (
+                "stopping short of the polyline",
+                RayCast2d::new(Vec2::new(3., -5.), Dir2::Y, 2.),
+                None,
+            ),
+        ] {
+            let hit = ray.polyline_intersection_at(&points);
+            assert_eq!(
+                hit, *expected,
+                "Case:\n  Test: {test:?}\n  Hit: {hit:?}\n  Expected: {expected:?}",
+            );
+        }
+
+        // A polyline needs at least two points to have segments
+        let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, 90.);
+        assert_eq!(ray.polyline_intersection_at(&[]), None);
+        assert_eq!(ray.polyline_intersection_at(&[Vec2::X]), None);
+    }
+
+    #[test]
+    fn test_ray_intersection_hollow() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        let circle = BoundingCircle::new(Vec2::ZERO, 1.);
+
+        // Starting inside reports the exit surface
+        let test = RayCast2d::new(Vec2::new(0.5, 0.), Dir2::X, 90.).with_solid(false);
+        assert_eq!(test.aabb_intersection_at(&aabb), Some(0.5));
+        assert_eq!(test.circle_intersection_at(&circle), Some(0.5));
+        let hit = test.aabb_intersection(&aabb).unwrap();
+        assert_eq!((hit.distance, hit.normal), (0.5, Dir2::X));
+        let hit = test.circle_intersection(&circle).unwrap();
+        assert_eq!((hit.distance, hit.normal), (0.5, Dir2::X));
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut out = Vec::new();
+            test.intersect_many(&[aabb], &mut out);
+            assert_eq!(out, [Some(0.5)]);
+        }
+
+        // The exit surface is beyond the max distance
+        let test = RayCast2d::new(Vec2::new(0.5, 0.), -Dir2::X, 1.).with_solid(false);
+        assert_eq!(test.aabb_intersection_at(&aabb), None);
+        assert_eq!(test.circle_intersection_at(&circle), None);
+        assert_eq!(test.aabb_intersection(&aabb), None);
+        assert_eq!(test.circle_intersection(&circle), None);
+
+        // Starting outside is unaffected
+        let test = RayCast2d::new(Vec2::new(-5., 0.), Dir2::X, 90.).with_solid(false);
+        assert_eq!(test.aabb_intersection_at(&aabb), Some(4.));
+        assert_eq!(test.circle_intersection_at(&circle), Some(4.));
+    }
+
+    #[test]
+    fn test_ray_intersection_aabb_normals() {
+        let volume = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        for (test, expected_distance, expected_normal) in &[
+            (RayCast2d::new(Vec2::Y * -5., Dir2::Y, 90.), 4., Dir2::NEG_Y),
+            (RayCast2d::new(Vec2::Y * 5., -Dir2::Y, 90.), 4., Dir2::Y),
+            (RayCast2d::new(Vec2::X * -5., Dir2::X, 90.), 4., Dir2::NEG_X),
+            (RayCast2d::new(Vec2::X * 5., -Dir2::X, 90.), 4., Dir2::X),
+            (
+                // Enter through the left face while moving diagonally
+                RayCast2d::new(Vec2::new(-3., -1.5), Dir2::from_xy(1., 0.5).unwrap(), 90.),
+                2.236,
+                Dir2::NEG_X,
+            ),
+            (
+                // Start inside, reporting the exit face
+                RayCast2d::new(Vec2::new(0.5, 0.), Dir2::from_xy(-1., 1.).unwrap(), 90.),
+                0.,
+                Dir2::Y,
+            ),
+            (RayCast2d::new(Vec2::ZERO, Dir2::X, 0.), 0., Dir2::X),
+        ] {
+            let hit = test.aabb_intersection(&volume).unwrap();
+            assert!(
+                ops::abs(hit.distance - expected_distance) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Expected distance: {expected_distance:?}\n  Actual: {hit:?}",
+            );
+            assert_eq!(
+                hit.normal, *expected_normal,
+                "Case:\n  Test: {test:?}\n  Expected normal: {expected_normal:?}\n  Actual: {hit:?}",
+            );
+            assert_eq!(Some(hit.distance), test.aabb_intersection_at(&volume));
+        }
+
+        let miss = RayCast2d::new(Vec2::Y * -5., Dir2::Y, 3.);
+        assert_eq!(miss.aabb_intersection(&volume), None);
+    }
+
+    #[test]
+    fn test_ray_intersection_circle_normals() {
+        let volume = BoundingCircle::new(Vec2::ONE, 1.);
+        for (test, expected_distance, expected_normal) in &[
+            (
+                RayCast2d::new(Vec2::new(1., -5.), Dir2::Y, 90.),
+                5.,
+                Vec2::NEG_Y,
+            ),
+            (
+                RayCast2d::new(Vec2::new(5., 1.), -Dir2::X, 90.),
+                3.,
+                Vec2::X,
+            ),
+            (
+                // Hit off-center
+                RayCast2d::new(Vec2::new(1.5, -5.), Dir2::Y, 90.),
+                5.134,
+                Vec2::new(0.5, -0.866),
+            ),
+            (
+                // Start inside, reporting the exit point
+                RayCast2d::new(Vec2::ONE, Dir2::X, 90.),
+                0.,
+                Vec2::X,
+            ),
+        ] {
+            let hit = test.circle_intersection(&volume).unwrap();
+            assert!(
+                ops::abs(hit.distance - expected_distance) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Expected distance: {expected_distance:?}\n  Actual: {hit:?}",
+            );
+            assert!(
+                hit.normal.distance(*expected_normal) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Expected normal: {expected_normal:?}\n  Actual: {hit:?}",
+            );
+            assert_eq!(Some(hit.distance), test.circle_intersection_at(&volume));
+        }
+
+        let miss = RayCast2d::new(Vec2::new(5., -5.), Dir2::Y, 90.);
+        assert_eq!(miss.circle_intersection(&volume), None);
+    }
+
+    #[test]
+    fn test_ray_cast_all() {
+        let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, 20.);
+        let volumes = [
+            CurrentVolume::Circle(BoundingCircle::new(Vec2::new(10., 0.), 1.)),
+            CurrentVolume::Aabb(Aabb2d::new(Vec2::new(5., 5.), Vec2::ONE)),
+            CurrentVolume::Aabb(Aabb2d::new(Vec2::new(5., 0.), Vec2::ONE)),
+            CurrentVolume::Circle(BoundingCircle::new(Vec2::new(30., 0.), 1.)),
+            CurrentVolume::Circle(BoundingCircle::new(Vec2::new(6., 0.), 2.)),
+        ];
+        let intersection_at = |ray: &RayCast2d, volume: &CurrentVolume| match volume {
+            CurrentVolume::Aabb(aabb) => ray.aabb_intersection_at(aabb),
+            CurrentVolume::Circle(circle) => ray.circle_intersection_at(circle),
+        };
+
+        // Hits at the same distance keep their order
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            ray.cast_all(&volumes, intersection_at),
+            [(2, 4.), (4, 4.), (0, 9.)]
+        );
+        assert_eq!(ray.cast_nearest(&volumes, intersection_at), Some((2, 4.)));
+
+        let misses = &volumes[1..2];
+        #[cfg(feature = "alloc")]
+        assert!(ray.cast_all(misses, intersection_at).is_empty());
+        assert_eq!(ray.cast_nearest(misses, intersection_at), None);
+    }
+
+    #[test]
+    fn ray_cast_first_with_filter() {
+        let ray = RayCast2d::new(Vec2::ZERO, Dir2::X, 20.);
+        // Each item has the mask of the layers it is on
+        let items = [
+            (Aabb2d::new(Vec2::new(10., 0.), Vec2::ONE), 0b001_u32),
+            (Aabb2d::new(Vec2::new(5., 0.), Vec2::ONE), 0b010),
+            (Aabb2d::new(Vec2::new(5., 5.), Vec2::ONE), 0b100),
+            (Aabb2d::new(Vec2::new(5., 0.), Vec2::splat(0.5)), 0b100),
+            (Aabb2d::new(Vec2::new(5., 0.), Vec2::ONE), 0b110),
+            (Aabb2d::new(Vec2::new(30., 0.), Vec2::ONE), 0b001),
+        ];
+
+        for (test, mask, expected) in &[
+            ("the first of equal hits", 0b111, Some((0b010, 4.))),
+            ("the nearest, not the first", 0b100, Some((0b110, 4.))),
+            ("a farther volume", 0b001, Some((0b001, 9.))),
+            ("no layer", 0b000, None),
+        ] {
+            let hit = ray_cast_first(&ray, items.into_iter(), |layers| layers & mask != 0);
+            assert_eq!(hit, *expected, "Case:\n  Test: {test:?}");
+        }
+
+        // Every item is filtered, even the ones missed by the ray
+        let checked = core::cell::Cell::new(0);
+        let hit = ray_cast_first(&ray, items.into_iter(), |_| {
+            checked.set(checked.get() + 1);
+            false
+        });
+        assert_eq!(hit, None);
+        assert_eq!(checked.get(), items.len());
+    }
+
+    #[derive(Debug)]
+    enum CurrentVolume {
+        Aabb(Aabb2d),
+        Circle(BoundingCircle),
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_ray_intersect_many() {
+        let aabbs = [
+            Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+            Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+            Aabb2d::new(Vec2::new(-3., 2.), Vec2::splat(0.5)),
+            Aabb2d::new(Vec2::new(4., -4.), Vec2::new(2., 0.25)),
+            Aabb2d::new(Vec2::X * 2., Vec2::ZERO),
+        ];
+        let rays = [
+            RayCast2d::new(Vec2::Y * -5., Dir2::Y, 90.),
+            RayCast2d::new(Vec2::Y * 5., -Dir2::Y, 90.),
+            RayCast2d::new(Vec2::new(-6., -1.), Dir2::from_xy(1., 0.5).unwrap(), 90.),
+            RayCast2d::new(Vec2::X * 10., -Dir2::X, 8.),
+            // Axis-aligned direction with a negative zero component
+            RayCast2d::new(Vec2::new(4., 2.), Dir2::from_xy(-0., -1.).unwrap(), 90.),
+            RayCast2d::new(Vec2::ZERO, Dir2::X, 0.),
+        ];
+
+        let mut out = Vec::new();
+        for ray in &rays {
+            ray.intersect_many(&aabbs, &mut out);
+            let expected: Vec<_> = aabbs
+                .iter()
+                .map(|aabb| ray.aabb_intersection_at(aabb))
+                .collect();
+            assert_eq!(out, expected, "Case:\n  Test: {ray:?}");
+        }
+    }
+
+    #[test]
+    fn test_double_ray_intersection() {
+        // Far enough from the origin that an `f32` can't represent the offsets
+        let origin = DVec2::new(1.0e9, 0.0);
+        let ray = DRayCast2d::new(origin, Dir2::X, 10.);
+
+        let aabb = DAabb2d::new(origin + DVec2::new(2.25, 0.5), DVec2::splat(1.));
+        assert_eq!(ray.aabb_intersection_at(&aabb), Some(1.25));
+        let circle = DBoundingCircle::new(origin + DVec2::X * 3.125, 1.);
+        assert_eq!(ray.circle_intersection_at(&circle), Some(2.125));
+
+        // Misses, by being behind the ray, beyond the max distance, or beside the ray
+        assert!(!ray.intersects(&DAabb2d::new(origin - DVec2::X * 2., DVec2::splat(1.))));
+        assert!(!ray.intersects(&DBoundingCircle::new(origin + DVec2::X * 12., 1.)));
+        assert!(!ray.intersects(&DBoundingCircle::new(origin + DVec2::Y * 1.125, 1.)));
+
+        // Starting inside
+        let circle = DBoundingCircle::new(origin, 1.);
+        assert_eq!(ray.circle_intersection_at(&circle), Some(0.));
+        let hollow = ray.with_solid(false);
+        assert_eq!(hollow.circle_intersection_at(&circle), Some(1.));
+        let aabb = DAabb2d::new(origin, DVec2::splat(0.5));
+        assert_eq!(hollow.aabb_intersection_at(&aabb), Some(0.5));
+    }
+
     #[test]
     fn test_aabb_cast_hits() {
         for (test, volume, expected_distance) in &[
@@ -467,6 +1994,237 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_aabb_cast_contacts() {
+        let cast_aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        for (test, cast, volume, expected) in &[
+            (
+                "hitting the bottom face",
+                AabbCast2d::new(cast_aabb, Vec2::ZERO, Dir2::Y, 90.),
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+                SweepHit2d {
+                    toi: 3.,
+                    point: Vec2::Y * 4.,
+                    normal: Dir2::NEG_Y,
+                },
+            ),
+            (
+                "hitting the left face off-center",
+                AabbCast2d::new(cast_aabb, Vec2::new(-10., 1.5), Dir2::X, 90.),
+                Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                SweepHit2d {
+                    toi: 8.,
+                    point: Vec2::new(-1., 0.75),
+                    normal: Dir2::NEG_X,
+                },
+            ),
+            (
+                "hitting the top face diagonally",
+                AabbCast2d::new(
+                    cast_aabb,
+                    Vec2::new(-4., 6.),
+                    Dir2::new(Vec2::new(1., -1.)).unwrap(),
+                    90.,
+                ),
+                // The box is above the left face when it reaches it
+                Aabb2d::new(Vec2::ZERO, Vec2::new(3., 1.)),
+                SweepHit2d {
+                    toi: 4. * SQRT_2,
+                    point: Vec2::Y,
+                    normal: Dir2::Y,
+                },
+            ),
+        ] {
+            let hit = cast.aabb_collision(volume).unwrap();
+            assert!(
+                ops::abs(hit.toi - expected.toi) < EPSILON
+                    && hit.point.distance(expected.point) < EPSILON
+                    && hit.normal == expected.normal,
+                "Case:\n  Test: {test:?}\n  Hit: {hit:?}\n  Expected: {expected:?}",
+            );
+            assert_eq!(Some(hit.toi), cast.aabb_collision_at(*volume));
+        }
+
+        // A miss
+        let cast = AabbCast2d::new(cast_aabb, Vec2::X * 3., Dir2::Y, 90.);
+        assert_eq!(
+            cast.aabb_collision(&Aabb2d::new(Vec2::Y * 5., Vec2::ONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_aabb_cast_interval() {
+        let cast_aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        let volume = Aabb2d::new(Vec2::Y * 5., Vec2::ONE);
+        for (test, cast, expected) in &[
+            (
+                "passing through",
+                AabbCast2d::new(cast_aabb, Vec2::ZERO, Dir2::Y, 90.),
+                Some((3., 7.)),
+            ),
+            (
+                "stopping inside",
+                AabbCast2d::new(cast_aabb, Vec2::ZERO, Dir2::Y, 5.),
+                Some((3., 5.)),
+            ),
+            (
+                "starting inside",
+                AabbCast2d::new(cast_aabb, Vec2::Y * 4., Dir2::Y, 90.),
+                Some((0., 3.)),
+            ),
+            (
+                "inside during the whole sweep",
+                AabbCast2d::new(cast_aabb, Vec2::new(0.5, 4.), Dir2::Y, 1.),
+                Some((0., 1.)),
+            ),
+            (
+                "passing beside",
+                AabbCast2d::new(cast_aabb, Vec2::X * 3., Dir2::Y, 90.),
+                None,
+            ),
+            (
+                "moving away",
+                AabbCast2d::new(cast_aabb, Vec2::ZERO, -Dir2::Y, 90.),
+                None,
+            ),
+            (
+                "stopping short",
+                AabbCast2d::new(cast_aabb, Vec2::ZERO, Dir2::Y, 2.),
+                None,
+            ),
+        ] {
+            let interval = cast.aabb_interval(&volume);
+            assert_eq!(
+                interval, *expected,
+                "Case:\n  Test: {test:?}\n  Interval: {interval:?}\n  Expected: {expected:?}",
+            );
+            assert_eq!(
+                interval.map(|(entry, _)| entry),
+                cast.aabb_collision_at(volume)
+            );
+        }
+
+        // The ray itself hits the AABB on both sides
+        let ray = RayCast2d::new(Vec2::new(-2., 0.5), Dir2::X, 90.);
+        assert_eq!(ray.aabb_intersections(&cast_aabb), Some((1., 3.)));
+        assert_eq!(
+            ray.with_solid(false).aabb_intersections(&cast_aabb),
+            Some((1., 3.))
+        );
+    }
+
+    #[test]
+    fn test_aabb_cast_circle_hits() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the center of the circle
+                AabbCast2d::new(Aabb2d::new(Vec2::ZERO, Vec2::ONE), Vec2::ZERO, Dir2::Y, 90.),
+                BoundingCircle::new(Vec2::Y * 5., 1.),
+                3.,
+            ),
+            (
+                // Hit the circle with the corner of the aabb, that a ray would've missed
+                AabbCast2d::new(
+                    Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                    Vec2::X * 1.6,
+                    Dir2::Y,
+                    90.,
+                ),
+                BoundingCircle::new(Vec2::Y * 5., 1.),
+                3.2,
+            ),
+            (
+                // Hit the circle with the corner of an off-center aabb
+                AabbCast2d::new(
+                    Aabb2d::new(Vec2::X * -1.6, Vec2::ONE),
+                    Vec2::X * 3.2,
+                    Dir2::Y,
+                    90.,
+                ),
+                BoundingCircle::new(Vec2::Y * 5., 1.),
+                3.2,
+            ),
+            (
+                // Hit the circle with the face of a wide aabb
+                AabbCast2d::new(
+                    Aabb2d::new(Vec2::ZERO, Vec2::new(3., 1.)),
+                    Vec2::X * 2.5,
+                    Dir2::Y,
+                    90.,
+                ),
+                BoundingCircle::new(Vec2::Y * 5., 1.),
+                3.,
+            ),
+            (
+                // The aabb already overlaps the circle
+                AabbCast2d::new(
+                    Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                    Vec2::Y * 3.5,
+                    Dir2::Y,
+                    90.,
+                ),
+                BoundingCircle::new(Vec2::Y * 5., 1.),
+                0.,
+            ),
+            (
+                // The aabb already overlaps the circle without being solid, and hits it on the way out
+                AabbCast2d {
+                    ray: RayCast2d::new(Vec2::Y * 3.5, Dir2::Y, 90.).with_solid(false),
+                    aabb: Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                },
+                BoundingCircle::new(Vec2::Y * 5., 1.),
+                3.5,
+            ),
+            (
+                // Start with the corner of the aabb outside the circle, but within the expanded box,
+                // without being solid
+                AabbCast2d {
+                    ray: RayCast2d::new(-Vec2::splat(1.9), Dir2::X, 90.).with_solid(false),
+                    aabb: Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                },
+                BoundingCircle::new(Vec2::ZERO, 1.),
+                0.464,
+            ),
+        ] {
+            assert!(
+                test.intersects(volume),
+                "Case:\n  Test: {test:?}\n  Volume: {volume:?}\n  Expected distance: {expected_distance:?}",
+            );
+            let actual_distance = test.circle_collision_at(volume).unwrap();
+            assert!(
+                ops::abs(actual_distance - expected_distance) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Volume: {volume:?}\n  Expected distance: {expected_distance:?}\n  Actual distance: {actual_distance}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_aabb_cast_circle_misses() {
+        for (test, volume) in &[
+            (
+                // Pass the circle diagonally, missing the rounded corner of the swept volume
+                AabbCast2d::new(
+                    Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                    Vec2::Y * 1.3,
+                    Dir2::from_xy(1., 1.).unwrap(),
+                    90.,
+                ),
+                BoundingCircle::new(Vec2::Y * 5., 1.),
+            ),
+            (
+                // The circle is beyond the max distance
+                AabbCast2d::new(Aabb2d::new(Vec2::ZERO, Vec2::ONE), Vec2::ZERO, Dir2::Y, 2.9),
+                BoundingCircle::new(Vec2::Y * 5., 1.),
+            ),
+        ] {
+            assert!(
+                !test.intersects(volume),
+                "Case:\n  Test: {test:?}\n  Volume: {volume:?}",
+            );
+        }
+    }
+
     #[test]
     fn test_circle_cast_hits() {
         for (test, volume, expected_distance) in &[
@@ -533,4 +2291,321 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_circle_cast_aabb_hits() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the face of the box head-on
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 1.),
+                    Vec2::ZERO,
+                    Dir2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+                3.,
+            ),
+            (
+                // Hit the rounded corner of the box, that a ray would've missed
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 1.),
+                    Vec2::X * 1.5,
+                    Dir2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+                3.134,
+            ),
+            (
+                // Graze the corner of the box
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 1.),
+                    Vec2::X * 1.6,
+                    Dir2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+                3.2,
+            ),
+            (
+                // Graze the corner of the box, by casting a circle that is off-center
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::X * -1.6, 1.),
+                    Vec2::X * 3.2,
+                    Dir2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+                3.2,
+            ),
+            (
+                // Start overlapping the box
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 1.),
+                    Vec2::Y * 3.5,
+                    Dir2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+                0.,
+            ),
+            (
+                // Start overlapping the box without being solid, and hit it on the way out
+                BoundingCircleCast {
+                    ray: RayCast2d::new(Vec2::Y * 3.5, Dir2::Y, 90.).with_solid(false),
+                    circle: BoundingCircle::new(Vec2::ZERO, 1.),
+                },
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+                3.5,
+            ),
+            (
+                // Start outside the rounded corner, but inside the expanded box, without being solid
+                BoundingCircleCast {
+                    ray: RayCast2d::new(Vec2::splat(1.9), -Dir2::X, 90.).with_solid(false),
+                    circle: BoundingCircle::new(Vec2::ZERO, 1.),
+                },
+                Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                0.464,
+            ),
+        ] {
+            assert!(
+                test.intersects(volume),
+                "Case:\n  Test: {test:?}\n  Volume: {volume:?}\n  Expected distance: {expected_distance:?}",
+            );
+            let actual_distance = test.aabb_collision_at(volume).unwrap();
+            assert!(
+                ops::abs(actual_distance - expected_distance) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Volume: {volume:?}\n  Expected distance: {expected_distance:?}\n  Actual distance: {actual_distance}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_circle_cast_aabb_misses() {
+        for (test, volume) in &[
+            (
+                // Pass diagonally by the corner, within the expanded box but outside the rounded corner
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 1.),
+                    Vec2::Y * 1.3,
+                    Dir2::from_xy(1., 1.).unwrap(),
+                    90.,
+                ),
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+            ),
+            (
+                // Cast in the wrong direction
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 1.),
+                    Vec2::ZERO,
+                    -Dir2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+            ),
+            (
+                // The box is beyond the max distance
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 1.),
+                    Vec2::ZERO,
+                    Dir2::Y,
+                    2.9,
+                ),
+                Aabb2d::new(Vec2::Y * 5., Vec2::ONE),
+            ),
+        ] {
+            assert!(
+                !test.intersects(volume),
+                "Case:\n  Test: {test:?}\n  Volume: {volume:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_ray_intersection_capsule() {
+        let capsule = BoundingCapsule2d::new(Vec2::new(-2., 0.), Vec2::new(2., 0.), 1.);
+        for (test, ray, expected) in &[
+            (
+                "the flat side",
+                RayCast2d::new(Vec2::new(0., -5.), Dir2::Y, 90.),
+                Some(4.),
+            ),
+            (
+                "an end cap",
+                RayCast2d::new(Vec2::new(2.6, -5.), Dir2::Y, 90.),
+                Some(4.2),
+            ),
+            (
+                "along the segment",
+                RayCast2d::new(Vec2::new(-6., 0.), Dir2::X, 90.),
+                Some(3.),
+            ),
+            (
+                "from inside",
+                RayCast2d::new(Vec2::new(0., 0.5), Dir2::X, 90.),
+                Some(0.),
+            ),
+            (
+                "from inside, not solid",
+                RayCast2d::new(Vec2::new(0., 0.5), Dir2::X, 90.).with_solid(false),
+                Some(2. + ops::sqrt(0.75)),
+            ),
+            (
+                "above the capsule",
+                RayCast2d::new(Vec2::new(-6., 1.5), Dir2::X, 90.),
+                None,
+            ),
+            (
+                "beyond the end cap",
+                RayCast2d::new(Vec2::new(3.1, -5.), Dir2::Y, 90.),
+                None,
+            ),
+            (
+                "beyond max",
+                RayCast2d::new(Vec2::new(0., -5.), Dir2::Y, 3.9),
+                None,
+            ),
+        ] {
+            let distance = ray.capsule_intersection_at(&capsule);
+            match (distance, expected) {
+                (None, None) => {}
+                (Some(distance), Some(expected)) => assert!(
+                    ops::abs(distance - expected) < EPSILON,
+                    "Case:\n  Test: {test:?}\n  Distance: {distance}",
+                ),
+                _ => panic!(
+                    "Case:\n  Test: {test:?}\n  Distance: {distance:?}\n  Expected: {expected:?}"
+                ),
+            }
+        }
+
+        // A capsule with a degenerate segment is a circle
+        let circle = BoundingCapsule2d::new(Vec2::ONE, Vec2::ONE, 1.);
+        let ray = RayCast2d::new(Vec2::new(1., -5.), Dir2::Y, 90.);
+        assert_eq!(ray.capsule_intersection_at(&circle), Some(5.));
+    }
+
+    #[test]
+    fn test_capsule_cast_hits() {
+        // An upright capsule, like a character controller, moving to the right
+        let character = BoundingCapsule2d::new(Vec2::new(0., -1.), Vec2::new(0., 1.), 0.5);
+        let cast = Capsule2dCast::new(character, Vec2::ZERO, Dir2::X, 90.);
+
+        for (test, circle, expected) in &[
+            (
+                "the flat side",
+                BoundingCircle::new(Vec2::new(5., 0.), 1.),
+                3.5,
+            ),
+            (
+                "an end cap",
+                BoundingCircle::new(Vec2::new(5., 2.), 1.),
+                5. - ops::sqrt(1.25),
+            ),
+        ] {
+            let distance = cast.circle_collision_at(circle).unwrap();
+            assert!(
+                ops::abs(distance - expected) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Distance: {distance}",
+            );
+        }
+
+        for (test, cast, aabb, expected) in &[
+            (
+                "the flat side against a face",
+                cast.clone(),
+                Aabb2d::new(Vec2::new(5., 0.), Vec2::ONE),
+                3.5,
+            ),
+            (
+                "an end cap against a corner",
+                cast.clone(),
+                Aabb2d::new(Vec2::new(5., 2.3), Vec2::ONE),
+                3.6,
+            ),
+            (
+                "the flat side against a corner between the end caps",
+                Capsule2dCast::new(
+                    BoundingCapsule2d::new(Vec2::new(-3., 0.), Vec2::new(3., 0.), 0.5),
+                    Vec2::ZERO,
+                    Dir2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::new(0., 5.), Vec2::splat(0.5)),
+                4.,
+            ),
+            (
+                "starting inside",
+                cast.clone(),
+                Aabb2d::new(Vec2::new(0.5, 0.), Vec2::ONE),
+                0.,
+            ),
+        ] {
+            assert!(cast.intersects(aabb), "Case:\n  Test: {test:?}");
+            let distance = cast.aabb_collision_at(aabb).unwrap();
+            assert!(
+                ops::abs(distance - expected) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Distance: {distance}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_capsule_cast_misses() {
+        let character = BoundingCapsule2d::new(Vec2::new(0., -1.), Vec2::new(0., 1.), 0.5);
+        for (test, cast) in &[
+            (
+                "wrong direction",
+                Capsule2dCast::new(character, Vec2::ZERO, -Dir2::X, 90.),
+            ),
+            (
+                "beyond max",
+                Capsule2dCast::new(character, Vec2::ZERO, Dir2::X, 3.4),
+            ),
+            (
+                "passing above",
+                Capsule2dCast::new(character, Vec2::new(0., 4.6), Dir2::X, 90.),
+            ),
+        ] {
+            assert!(
+                !cast.intersects(&Aabb2d::new(Vec2::new(5., 0.), Vec2::ONE)),
+                "Case:\n  Test: {test:?}",
+            );
+            assert!(
+                !cast.intersects(&BoundingCircle::new(Vec2::new(5., 0.), 1.)),
+                "Case:\n  Test: {test:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_capsule_cast_aabb_matches_stepping() {
+        // Compare the time of impact with stepping the capsule along the ray, for a few directions and boxes
+        let capsule = BoundingCapsule2d::new(Vec2::new(-1., -0.5), Vec2::new(1., 0.5), 0.25);
+        let step = 0.01;
+        for i in 0..16 {
+            let angle = i as f32 * core::f32::consts::TAU / 16.;
+            let direction = Dir2::from_xy(ops::cos(angle), ops::sin(angle)).unwrap();
+            for center in [Vec2::new(3., 0.5), Vec2::new(-2., 3.), Vec2::new(2.5, -2.5)] {
+                let aabb = Aabb2d::new(center, Vec2::new(0.5, 0.3));
+                let cast = Capsule2dCast::new(capsule, Vec2::ZERO, direction, 10.);
+                let first_contact = (0..=1000).map(|n| n as f32 * step).find(|&distance| {
+                    let mut moved = capsule;
+                    moved.translate_by(*direction * distance);
+                    moved.intersects(&aabb)
+                });
+                match (cast.aabb_collision_at(&aabb), first_contact) {
+                    (None, None) => {}
+                    (Some(distance), Some(first_contact)) => assert!(
+                        distance <= first_contact && first_contact - distance < step + EPSILON,
+                        "Angle: {angle}, Center: {center}, Distance: {distance}, Stepped: {first_contact}",
+                    ),
+                    (distance, first_contact) => panic!(
+                        "Angle: {angle}, Center: {center}, Distance: {distance:?}, Stepped: {first_contact:?}"
+                    ),
+                }
+            }
+        }
+    }
 }
@@ -1,15 +1,79 @@
-use super::{Aabb3d, BoundingSphere, IntersectsVolume};
+use super::{Aabb3d, BoundingSphere, IntersectsVolume, Interval1d};
 use crate::{
     ops::{self, FloatPow},
-    Dir3A, Ray3d, Vec3A,
+    primitives::Triangle3d,
+    Dir3, Dir3A, Ray3d, Vec3, Vec3A,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
+#[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// The result of a successful [`RayCast3d`] against a [`Triangle3d`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct RayHit3d {
+    /// The distance along the ray at which the triangle was hit
+    pub distance: f32,
+    /// The barycentric coordinates of the hit point, as the weights of the vertices of the triangle
+    pub barycentric_coords: Vec3,
+    /// The normal of the front face of the triangle, following the right-hand rule.
+    ///
+    /// This is the same for hits on the front and back faces of the triangle.
+    pub normal: Dir3A,
+}
+
+/// The result of a successful [`RayCast3d::aabb_interval`], describing where the ray passes through an [`Aabb3d`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AabbRayHit3d {
+    /// The distance along the ray at which it enters the AABB, or `0.0` if it starts inside it
+    pub entry: f32,
+    /// The distance along the ray at which it exits the AABB, or the max distance if it ends inside it
+    pub exit: f32,
+    /// The outward normal of the face through which the ray line enters the AABB
+    pub entry_normal: Dir3A,
+    /// The outward normal of the face through which the ray line exits the AABB
+    pub exit_normal: Dir3A,
+}
 
 /// A raycast intersection test for 3D bounding volumes
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, Clone))]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Serialize),
+    derive(Deserialize),
+    serde(from = "RayCast3dFields")
+)]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct RayCast3d {
     /// The origin of the ray.
     pub origin: Vec3A,
@@ -18,9 +82,26 @@ pub struct RayCast3d {
     /// The maximum distance for the ray
     pub max: f32,
     /// The multiplicative inverse direction of the ray
+    #[cfg_attr(feature = "serialize", serde(skip_serializing))]
     direction_recip: Vec3A,
 }
 
+/// The serialized fields of a [`RayCast3d`], without the cached inverse direction.
+#[cfg(feature = "serialize")]
+#[derive(Deserialize)]
+struct RayCast3dFields {
+    origin: Vec3A,
+    direction: Dir3A,
+    max: f32,
+}
+
+#[cfg(feature = "serialize")]
+impl From<RayCast3dFields> for RayCast3d {
+    fn from(fields: RayCast3dFields) -> Self {
+        Self::new(fields.origin, fields.direction, fields.max)
+    }
+}
+
 impl RayCast3d {
     /// Construct a [`RayCast3d`] from an origin, [direction], and max distance.
     ///
@@ -61,14 +142,70 @@ impl RayCast3d {
         // to min/max is NaN, the other argument is used.
         // An axis for which the direction is the wrong way will return an arbitrarily large
         // negative value.
-        let tmin = tmin.max_element().max(0.);
-        let tmax = tmax.min_element().min(self.max);
+        let inside = Interval1d::new(tmin.max_element(), tmax.min_element()).clamp_to(0., self.max);
+        (!inside.is_empty()).then_some(inside.min)
+    }
 
-        if tmin <= tmax {
-            Some(tmin)
-        } else {
-            None
+    /// Get the distances at which the ray enters and exits an [`Aabb3d`], and the normals of the faces
+    /// it crosses there, if it hits it.
+    ///
+    /// The distances are sorted and clamped to the range of the ray, which is useful for integrating
+    /// along the part of the ray that is inside the AABB, like for a box of fog.
+    /// The normals are always those of the faces that the ray line crosses, even if the ray starts
+    /// or ends inside the AABB.
+    /// If the ray passes through an edge or a corner, the normal is that of the first face along the axes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::{Aabb3d, RayCast3d}, Dir3, Dir3A, Vec3};
+    /// let ray_cast = RayCast3d::new(Vec3::new(-5.0, 0.5, 0.0), Dir3::X, 100.0);
+    /// let fog = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+    ///
+    /// let hit = ray_cast.aabb_interval(&fog).unwrap();
+    /// assert_eq!((hit.entry, hit.exit), (4.0, 6.0));
+    /// assert_eq!((hit.entry_normal, hit.exit_normal), (Dir3A::NEG_X, Dir3A::X));
+    /// ```
+    pub fn aabb_interval(&self, aabb: &Aabb3d) -> Option<AabbRayHit3d> {
+        let positive = self.direction.signum().cmpgt(Vec3A::ZERO);
+        let min = Vec3A::select(positive, aabb.min, aabb.max);
+        let max = Vec3A::select(positive, aabb.max, aabb.min);
+        let tmin = (min - self.origin) * self.direction_recip;
+        let tmax = (max - self.origin) * self.direction_recip;
+
+        // This is the same as `aabb_intersection_at`, but also tracks which slab bounds the interval.
+        // Comparisons with NaN are false, so axes that are not relevant to the ray direction are skipped.
+        let (mut entry, mut entry_axis) = (f32::NEG_INFINITY, 0);
+        let (mut exit, mut exit_axis) = (f32::INFINITY, 0);
+        for axis in 0..3 {
+            if tmin[axis] > entry {
+                (entry, entry_axis) = (tmin[axis], axis);
+            }
+            if tmax[axis] < exit {
+                (exit, exit_axis) = (tmax[axis], axis);
+            }
         }
+
+        let inside = Interval1d::new(entry, exit).clamp_to(0., self.max);
+        if inside.is_empty() {
+            return None;
+        }
+
+        // The ray enters through the face that it moves into, and exits through the face that it moves out of
+        let face_normal = |axis: usize, outward: bool| {
+            let normal = [Dir3A::X, Dir3A::Y, Dir3A::Z][axis];
+            if (self.direction[axis] > 0.) == outward {
+                normal
+            } else {
+                -normal
+            }
+        };
+        Some(AabbRayHit3d {
+            entry: inside.min,
+            exit: inside.max,
+            entry_normal: face_normal(entry_axis, false),
+            exit_normal: face_normal(exit_axis, true),
+        })
     }
 
     /// Get the distance of an intersection with a [`BoundingSphere`], if any.
@@ -90,6 +227,284 @@ impl RayCast3d {
             }
         }
     }
+
+    /// Get the distance of an intersection with a solid cone, if any.
+    ///
+    /// The cone has its tip at `apex`, and opens along `axis` with the given `half_angle`
+    /// between the axis and its sides, up to a flat base at `height` along the axis.
+    /// The `half_angle` must be between `0` and `π/2`.
+    ///
+    /// Like for the other volumes, a ray starting inside the cone, or at its apex, hits it at a distance of `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::RayCast3d, Dir3, Vec3};
+    /// # use core::f32::consts::FRAC_PI_4;
+    /// // A spotlight pointing down from a height of 10, lighting a circle of radius 10 on the ground
+    /// let apex = Vec3::new(0.0, 10.0, 0.0);
+    /// let ray_cast = RayCast3d::new(Vec3::new(-10.0, 5.0, 0.0), Dir3::X, 100.0);
+    ///
+    /// let distance = ray_cast.cone_intersection_at(apex, Dir3::NEG_Y, FRAC_PI_4, 10.0);
+    /// assert!((distance.unwrap() - 5.0).abs() < 1e-5);
+    /// ```
+    pub fn cone_intersection_at(
+        &self,
+        apex: Vec3,
+        axis: Dir3,
+        half_angle: f32,
+        height: f32,
+    ) -> Option<f32> {
+        debug_assert!(half_angle > 0. && half_angle < core::f32::consts::FRAC_PI_2);
+        debug_assert!(height >= 0.);
+        let axis = Vec3A::from(*axis);
+        let offset = self.origin - Vec3A::from(apex);
+        let direction_along = self.direction.dot(axis);
+        let offset_along = offset.dot(axis);
+
+        // The distances at which the ray is between the planes of the apex and the base.
+        // A ray parallel to the base is either always or never between them.
+        let slab = if direction_along == 0. {
+            if (0.0..=height).contains(&offset_along) {
+                Interval1d::EVERYTHING
+            } else {
+                return None;
+            }
+        } else {
+            let t_apex = -offset_along / direction_along;
+            let t_base = (height - offset_along) / direction_along;
+            Interval1d::new(t_apex.min(t_base), t_apex.max(t_base))
+        };
+
+        // The ray is inside the infinite double cone where `f(t) = a * t² + 2 * b * t + c`
+        // is non-negative. Between the two planes, that is only the nappe opening along the axis,
+        // which is convex, so the ray is inside it during a single interval.
+        let cos_squared = ops::cos(half_angle).squared();
+        let a = direction_along.squared() - cos_squared;
+        let b = direction_along * offset_along - cos_squared * self.direction.dot(offset);
+        let c = offset_along.squared() - cos_squared * offset.length_squared();
+
+        let cone = if ops::abs(a) < f32::EPSILON {
+            // The ray is parallel to the sides of the cone, so `f` is linear
+            if b == 0. {
+                if c >= 0. {
+                    Interval1d::EVERYTHING
+                } else {
+                    return None;
+                }
+            } else if b > 0. {
+                Interval1d::new(-c / (2. * b), f32::INFINITY)
+            } else {
+                Interval1d::new(f32::NEG_INFINITY, -c / (2. * b))
+            }
+        } else {
+            let discriminant = b.squared() - a * c;
+            if a < 0. && discriminant < 0. {
+                return None;
+            }
+            // A ray steeper than the sides passes through both nappes, and only touches
+            // the apex if the discriminant is zero, or slightly negative after rounding
+            let root = ops::sqrt(discriminant.max(0.));
+            let near = (-b - root) / a;
+            let far = (-b + root) / a;
+            if a < 0. {
+                // The ray is shallower than the sides of the cone, and only inside it between the roots
+                Interval1d::new(far, near)
+            } else if direction_along > 0. {
+                // The ray leaves the backward nappe, and enters the one opening along the axis
+                Interval1d::new(far, f32::INFINITY)
+            } else {
+                Interval1d::new(f32::NEG_INFINITY, near)
+            }
+        };
+
+        let inside = slab.intersect(cone).clamp_to(0., self.max);
+        (!inside.is_empty()).then_some(inside.min)
+    }
+
+    /// Casts the ray against each of the `volumes`, using `intersection_at` to get the distance
+    /// of the intersection with each of them, and returns the index and distance of every hit,
+    /// sorted from nearest to farthest.
+    ///
+    /// Hits at the same distance are kept in the order of the `volumes`.
+    /// Use [`Self::cast_nearest`] if only the nearest hit is needed, which avoids allocating and sorting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::{Aabb3d, RayCast3d}, Dir3, Vec3};
+    /// let ray_cast = RayCast3d::new(Vec3::ZERO, Dir3::X, 100.0);
+    /// let volumes = [
+    ///     Aabb3d::new(Vec3::new(10.0, 0.0, 0.0), Vec3::ONE),
+    ///     Aabb3d::new(Vec3::new(5.0, 0.0, 0.0), Vec3::ONE),
+    ///     Aabb3d::new(Vec3::new(5.0, 5.0, 0.0), Vec3::ONE),
+    /// ];
+    ///
+    /// let hits = ray_cast.cast_all(&volumes, RayCast3d::aabb_intersection_at);
+    /// assert_eq!(hits, [(1, 4.0), (0, 9.0)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn cast_all<T>(
+        &self,
+        volumes: impl IntoIterator<Item = T>,
+        mut intersection_at: impl FnMut(&Self, T) -> Option<f32>,
+    ) -> Vec<(usize, f32)> {
+        let mut hits: Vec<_> = volumes
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, volume)| {
+                intersection_at(self, volume).map(|distance| (index, distance))
+            })
+            .collect();
+        hits.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        hits
+    }
+
+    /// Casts the ray against each of the `volumes`, using `intersection_at` to get the distance
+    /// of the intersection with each of them, and returns the index and distance of the nearest hit.
+    ///
+    /// If several volumes are hit at the nearest distance, the first of them is returned.
+    pub fn cast_nearest<T>(
+        &self,
+        volumes: impl IntoIterator<Item = T>,
+        mut intersection_at: impl FnMut(&Self, T) -> Option<f32>,
+    ) -> Option<(usize, f32)> {
+        volumes
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, volume)| {
+                intersection_at(self, volume).map(|distance| (index, distance))
+            })
+            .reduce(|nearest, hit| if hit.1 < nearest.1 { hit } else { nearest })
+    }
+
+    /// Get the intersection of the ray with a [`Triangle3d`], if any,
+    /// using the Möller–Trumbore algorithm.
+    ///
+    /// The front face of the triangle is the one its vertices wind counterclockwise around.
+    /// If `cull_backface` is `true`, rays hitting the back face of the triangle miss it.
+    /// Rays parallel to the triangle, and degenerate triangles, are never hit.
+    pub fn triangle_intersection(
+        &self,
+        triangle: &Triangle3d,
+        cull_backface: bool,
+    ) -> Option<RayHit3d> {
+        let [a, b, c] = triangle.vertices.map(Vec3A::from);
+        let ab = b - a;
+        let ac = c - a;
+        let p = self.direction.cross(ac);
+        let determinant = ab.dot(p);
+
+        // A negative determinant means the ray hits the back face,
+        // and a determinant close to zero means the ray is parallel to the triangle
+        if determinant < f32::EPSILON && (cull_backface || ops::abs(determinant) < f32::EPSILON) {
+            return None;
+        }
+        let determinant_recip = determinant.recip();
+
+        let offset = self.origin - a;
+        let u = offset.dot(p) * determinant_recip;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = offset.cross(ab);
+        let v = self.direction.dot(q) * determinant_recip;
+        if v < 0. || u + v > 1. {
+            return None;
+        }
+
+        let distance = ac.dot(q) * determinant_recip;
+        if distance < 0. || distance > self.max {
+            return None;
+        }
+
+        Some(RayHit3d {
+            distance,
+            barycentric_coords: Vec3::new(1. - u - v, u, v),
+            normal: Dir3A::new(ab.cross(ac)).ok()?,
+        })
+    }
+
+    /// Get the distance of an intersection with the [`Aabb3d`] expanded by `radius` in all directions,
+    /// with its edges and corners rounded, if there is one.
+    ///
+    /// This is the Minkowski sum of the box and a sphere, used for sweeping spheres against boxes.
+    fn rounded_aabb_intersection_at(&self, aabb: &Aabb3d, radius: f32) -> Option<f32> {
+        let expanded = Aabb3d {
+            min: aabb.min - radius,
+            max: aabb.max + radius,
+        };
+        let distance = self.aabb_intersection_at(&expanded)?;
+
+        // A hit within the slab of the box on at least two axes is on one of the flat faces.
+        // Otherwise, it is in the region of one of the rounded edges or corners, which are not
+        // covered by the expanded box and have to be tested against the edges of the box instead.
+        let point = self.origin + *self.direction * distance;
+        let below = point.cmplt(aabb.min);
+        let outside = below | point.cmpgt(aabb.max);
+        let corner = Vec3A::select(below, aabb.min, aabb.max);
+
+        match outside.bitmask().count_ones() {
+            0 | 1 => Some(distance),
+            // In an edge region, only the edge along the axis that is within the slab can be hit
+            2 => {
+                let axis = (!outside.bitmask()).trailing_zeros() as usize;
+                self.edge_intersection_at(corner, axis, aabb, radius)
+            }
+            // In a corner region, any of the three edges meeting at the corner can be hit first
+            _ => (0..3)
+                .filter_map(|axis| self.edge_intersection_at(corner, axis, aabb, radius))
+                .reduce(f32::min),
+        }
+    }
+
+    /// Get the distance of an intersection with a capsule of the given `radius` around the edge
+    /// of the [`Aabb3d`] that passes through `corner` along the given `axis`, if any.
+    fn edge_intersection_at(
+        &self,
+        corner: Vec3A,
+        axis: usize,
+        aabb: &Aabb3d,
+        radius: f32,
+    ) -> Option<f32> {
+        let (start, end) = (aabb.min[axis], aabb.max[axis]);
+
+        // Intersect the infinite cylinder around the edge, by projecting onto the plane perpendicular to it
+        let mut offset = self.origin - corner;
+        let mut direction = *self.direction;
+        offset[axis] = 0.;
+        direction[axis] = 0.;
+
+        let a = direction.length_squared();
+        let b = offset.dot(direction);
+        let c = offset.length_squared() - radius.squared();
+        let discriminant = b.squared() - a * c;
+        if discriminant < 0. || (c > 0. && b > 0.) || (a == 0. && c > 0.) {
+            // The ray misses the cylinder, or moves away from it
+            return None;
+        }
+
+        let distance = if c <= 0. {
+            // The ray starts inside the cylinder
+            0.
+        } else {
+            (-b - ops::sqrt(discriminant)) / a
+        };
+        if distance > self.max {
+            return None;
+        }
+
+        // A hit beyond the ends of the edge can only be on the sphere at that end
+        let along = self.origin[axis] + self.direction[axis] * distance;
+        if along < start || along > end {
+            let mut center = corner;
+            center[axis] = along.clamp(start, end);
+            return self.sphere_intersection_at(&BoundingSphere::new(center, radius));
+        }
+
+        Some(distance)
+    }
 }
 
 impl IntersectsVolume<Aabb3d> for RayCast3d {
@@ -107,6 +522,11 @@ impl IntersectsVolume<BoundingSphere> for RayCast3d {
 /// An intersection test that casts an [`Aabb3d`] along a ray.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, Clone))]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct AabbCast3d {
     /// The ray along which to cast the bounding volume
     pub ray: RayCast3d,
@@ -141,6 +561,18 @@ impl AabbCast3d {
         aabb.max -= self.aabb.min;
         self.ray.aabb_intersection_at(&aabb)
     }
+
+    /// Get the distance at which the [`Aabb3d`] collides with the given [`BoundingSphere`], if at all.
+    ///
+    /// If the [`Aabb3d`] already overlaps the sphere at the origin of the ray, the distance is zero.
+    pub fn sphere_collision_at(&self, sphere: &BoundingSphere) -> Option<f32> {
+        let offset = Aabb3d {
+            min: sphere.center - self.aabb.max,
+            max: sphere.center - self.aabb.min,
+        };
+        self.ray
+            .rounded_aabb_intersection_at(&offset, sphere.radius())
+    }
 }
 
 impl IntersectsVolume<Aabb3d> for AabbCast3d {
@@ -149,9 +581,20 @@ impl IntersectsVolume<Aabb3d> for AabbCast3d {
     }
 }
 
+impl IntersectsVolume<BoundingSphere> for AabbCast3d {
+    fn intersects(&self, volume: &BoundingSphere) -> bool {
+        self.sphere_collision_at(volume).is_some()
+    }
+}
+
 /// An intersection test that casts a [`BoundingSphere`] along a ray.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, Clone))]
+#[cfg_attr(feature = "serialize", derive(Serialize), derive(Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct BoundingSphereCast {
     /// The ray along which to cast the bounding volume
     pub ray: RayCast3d,
@@ -186,6 +629,18 @@ impl BoundingSphereCast {
         sphere.sphere.radius += self.sphere.radius();
         self.ray.sphere_intersection_at(&sphere)
     }
+
+    /// Get the distance at which the [`BoundingSphere`] collides with the given [`Aabb3d`], if at all.
+    ///
+    /// If the [`BoundingSphere`] already overlaps the AABB at the origin of the ray, the distance is zero.
+    pub fn aabb_collision_at(&self, aabb: &Aabb3d) -> Option<f32> {
+        let offset = Aabb3d {
+            min: aabb.min - self.sphere.center,
+            max: aabb.max - self.sphere.center,
+        };
+        self.ray
+            .rounded_aabb_intersection_at(&offset, self.sphere.radius())
+    }
 }
 
 impl IntersectsVolume<BoundingSphere> for BoundingSphereCast {
@@ -194,10 +649,17 @@ impl IntersectsVolume<BoundingSphere> for BoundingSphereCast {
     }
 }
 
+impl IntersectsVolume<Aabb3d> for BoundingSphereCast {
+    fn intersects(&self, volume: &Aabb3d) -> bool {
+        self.aabb_collision_at(volume).is_some()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{Dir3, Vec3};
+    use core::f32::consts::SQRT_2;
 
     const EPSILON: f32 = 0.001;
 
@@ -309,6 +771,286 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ray_aabb_interval() {
+        let aabb = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+        for (test, ray, expected) in &[
+            (
+                "straight through",
+                RayCast3d::new(Vec3::new(-5., 0.5, 0.), Dir3::X, 90.),
+                Some((4., 6., Dir3A::NEG_X, Dir3A::X)),
+            ),
+            (
+                "straight through backwards",
+                RayCast3d::new(Vec3::new(0.5, 0., 5.), -Dir3::Z, 90.),
+                Some((4., 6., Dir3A::Z, Dir3A::NEG_Z)),
+            ),
+            (
+                "diagonally through different faces",
+                RayCast3d::new(
+                    Vec3::new(-2., -3., 0.),
+                    Dir3::from_xyz(1., 1., 0.).unwrap(),
+                    90.,
+                ),
+                Some((2. * SQRT_2, 3. * SQRT_2, Dir3A::NEG_Y, Dir3A::X)),
+            ),
+            (
+                "from inside",
+                RayCast3d::new(Vec3::ZERO, Dir3::Y, 90.),
+                Some((0., 1., Dir3A::NEG_Y, Dir3A::Y)),
+            ),
+            (
+                "ending inside",
+                RayCast3d::new(Vec3::new(-5., 0.5, 0.), Dir3::X, 5.),
+                Some((4., 5., Dir3A::NEG_X, Dir3A::X)),
+            ),
+            (
+                "beside the box",
+                RayCast3d::new(Vec3::new(-5., 1.5, 0.), Dir3::X, 90.),
+                None,
+            ),
+            (
+                "pointing away",
+                RayCast3d::new(Vec3::new(-5., 0.5, 0.), -Dir3::X, 90.),
+                None,
+            ),
+            (
+                "beyond max",
+                RayCast3d::new(Vec3::new(-5., 0.5, 0.), Dir3::X, 3.9),
+                None,
+            ),
+        ] {
+            let hit = ray.aabb_interval(&aabb);
+            match (hit, expected) {
+                (None, None) => {}
+                (Some(hit), Some((entry, exit, entry_normal, exit_normal))) => {
+                    assert!(
+                        ops::abs(hit.entry - entry) < EPSILON
+                            && ops::abs(hit.exit - exit) < EPSILON,
+                        "Case:\n  Test: {test:?}\n  Hit: {hit:?}",
+                    );
+                    assert_eq!(
+                        (hit.entry_normal, hit.exit_normal),
+                        (*entry_normal, *exit_normal),
+                        "Case:\n  Test: {test:?}",
+                    );
+                    // The distances agree with the scalar ray cast
+                    assert_eq!(ray.aabb_intersection_at(&aabb), Some(hit.entry));
+                }
+                _ => panic!("Case:\n  Test: {test:?}\n  Hit: {hit:?}\n  Expected: {expected:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_ray_intersection_cone() {
+        use core::f32::consts::FRAC_PI_4;
+
+        // A cone with its apex at the origin, opening upwards to a base of radius 2 at a height of 2
+        let (apex, axis, half_angle, height) = (Vec3::ZERO, Dir3::Y, FRAC_PI_4, 2.);
+        for (test, ray, expected) in &[
+            (
+                "through the side",
+                RayCast3d::new(Vec3::new(-5., 1., 0.), Dir3::X, 90.),
+                Some(4.),
+            ),
+            (
+                "through the base",
+                RayCast3d::new(Vec3::new(1., 5., 0.), -Dir3::Y, 90.),
+                Some(3.),
+            ),
+            (
+                "along the axis into the apex",
+                RayCast3d::new(Vec3::new(0., -3., 0.), Dir3::Y, 90.),
+                Some(3.),
+            ),
+            (
+                "along the axis into the base",
+                RayCast3d::new(Vec3::new(0., 5., 0.), -Dir3::Y, 90.),
+                Some(3.),
+            ),
+            (
+                "parallel to the side",
+                RayCast3d::new(
+                    Vec3::new(-1., 0., 0.),
+                    Dir3::new(Vec3::ONE.with_z(0.)).unwrap(),
+                    90.,
+                ),
+                Some(core::f32::consts::FRAC_1_SQRT_2),
+            ),
+            (
+                "from inside",
+                RayCast3d::new(Vec3::new(0., 1., 0.), Dir3::X, 90.),
+                Some(0.),
+            ),
+            (
+                "from the apex into the cone",
+                RayCast3d::new(Vec3::ZERO, Dir3::Y, 90.),
+                Some(0.),
+            ),
+            (
+                "from the apex away from the cone",
+                RayCast3d::new(Vec3::ZERO, -Dir3::Y, 90.),
+                Some(0.),
+            ),
+            (
+                "above the base",
+                RayCast3d::new(Vec3::new(-5., 3., 0.), Dir3::X, 90.),
+                None,
+            ),
+            (
+                "beside the cone",
+                RayCast3d::new(Vec3::new(-5., 1., 3.), Dir3::X, 90.),
+                None,
+            ),
+            (
+                "through the mirrored cone below the apex",
+                RayCast3d::new(Vec3::new(-5., -1., 0.), Dir3::X, 90.),
+                None,
+            ),
+            (
+                "along the axis away from the apex",
+                RayCast3d::new(Vec3::new(0., -3., 0.), -Dir3::Y, 90.),
+                None,
+            ),
+            (
+                "pointing away",
+                RayCast3d::new(Vec3::new(-5., 1., 0.), -Dir3::X, 90.),
+                None,
+            ),
+            (
+                "beyond max",
+                RayCast3d::new(Vec3::new(-5., 1., 0.), Dir3::X, 3.9),
+                None,
+            ),
+        ] {
+            let distance = ray.cone_intersection_at(apex, axis, half_angle, height);
+            match (distance, expected) {
+                (None, None) => {}
+                (Some(distance), Some(expected)) => assert!(
+                    ops::abs(distance - expected) < EPSILON,
+                    "Case:\n  Test: {test:?}\n  Distance: {distance}",
+                ),
+                _ => panic!(
+                    "Case:\n  Test: {test:?}\n  Distance: {distance:?}\n  Expected: {expected:?}"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_ray_cast_all() {
+        let ray = RayCast3d::new(Vec3::ZERO, Dir3::X, 20.);
+        let spheres = [
+            BoundingSphere::new(Vec3::new(10., 0., 0.), 1.),
+            BoundingSphere::new(Vec3::new(5., 5., 0.), 1.),
+            BoundingSphere::new(Vec3::new(5., 0., 0.), 1.),
+            BoundingSphere::new(Vec3::new(30., 0., 0.), 1.),
+        ];
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            ray.cast_all(&spheres, RayCast3d::sphere_intersection_at),
+            [(2, 4.), (0, 9.)]
+        );
+        assert_eq!(
+            ray.cast_nearest(&spheres, RayCast3d::sphere_intersection_at),
+            Some((2, 4.))
+        );
+        assert_eq!(
+            ray.cast_nearest(&spheres[3..], RayCast3d::sphere_intersection_at),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ray_intersection_triangle() {
+        let triangle = Triangle3d::new(
+            Vec3::new(-1., -1., 0.),
+            Vec3::new(1., -1., 0.),
+            Vec3::new(-1., 1., 0.),
+        );
+        for (test, ray, expected) in &[
+            (
+                "front face",
+                RayCast3d::new(Vec3::new(-0.5, -0.5, 5.), -Dir3::Z, 90.),
+                Some((5., Vec3::new(0.5, 0.25, 0.25))),
+            ),
+            (
+                "back face",
+                RayCast3d::new(Vec3::new(-0.5, -0.5, -5.), Dir3::Z, 90.),
+                Some((5., Vec3::new(0.5, 0.25, 0.25))),
+            ),
+            (
+                "vertex",
+                RayCast3d::new(Vec3::new(1., -1., 2.), -Dir3::Z, 90.),
+                Some((2., Vec3::new(0., 1., 0.))),
+            ),
+            (
+                "outside the hypotenuse",
+                RayCast3d::new(Vec3::new(0.5, 0.5, 5.), -Dir3::Z, 90.),
+                None,
+            ),
+            (
+                "outside a leg",
+                RayCast3d::new(Vec3::new(-1.5, 0., 5.), -Dir3::Z, 90.),
+                None,
+            ),
+            (
+                "pointing away",
+                RayCast3d::new(Vec3::new(-0.5, -0.5, 5.), Dir3::Z, 90.),
+                None,
+            ),
+            (
+                "parallel",
+                RayCast3d::new(Vec3::new(-5., -0.5, 0.), Dir3::X, 90.),
+                None,
+            ),
+            (
+                "beyond max",
+                RayCast3d::new(Vec3::new(-0.5, -0.5, 5.), -Dir3::Z, 4.9),
+                None,
+            ),
+        ] {
+            let hit = ray.triangle_intersection(&triangle, false);
+            match (hit, expected) {
+                (None, None) => {}
+                (Some(hit), Some((distance, barycentric_coords))) => {
+                    assert!(
+                        ops::abs(hit.distance - distance) < EPSILON,
+                        "Case:\n  Test: {test:?}\n  Hit: {hit:?}",
+                    );
+                    assert!(
+                        hit.barycentric_coords
+                            .abs_diff_eq(*barycentric_coords, EPSILON),
+                        "Case:\n  Test: {test:?}\n  Hit: {hit:?}",
+                    );
+                    assert_eq!(hit.normal, Dir3A::Z, "Case:\n  Test: {test:?}");
+
+                    // The barycentric coordinates give the hit point
+                    let point = Vec3::from(ray.origin + *ray.direction * hit.distance);
+                    let [a, b, c] = triangle.vertices;
+                    let weights = hit.barycentric_coords;
+                    assert!(
+                        (a * weights.x + b * weights.y + c * weights.z).abs_diff_eq(point, EPSILON),
+                        "Case:\n  Test: {test:?}\n  Hit: {hit:?}",
+                    );
+                }
+                _ => panic!("Case:\n  Test: {test:?}\n  Hit: {hit:?}\n  Expected: {expected:?}"),
+            }
+        }
+
+        // Culling the back face
+        let front = RayCast3d::new(Vec3::new(-0.5, -0.5, 5.), -Dir3::Z, 90.);
+        let back = RayCast3d::new(Vec3::new(-0.5, -0.5, -5.), Dir3::Z, 90.);
+        assert!(front.triangle_intersection(&triangle, true).is_some());
+        assert!(back.triangle_intersection(&triangle, true).is_none());
+
+        // Degenerate triangles are never hit
+        let degenerate = Triangle3d::new(Vec3::ZERO, Vec3::X, Vec3::X * 2.);
+        let ray = RayCast3d::new(Vec3::new(0.5, 0., 5.), -Dir3::Z, 90.);
+        assert!(ray.triangle_intersection(&degenerate, false).is_none());
+    }
+
     #[test]
     fn test_ray_intersection_aabb_hits() {
         for (test, volume, expected_distance) in &[
@@ -543,4 +1285,175 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_sphere_cast_aabb_hits() {
+        let aabb = Aabb3d::new(Vec3::Y * 5., Vec3::ONE);
+        for (test, expected_distance) in &[
+            (
+                // Hit the face of the box head-on
+                BoundingSphereCast::new(
+                    BoundingSphere::new(Vec3::ZERO, 1.),
+                    Vec3::ZERO,
+                    Dir3::Y,
+                    90.,
+                ),
+                3.,
+            ),
+            (
+                // Hit a rounded edge of the box
+                BoundingSphereCast::new(
+                    BoundingSphere::new(Vec3::ZERO, 1.),
+                    Vec3::X * 1.6,
+                    Dir3::Y,
+                    90.,
+                ),
+                3.2,
+            ),
+            (
+                // Hit a rounded corner of the box, by casting a sphere that is off-center
+                BoundingSphereCast::new(
+                    BoundingSphere::new(Vec3::new(-1.4, 0., -1.4), 1.),
+                    Vec3::new(2.8, 0., 2.8),
+                    Dir3::Y,
+                    90.,
+                ),
+                4. - ops::sqrt(0.68),
+            ),
+            (
+                // Start overlapping the box
+                BoundingSphereCast::new(
+                    BoundingSphere::new(Vec3::ZERO, 1.),
+                    Vec3::new(1.5, 3.5, 0.),
+                    Dir3::Y,
+                    90.,
+                ),
+                0.,
+            ),
+        ] {
+            assert!(
+                test.intersects(&aabb),
+                "Case:\n  Test: {test:?}\n  Expected distance: {expected_distance:?}",
+            );
+            let actual_distance = test.aabb_collision_at(&aabb).unwrap();
+            assert!(
+                ops::abs(actual_distance - expected_distance) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Expected distance: {expected_distance:?}\n  Actual distance: {actual_distance}",
+            );
+        }
+
+        // Pass by a corner within the expanded box, but outside the rounded corner
+        let test = BoundingSphereCast::new(
+            BoundingSphere::new(Vec3::ZERO, 1.),
+            Vec3::new(1.8, 0., 1.8),
+            Dir3::Y,
+            90.,
+        );
+        assert!(!test.intersects(&aabb));
+    }
+
+    #[test]
+    fn test_aabb_cast_sphere_hits() {
+        let sphere = BoundingSphere::new(Vec3::Y * 5., 1.);
+        for (test, expected_distance) in &[
+            (
+                // Hit the center of the sphere
+                AabbCast3d::new(Aabb3d::new(Vec3::ZERO, Vec3::ONE), Vec3::ZERO, Dir3::Y, 90.),
+                3.,
+            ),
+            (
+                // Hit the sphere with an edge of the aabb
+                AabbCast3d::new(
+                    Aabb3d::new(Vec3::ZERO, Vec3::ONE),
+                    Vec3::X * 1.6,
+                    Dir3::Y,
+                    90.,
+                ),
+                3.2,
+            ),
+            (
+                // The aabb already overlaps the sphere
+                AabbCast3d::new(
+                    Aabb3d::new(Vec3::ZERO, Vec3::ONE),
+                    Vec3::Y * 3.5,
+                    Dir3::Y,
+                    90.,
+                ),
+                0.,
+            ),
+        ] {
+            assert!(
+                test.intersects(&sphere),
+                "Case:\n  Test: {test:?}\n  Expected distance: {expected_distance:?}",
+            );
+            let actual_distance = test.sphere_collision_at(&sphere).unwrap();
+            assert!(
+                ops::abs(actual_distance - expected_distance) < EPSILON,
+                "Case:\n  Test: {test:?}\n  Expected distance: {expected_distance:?}\n  Actual distance: {actual_distance}",
+            );
+        }
+
+        // The sphere is beyond the max distance
+        let test = AabbCast3d::new(Aabb3d::new(Vec3::ZERO, Vec3::ONE), Vec3::ZERO, Dir3::Y, 2.9);
+        assert!(!test.intersects(&sphere));
+    }
+
+    #[test]
+    fn test_sphere_cast_aabb_ray_march() {
+        use rand::{Rng, SeedableRng};
+        use rand_chacha::ChaCha8Rng;
+
+        // Compare against marching the sphere along the ray in small steps
+        const STEP: f32 = 0.001;
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let aabb = Aabb3d::new(Vec3::ZERO, Vec3::new(1., 0.5, 0.75));
+        let sphere = BoundingSphere::new(Vec3::ZERO, 0.5);
+
+        for _ in 0..200 {
+            let origin = Vec3::new(
+                rng.gen_range(-3.0..3.0),
+                rng.gen_range(-3.0..3.0),
+                rng.gen_range(-3.0..3.0),
+            );
+            // Aim roughly at the box, so that many casts graze its edges and corners
+            let target = Vec3::new(
+                rng.gen_range(-1.6..1.6),
+                rng.gen_range(-1.1..1.1),
+                rng.gen_range(-1.3..1.3),
+            );
+            let Ok(direction) = Dir3::new(target - origin) else {
+                continue;
+            };
+            let test = BoundingSphereCast::new(sphere, origin, direction, 10.);
+
+            let marched = (0..=(10. / STEP) as usize)
+                .map(|i| i as f32 * STEP)
+                .find(|&distance| {
+                    let center = Vec3A::from(origin + *direction * distance);
+                    aabb.closest_point(center).distance(center) <= sphere.radius()
+                });
+            let actual = test.aabb_collision_at(&aabb);
+
+            match (marched, actual) {
+                (None, None) => {}
+                (Some(marched), Some(actual)) => assert!(
+                    ops::abs(marched - actual) <= 2. * STEP,
+                    "Test: {test:?}\n  Marched: {marched}\n  Actual: {actual}",
+                ),
+                // The march can step over hits that only graze the volume
+                (None, Some(actual)) => {
+                    let center = Vec3A::from(origin + *direction * actual);
+                    let distance = aabb.closest_point(center).distance(center);
+                    assert!(
+                        ops::abs(distance - sphere.radius()) < EPSILON,
+                        "Test: {test:?}\n  Actual: {actual}",
+                    );
+                }
+                (Some(marched), None) => {
+                    panic!("Test: {test:?}\n  Marched: {marched}\n  Actual: None")
+                }
+            }
+        }
+    }
 }
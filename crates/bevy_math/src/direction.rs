@@ -1,9 +1,10 @@
 use crate::{
+    ops,
     primitives::{Primitive2d, Primitive3d},
     Quat, Rot2, Vec2, Vec3, Vec3A,
 };
 
-use core::f32::consts::FRAC_1_SQRT_2;
+use core::f32::consts::{FRAC_1_SQRT_2, PI};
 use derive_more::derive::Into;
 
 #[cfg(feature = "bevy_reflect")]
@@ -17,6 +18,10 @@ use std::eprintln;
 
 use thiserror::Error;
 
+/// The dot product above which two 3D directions are considered nearly identical
+/// in [`Dir3::slerp`] and [`Dir3A::slerp`], in which case they are interpolated linearly.
+const SLERP_DOT_THRESHOLD: f32 = 0.9995;
+
 /// An error indicating that a direction is invalid.
 #[derive(Debug, PartialEq, Error)]
 pub enum InvalidDirectionError {
@@ -128,6 +133,7 @@ impl Dir2 {
     ///
     /// Returns [`Err(InvalidDirectionError)`](InvalidDirectionError) if the length
     /// of the given vector is zero (or very close to zero), infinite, or `NaN`.
+    #[doc(alias = "new_checked")]
     pub fn new(value: Vec2) -> Result<Self, InvalidDirectionError> {
         Self::new_and_length(value).map(|(dir, _)| dir)
     }
@@ -178,6 +184,20 @@ impl Dir2 {
         Self::new_unchecked(Vec2::new(x, y))
     }
 
+    /// Create the direction that the X axis is rotated to by the given [`Rot2`].
+    ///
+    /// The cosine and sine of the rotation are used as the components of the direction directly,
+    /// without any trigonometric functions or loss of precision.
+    /// This is the inverse of [`Rot2::from_dir`].
+    ///
+    /// # Warning
+    ///
+    /// The rotation must be [normalized](Rot2::is_normalized).
+    #[inline]
+    pub fn from_rot2(rot: Rot2) -> Self {
+        Self::new_unchecked(Vec2::new(rot.cos, rot.sin))
+    }
+
     /// Returns the inner [`Vec2`]
     pub const fn as_vec2(&self) -> Vec2 {
         self.0
@@ -191,6 +211,10 @@ impl Dir2 {
     /// When `s == 0.0`, the result will be equal to `self`.
     /// When `s == 1.0`, the result will be equal to `rhs`.
     ///
+    /// The interpolation always follows the shorter arc between the two directions.
+    /// If `self` and `rhs` point in opposite directions, both arcs are equally short,
+    /// and the counterclockwise one is used.
+    ///
     /// # Example
     ///
     /// ```
@@ -210,7 +234,11 @@ impl Dir2 {
     /// ```
     #[inline]
     pub fn slerp(self, rhs: Self, s: f32) -> Self {
-        let angle = self.angle_to(rhs.0);
+        // Unlike `Vec2::angle_to`, `atan2` stays precise for nearly identical directions
+        let angle = ops::atan2(self.perp_dot(rhs.0), self.dot(rhs.0));
+        // For antipodal directions, the sign of the angle depends on the sign of a zero,
+        // so always pick the counterclockwise arc to be consistent.
+        let angle = if angle == -PI { PI } else { angle };
         Rot2::radians(angle * s) * self
     }
 
@@ -393,6 +421,7 @@ impl Dir3 {
     ///
     /// Returns [`Err(InvalidDirectionError)`](InvalidDirectionError) if the length
     /// of the given vector is zero (or very close to zero), infinite, or `NaN`.
+    #[doc(alias = "new_checked")]
     pub fn new(value: Vec3) -> Result<Self, InvalidDirectionError> {
         Self::new_and_length(value).map(|(dir, _)| dir)
     }
@@ -456,6 +485,12 @@ impl Dir3 {
     /// When `s == 0.0`, the result will be equal to `self`.
     /// When `s == 1.0`, the result will be equal to `rhs`.
     ///
+    /// The interpolation always follows the shorter great-circle arc between the two directions,
+    /// and falls back to a normalized linear interpolation when they are nearly identical.
+    /// If `self` and `rhs` point in opposite directions, there is no unique shortest arc.
+    /// In that case, the interpolation rotates around an arbitrary axis perpendicular to `self`,
+    /// which only depends on `self` and is therefore stable across calls.
+    ///
     /// # Example
     ///
     /// ```
@@ -479,6 +514,11 @@ impl Dir3 {
     /// ```
     #[inline]
     pub fn slerp(self, rhs: Self, s: f32) -> Self {
+        // `Quat::from_rotation_arc` returns the identity for nearly identical directions,
+        // so interpolate linearly and renormalize instead.
+        if self.dot(rhs.0) > SLERP_DOT_THRESHOLD {
+            return Dir3(self.0.lerp(rhs.0, s).normalize());
+        }
         let quat = Quat::IDENTITY.slerp(Quat::from_rotation_arc(self.0, rhs.0), s);
         Dir3(quat.mul_vec3(self.0))
     }
@@ -658,6 +698,7 @@ impl Dir3A {
     ///
     /// Returns [`Err(InvalidDirectionError)`](InvalidDirectionError) if the length
     /// of the given vector is zero (or very close to zero), infinite, or `NaN`.
+    #[doc(alias = "new_checked")]
     pub fn new(value: Vec3A) -> Result<Self, InvalidDirectionError> {
         Self::new_and_length(value).map(|(dir, _)| dir)
     }
@@ -721,6 +762,12 @@ impl Dir3A {
     /// When `s == 0.0`, the result will be equal to `self`.
     /// When `s == 1.0`, the result will be equal to `rhs`.
     ///
+    /// The interpolation always follows the shorter great-circle arc between the two directions,
+    /// and falls back to a normalized linear interpolation when they are nearly identical.
+    /// If `self` and `rhs` point in opposite directions, there is no unique shortest arc.
+    /// In that case, the interpolation rotates around an arbitrary axis perpendicular to `self`,
+    /// which only depends on `self` and is therefore stable across calls.
+    ///
     /// # Example
     ///
     /// ```
@@ -744,6 +791,11 @@ impl Dir3A {
     /// ```
     #[inline]
     pub fn slerp(self, rhs: Self, s: f32) -> Self {
+        // `Quat::from_rotation_arc` returns the identity for nearly identical directions,
+        // so interpolate linearly and renormalize instead.
+        if self.dot(rhs.0) > SLERP_DOT_THRESHOLD {
+            return Dir3A(self.0.lerp(rhs.0, s).normalize());
+        }
         let quat = Quat::IDENTITY.slerp(
             Quat::from_rotation_arc(Vec3::from(self.0), Vec3::from(rhs.0)),
             s,
@@ -912,6 +964,15 @@ mod tests {
             Dir2::X.slerp(Dir2::Y, 2.0 / 3.0),
             Dir2::from_xy(0.5, ops::sqrt(0.75_f32)).unwrap()
         );
+
+        // Antipodal directions are interpolated counterclockwise
+        assert_relative_eq!(Dir2::X.slerp(Dir2::NEG_X, 0.5), Dir2::Y);
+        assert_relative_eq!(Dir2::Y.slerp(Dir2::NEG_Y, 0.5), Dir2::NEG_X);
+        assert_relative_eq!(Dir2::X.slerp(Dir2::NEG_X, 1.0), Dir2::NEG_X);
+
+        // Nearly identical directions
+        let dir = Dir2::from_xy(1.0, 1e-4).unwrap();
+        assert_relative_eq!(Dir2::X.slerp(dir, 0.5), Dir2::from_xy(1.0, 5e-5).unwrap());
     }
 
     #[test]
@@ -924,6 +985,24 @@ mod tests {
         assert_relative_eq!(Dir2::NORTH_WEST.rotation_from_y(), Rot2::FRAC_PI_4);
     }
 
+    #[test]
+    fn dir2_rotation2d_conversions() {
+        for dir in [
+            Dir2::EAST,
+            Dir2::NORTH_WEST,
+            Dir2::from_xy(0.6, -0.8).unwrap(),
+        ] {
+            let rot = Rot2::from_dir(dir);
+            assert_eq!(rot, dir.rotation_from_x());
+            assert_eq!(Dir2::from_rot2(rot), dir);
+            assert_eq!(rot * Dir2::X, dir);
+        }
+
+        let rot = Rot2::radians(1.0);
+        assert_eq!(Rot2::from_dir(Dir2::from_rot2(rot)), rot);
+        assert_eq!(Dir2::from_rot2(Rot2::FRAC_PI_2), Dir2::Y);
+    }
+
     #[test]
     fn dir2_renorm() {
         // Evil denormalized Rot2
@@ -994,6 +1073,24 @@ mod tests {
             Dir3::Z.slerp(Dir3::Y, 2.0 / 3.0),
             Dir3::from_xyz(0.0, ops::sqrt(0.75f32), 0.5).unwrap()
         );
+
+        // Antipodal directions are interpolated along a stable perpendicular arc
+        let halfway = Dir3::X.slerp(Dir3::NEG_X, 0.5);
+        assert!(ops::abs(halfway.dot(*Dir3::X)) < 1e-6);
+        assert_eq!(halfway, Dir3::X.slerp(Dir3::NEG_X, 0.5));
+        assert_relative_eq!(
+            Dir3::X.slerp(Dir3::NEG_X, 1.0),
+            Dir3::NEG_X,
+            epsilon = 0.000001
+        );
+
+        // Nearly identical directions
+        let dir = Dir3::from_xyz(1.0, 1e-4, 0.0).unwrap();
+        assert_relative_eq!(
+            Dir3::X.slerp(dir, 0.5),
+            Dir3::from_xyz(1.0, 5e-5, 0.0).unwrap(),
+            epsilon = 0.000001
+        );
     }
 
     #[test]
@@ -1065,6 +1162,15 @@ mod tests {
             Dir3A::Z.slerp(Dir3A::Y, 2.0 / 3.0),
             Dir3A::from_xyz(0.0, ops::sqrt(0.75f32), 0.5).unwrap()
         );
+
+        // Nearly identical directions
+        let dir = Dir3A::from_xyz(1.0, 1e-4, 0.0).unwrap();
+        assert_relative_eq!(
+            Dir3A::X.slerp(dir, 0.5),
+            Dir3A::from_xyz(1.0, 5e-5, 0.0).unwrap(),
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(Dir3A::X.slerp(dir, 1.0), dir, epsilon = 0.000001);
     }
 
     #[test]
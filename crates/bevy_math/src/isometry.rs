@@ -156,6 +156,10 @@ impl Isometry2d {
 
     /// Compute `iso1.inverse() * iso2` in a more efficient way for one-shot cases.
     ///
+    /// For `self = (r1, t1)` and `rhs = (r2, t2)`, the inverse of `self` is `(r1.inverse(), r1.inverse() * -t1)`,
+    /// so the product is `(r1.inverse() * r2, r1.inverse() * (t2 - t1))`. This is computed directly, without first
+    /// constructing the inverse isometry.
+    ///
     /// If the same isometry is used multiple times, it is more efficient to instead compute
     /// the inverse once and use that for each transformation.
     #[inline]
@@ -178,7 +182,38 @@ impl Isometry2d {
     /// the inverse once and use that for each transformation.
     #[inline]
     pub fn inverse_transform_point(&self, point: Vec2) -> Vec2 {
-        self.rotation.inverse() * (point - self.translation)
+        self.rotation.inverse_mul_vec(point - self.translation)
+    }
+
+    /// Transform a vector by rotating it using this isometry.
+    ///
+    /// Unlike [`Isometry2d::transform_point`], the translation is ignored,
+    /// since a vector like a velocity or an offset has no position.
+    #[inline]
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        self.rotation * vector
+    }
+
+    /// Transform a vector by rotating it using the inverse of this isometry.
+    ///
+    /// Unlike [`Isometry2d::inverse_transform_point`], the translation is ignored.
+    #[inline]
+    pub fn inverse_transform_vector(&self, vector: Vec2) -> Vec2 {
+        self.rotation.inverse_mul_vec(vector)
+    }
+
+    /// Interpolates between `self` and `rhs` based on the value `t`, linearly for the translation
+    /// and along the shortest arc for the rotation, using [`Rot2::slerp`].
+    ///
+    /// When `t == 0.0`, the result will be equal to `self`.
+    /// When `t == 1.0`, the result will be equal to `rhs`.
+    /// Values of `t` outside of `[0, 1]` are not clamped, and extrapolate past `self` or `rhs`.
+    #[inline]
+    pub fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Isometry2d {
+            rotation: self.rotation.slerp(rhs.rotation, t),
+            translation: self.translation.lerp(rhs.translation, t),
+        }
     }
 }
 
@@ -434,6 +469,10 @@ impl Isometry3d {
 
     /// Compute `iso1.inverse() * iso2` in a more efficient way for one-shot cases.
     ///
+    /// For `self = (r1, t1)` and `rhs = (r2, t2)`, the inverse of `self` is `(r1.inverse(), r1.inverse() * -t1)`,
+    /// so the product is `(r1.inverse() * r2, r1.inverse() * (t2 - t1))`. This is computed directly, without first
+    /// constructing the inverse isometry.
+    ///
     /// If the same isometry is used multiple times, it is more efficient to instead compute
     /// the inverse once and use that for each transformation.
     #[inline]
@@ -458,6 +497,37 @@ impl Isometry3d {
     pub fn inverse_transform_point(&self, point: impl Into<Vec3A>) -> Vec3A {
         self.rotation.inverse() * (point.into() - self.translation)
     }
+
+    /// Transform a vector by rotating it using this isometry.
+    ///
+    /// Unlike [`Isometry3d::transform_point`], the translation is ignored,
+    /// since a vector like a velocity or an offset has no position.
+    #[inline]
+    pub fn transform_vector(&self, vector: impl Into<Vec3A>) -> Vec3A {
+        self.rotation * vector.into()
+    }
+
+    /// Transform a vector by rotating it using the inverse of this isometry.
+    ///
+    /// Unlike [`Isometry3d::inverse_transform_point`], the translation is ignored.
+    #[inline]
+    pub fn inverse_transform_vector(&self, vector: impl Into<Vec3A>) -> Vec3A {
+        self.rotation.inverse() * vector.into()
+    }
+
+    /// Interpolates between `self` and `rhs` based on the value `t`, linearly for the translation
+    /// and along the shortest arc for the rotation, using [`Quat::slerp`].
+    ///
+    /// When `t == 0.0`, the result will be equal to `self`.
+    /// When `t == 1.0`, the result will be equal to `rhs`.
+    /// Values of `t` outside of `[0, 1]` are not clamped, and extrapolate past `self` or `rhs`.
+    #[inline]
+    pub fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Isometry3d {
+            rotation: self.rotation.slerp(rhs.rotation, t),
+            translation: self.translation.lerp(rhs.translation, t),
+        }
+    }
 }
 
 impl From<Isometry3d> for Affine3 {
@@ -594,7 +664,7 @@ mod tests {
     use super::*;
     use crate::{vec2, vec3, vec3a};
     use approx::assert_abs_diff_eq;
-    use core::f32::consts::{FRAC_PI_2, FRAC_PI_3};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, PI};
 
     #[test]
     fn mul_2d() {
@@ -610,6 +680,33 @@ mod tests {
         let iso2 = Isometry2d::new(vec2(0.0, 0.0), Rot2::PI);
         let expected = Isometry2d::new(vec2(0.0, 1.0), Rot2::FRAC_PI_2);
         assert_abs_diff_eq!(iso1.inverse_mul(iso2), expected);
+        assert_abs_diff_eq!(iso1.inverse_mul(iso2), iso1.inverse() * iso2);
+        assert_abs_diff_eq!(iso1.inverse_mul(iso1), Isometry2d::IDENTITY);
+    }
+
+    #[test]
+    fn lerp_2d() {
+        let iso1 = Isometry2d::new(vec2(1.0, 0.0), Rot2::IDENTITY);
+        let iso2 = Isometry2d::new(vec2(3.0, 2.0), Rot2::FRAC_PI_2);
+        assert_abs_diff_eq!(iso1.lerp(&iso2, 0.0), iso1);
+        assert_abs_diff_eq!(iso1.lerp(&iso2, 1.0), iso2);
+        assert_abs_diff_eq!(
+            iso1.lerp(&iso2, 0.5),
+            Isometry2d::new(vec2(2.0, 1.0), Rot2::FRAC_PI_4)
+        );
+        // Extrapolation
+        assert_abs_diff_eq!(
+            iso1.lerp(&iso2, 2.0),
+            Isometry2d::new(vec2(5.0, 4.0), Rot2::PI)
+        );
+        // The rotation takes the shortest arc
+        let iso3 = Isometry2d::from_rotation(Rot2::degrees(-90.0));
+        let iso4 = Isometry2d::from_rotation(Rot2::degrees(180.0));
+        assert_abs_diff_eq!(
+            iso3.lerp(&iso4, 0.5),
+            Isometry2d::from_rotation(Rot2::degrees(-135.0)),
+            epsilon = 1e-6
+        );
     }
 
     #[test]
@@ -626,6 +723,37 @@ mod tests {
         let iso2 = Isometry3d::new(vec3(1.0, 0.0, 1.0), Quat::from_rotation_x(FRAC_PI_2));
         let expected = Isometry3d::new(vec3(0.0, 1.0, 0.0), Quat::IDENTITY);
         assert_abs_diff_eq!(iso1.inverse_mul(iso2), expected);
+        assert_abs_diff_eq!(iso1.inverse_mul(iso2), iso1.inverse() * iso2);
+        assert_abs_diff_eq!(iso1.inverse_mul(iso1), Isometry3d::IDENTITY);
+    }
+
+    #[test]
+    fn lerp_3d() {
+        let iso1 = Isometry3d::new(vec3(1.0, 0.0, 0.0), Quat::IDENTITY);
+        let iso2 = Isometry3d::new(vec3(3.0, 2.0, 0.0), Quat::from_rotation_z(FRAC_PI_2));
+        assert_abs_diff_eq!(iso1.lerp(&iso2, 0.0), iso1);
+        assert_abs_diff_eq!(iso1.lerp(&iso2, 1.0), iso2, epsilon = 1e-6);
+        assert_abs_diff_eq!(
+            iso1.lerp(&iso2, 0.5),
+            Isometry3d::new(vec3(2.0, 1.0, 0.0), Quat::from_rotation_z(FRAC_PI_4)),
+            epsilon = 1e-6
+        );
+        // Extrapolation
+        assert_abs_diff_eq!(
+            iso1.lerp(&iso2, 2.0),
+            Isometry3d::new(vec3(5.0, 4.0, 0.0), Quat::from_rotation_z(PI)),
+            epsilon = 1e-6
+        );
+        // The rotation takes the shortest arc, even if the quaternions are in opposite hemispheres
+        let iso3 = Isometry3d::from_rotation(-Quat::from_rotation_z(FRAC_PI_2));
+        assert_abs_diff_eq!(
+            Isometry3d::IDENTITY
+                .lerp(&iso3, 0.5)
+                .rotation
+                .angle_between(Quat::IDENTITY),
+            FRAC_PI_4,
+            epsilon = 1e-6
+        );
     }
 
     #[test]
@@ -658,6 +786,15 @@ mod tests {
         assert_abs_diff_eq!(inv * iso, Isometry3d::IDENTITY);
     }
 
+    #[test]
+    fn affine_from_isometry_2d() {
+        let iso = Isometry2d::new(vec2(0.5, -0.5), Rot2::degrees(30.0));
+        let affine = Affine2::from(iso);
+        let point = vec2(2.0, 1.0);
+        assert_abs_diff_eq!(affine.transform_point2(point), iso * point);
+        assert_abs_diff_eq!(affine.transform_vector2(point), iso.rotation * point);
+    }
+
     #[test]
     fn transform_2d() {
         let iso = Isometry2d::new(vec2(0.5, -0.5), Rot2::FRAC_PI_2);
@@ -672,6 +809,20 @@ mod tests {
         assert_abs_diff_eq!(vec2(1.0, 1.0), iso.inverse_transform_point(point));
     }
 
+    #[test]
+    fn transform_vector_2d() {
+        let iso = Isometry2d::new(vec2(0.5, -0.5), Rot2::FRAC_PI_2);
+        let vector = vec2(1.0, 1.0);
+        assert_abs_diff_eq!(vec2(-1.0, 1.0), iso.transform_vector(vector));
+        assert_abs_diff_eq!(vector, iso.inverse_transform_vector(vec2(-1.0, 1.0)));
+        // The difference of two transformed points is the transformed vector between them
+        let point = vec2(3.0, -2.0);
+        assert_abs_diff_eq!(
+            iso.transform_point(point + vector) - iso.transform_point(point),
+            iso.transform_vector(vector)
+        );
+    }
+
     #[test]
     fn transform_3d() {
         let iso = Isometry3d::new(vec3(1.0, 0.0, 0.0), Quat::from_rotation_y(FRAC_PI_2));
@@ -685,4 +836,15 @@ mod tests {
         let point = vec3(2.0, 1.0, -1.0);
         assert_abs_diff_eq!(vec3a(1.0, 1.0, 1.0), iso.inverse_transform_point(point));
     }
+
+    #[test]
+    fn transform_vector_3d() {
+        let iso = Isometry3d::new(vec3(1.0, 0.0, 0.0), Quat::from_rotation_y(FRAC_PI_2));
+        let vector = vec3(1.0, 1.0, 1.0);
+        assert_abs_diff_eq!(vec3a(1.0, 1.0, -1.0), iso.transform_vector(vector));
+        assert_abs_diff_eq!(
+            vec3a(1.0, 1.0, 1.0),
+            iso.inverse_transform_vector(vec3(1.0, 1.0, -1.0))
+        );
+    }
 }
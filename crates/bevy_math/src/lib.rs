@@ -65,6 +65,10 @@ pub use curve::Curve;
 #[cfg(feature = "rand")]
 pub use sampling::{FromRng, ShapeSample};
 
+/// The half-precision float type used by half-precision bounding volumes like [`bounding::HAabb2d`].
+#[cfg(feature = "half")]
+pub use half::f16;
+
 /// The math prelude.
 ///
 /// This includes the most common types in this crate, re-exported for your convenience.
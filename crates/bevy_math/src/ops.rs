@@ -7,6 +7,13 @@
 //!
 //! It also provides `no_std` compatible alternatives to certain floating-point
 //! operations which are not provided in the [`core`] library.
+//!
+//! Code that can otherwise produce different results on different platforms, like the
+//! bounding volume intersection tests, routes its math through this module. Enabling the
+//! `deterministic` crate feature selects the `libm` versions, so that results are bit-identical
+//! everywhere. This does not change how `glam` itself is compiled: its SIMD types like `Vec3A` may
+//! still use platform-specific code. Enable `glam`'s `scalar-math` feature separately to opt out of
+//! that, keeping in mind that it is not supported together with `bevy_reflect`.
 
 // Note: There are some Rust methods with unspecified precision without a `libm`
 // equivalent:
@@ -4,8 +4,9 @@ use thiserror::Error;
 
 use super::{Measured2d, Primitive2d, WindingOrder};
 use crate::{
+    bounding::BoundingCircle,
     ops::{self, FloatPow},
-    Dir2, InvalidDirectionError, Isometry2d, Ray2d, Rot2, Vec2,
+    Dir2, InvalidDirectionError, Isometry2d, Ray2d, Rot2, Vec2, Vec3,
 };
 
 #[cfg(feature = "alloc")]
@@ -20,7 +21,9 @@ use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 use alloc::{boxed::Box, vec::Vec};
 
 /// A circle primitive, representing the set of points some distance from the origin
+#[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "bevy_reflect",
@@ -405,6 +408,20 @@ impl CircularSector {
     pub fn sagitta(&self) -> f32 {
         self.arc.sagitta()
     }
+
+    /// Checks if the given `point` is inside the sector or on its boundary.
+    ///
+    /// The point must be within the radius of the circle, and at most [`half_angle`](Self::half_angle)
+    /// radians away from [`Vec2::Y`] as seen from the center. This works for reflex sectors too,
+    /// and the center of the circle is always considered inside.
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        if point.length_squared() > self.arc.radius.squared() {
+            return false;
+        }
+        // The sector is symmetric about the Y axis, so the angle from it is in [0, π]
+        ops::atan2(ops::abs(point.x), point.y) <= self.arc.half_angle
+    }
 }
 
 /// A primitive representing a circular segment:
@@ -543,6 +560,14 @@ impl CircularSegment {
     pub fn sagitta(&self) -> f32 {
         self.arc.sagitta()
     }
+
+    /// Checks if the given `point` is inside the segment or on its boundary.
+    ///
+    /// The point must be within the radius of the circle, and on the same side of the chord as the arc.
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.length_squared() <= self.arc.radius.squared() && point.y >= self.apothem()
+    }
 }
 
 #[cfg(test)]
@@ -768,6 +793,72 @@ mod arc_tests {
         tests.check_sector(CircularSector::from_degrees(1.0, 360.0));
         tests.check_segment(CircularSegment::from_degrees(1.0, 360.0));
     }
+
+    #[test]
+    fn sector_contains_point() {
+        for degrees in [60.0, 180.0, 270.0, 360.0] {
+            let sector = CircularSector::from_degrees(2.0, degrees);
+            assert!(sector.contains_point(Vec2::ZERO), "{degrees}°");
+            assert!(sector.contains_point(Vec2::new(0.0, 1.999)), "{degrees}°");
+            assert!(!sector.contains_point(Vec2::new(0.0, 2.001)), "{degrees}°");
+
+            // Just inside and outside of both ends of the arc
+            let arc = sector.arc;
+            for endpoint in arc.endpoints() {
+                assert!(!sector.contains_point(endpoint * 1.001), "{degrees}°");
+            }
+            for (rotation, inside) in [(0.001, true), (-0.001, false)] {
+                let right = Rot2::radians(rotation) * arc.right_endpoint() * 0.999;
+                let left = Rot2::radians(-rotation) * arc.left_endpoint() * 0.999;
+                // Rotating the ends outwards of a full circle wraps around into the sector
+                let expected = inside || degrees == 360.0;
+                assert_eq!(sector.contains_point(right), expected, "{degrees}°");
+                assert_eq!(sector.contains_point(left), expected, "{degrees}°");
+            }
+        }
+
+        // A sector with zero angle is a line segment along the Y axis
+        let sector = CircularSector::from_degrees(2.0, 0.0);
+        assert!(sector.contains_point(Vec2::ZERO));
+        assert!(sector.contains_point(Vec2::new(0.0, 1.0)));
+        assert!(!sector.contains_point(Vec2::new(0.001, 1.0)));
+        assert!(!sector.contains_point(Vec2::new(0.0, -1.0)));
+
+        // A reflex sector contains the points below the center, except for a wedge around -Y
+        let sector = CircularSector::from_degrees(1.0, 270.0);
+        assert!(sector.contains_point(Vec2::new(0.5, -0.4)));
+        assert!(sector.contains_point(Vec2::new(-0.5, -0.4)));
+        assert!(!sector.contains_point(Vec2::new(0.3, -0.5)));
+        assert!(!sector.contains_point(Vec2::new(0.0, -0.5)));
+    }
+
+    #[test]
+    fn segment_contains_point() {
+        for degrees in [60.0, 180.0, 270.0] {
+            let segment = CircularSegment::from_degrees(2.0, degrees);
+            assert!(segment.contains_point(Vec2::new(0.0, 1.999)), "{degrees}°");
+            assert!(!segment.contains_point(Vec2::new(0.0, 2.001)), "{degrees}°");
+
+            // Just inside and outside of the chord, next to both ends of the arc
+            for endpoint in segment.arc.endpoints() {
+                let inside = endpoint * Vec2::new(0.99, 1.0) + Vec2::new(0.0, 0.001);
+                let outside = endpoint * Vec2::new(0.99, 1.0) - Vec2::new(0.0, 0.001);
+                assert!(segment.contains_point(inside), "{degrees}°");
+                assert!(!segment.contains_point(outside), "{degrees}°");
+            }
+        }
+
+        // Unlike the sector, a minor segment doesn't contain the center of the circle
+        let segment = CircularSegment::from_degrees(1.0, 60.0);
+        assert!(!segment.contains_point(Vec2::ZERO));
+        assert!(CircularSector::from_degrees(1.0, 60.0).contains_point(Vec2::ZERO));
+
+        // A major segment contains the center, and everything above the chord
+        let segment = CircularSegment::from_degrees(1.0, 270.0);
+        assert!(segment.contains_point(Vec2::ZERO));
+        assert!(segment.contains_point(Vec2::new(0.0, -0.7)));
+        assert!(!segment.contains_point(Vec2::new(0.0, -0.8)));
+    }
 }
 
 /// An ellipse primitive, which is like a circle, but the width and height can be different
@@ -854,6 +945,54 @@ impl Ellipse {
     pub fn semi_minor(&self) -> f32 {
         self.half_size.min_element()
     }
+
+    /// Checks if the given `point` is inside the ellipse or on its boundary.
+    #[inline(always)]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        (point / self.half_size).length_squared() <= 1.0
+    }
+
+    /// Finds the point on the ellipse that is closest to the given `point`.
+    ///
+    /// If the point is outside the ellipse, the returned point will be on the perimeter of the ellipse.
+    /// Otherwise, it will be inside the ellipse and returned as is.
+    ///
+    /// Unlike for a circle, there is no closed-form solution, so the point is found iteratively.
+    /// The iteration is robust even for very elongated ellipses and for points near the ends
+    /// of the major axis, where the curvature is highest.
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        if self.contains_point(point) {
+            return point;
+        }
+
+        // By symmetry, it is enough to solve the problem in the first quadrant.
+        // The closest point is `a² * p / (a² + t)` for the unique `t >= 0` where it lies on the ellipse,
+        // so we find the root of `f(t) = |a * p / (a² + t)|² - 1` with Newton's method.
+        let p = point.abs();
+        let a = self.half_size;
+        let a_squared = a * a;
+        let ap = a * p;
+
+        // Each term of `f(t) + 1` is at most 1 at the root, which bounds it from below.
+        // Because `f` is convex and decreasing, Newton's method converges monotonically from the left.
+        let mut t = (ap - a_squared).max_element().max(0.0);
+        for _ in 0..32 {
+            let denom = a_squared + t;
+            let r = ap / denom;
+            let f = r.length_squared() - 1.0;
+            if f <= 0.0 {
+                break;
+            }
+            let df = -2.0 * (r * r / denom).element_sum();
+            let step = f / df;
+            t -= step;
+            if -step <= t * f32::EPSILON {
+                break;
+            }
+        }
+
+        (a_squared * p / (a_squared + t)).copysign(point)
+    }
 }
 
 impl Measured2d for Ellipse {
@@ -973,6 +1112,16 @@ impl Annulus {
         self.outer_circle.radius - self.inner_circle.radius
     }
 
+    /// Checks if the given `point` is inside the annulus, between its inner and outer circles.
+    ///
+    /// Points on either of the circles are considered inside.
+    #[inline(always)]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let distance_squared = point.length_squared();
+        self.inner_circle.radius.squared() <= distance_squared
+            && distance_squared <= self.outer_circle.radius.squared()
+    }
+
     /// Finds the point on the annulus that is closest to the given `point`:
     ///
     /// - If the point is outside of the annulus completely, the returned point will be on the outer perimeter.
@@ -1192,6 +1341,15 @@ impl Plane2d {
             normal: Dir2::new(normal).expect("normal must be nonzero and finite"),
         }
     }
+
+    /// Computes the signed distance from the given `point` to the plane placed at `plane_origin`.
+    ///
+    /// The result is positive if the point is on the side of the plane that the normal points towards,
+    /// zero if it is on the plane, and negative otherwise.
+    #[inline(always)]
+    pub fn signed_distance(&self, plane_origin: Vec2, point: Vec2) -> f32 {
+        self.normal.dot(point - plane_origin)
+    }
 }
 
 /// An infinite line going through the origin along a direction in 2D space.
@@ -1455,6 +1613,163 @@ impl Segment2d {
         segment.translated(offset_from_origin)
     }
 
+    /// Returns the point on the line segment that is closest to the given `point`.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let [start, end] = self.vertices;
+        let direction = end - start;
+        let length_squared = direction.length_squared();
+        if length_squared == 0.0 {
+            return start;
+        }
+        let t = ((point - start).dot(direction) / length_squared).clamp(0.0, 1.0);
+        start + direction * t
+    }
+
+    /// Returns the closest pair of points between this line segment and `other`,
+    /// with the first point on `self` and the second point on `other`.
+    ///
+    /// If the segments are parallel and overlap, there are infinitely many closest pairs.
+    /// In that case, the pair at the middle of the overlapping region is returned.
+    pub fn closest_points(&self, other: &Segment2d) -> (Vec2, Vec2) {
+        // Reference: Real-Time Collision Detection by Christer Ericson, section 5.1.9
+        let [p1, q1] = self.vertices;
+        let [p2, q2] = other.vertices;
+        let d1 = q1 - p1;
+        let d2 = q2 - p2;
+        let r = p1 - p2;
+        let a = d1.length_squared();
+        let e = d2.length_squared();
+        let f = d2.dot(r);
+
+        let (s, t) = if a == 0.0 && e == 0.0 {
+            // Both segments are points
+            (0.0, 0.0)
+        } else if a == 0.0 {
+            (0.0, (f / e).clamp(0.0, 1.0))
+        } else {
+            let c = d1.dot(r);
+            if e == 0.0 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else {
+                let b = d1.dot(d2);
+                let denom = a * e - b * b;
+                let s = if denom > f32::EPSILON * a * e {
+                    ((b * f - c * e) / denom).clamp(0.0, 1.0)
+                } else {
+                    // The segments are parallel. Pick the middle of the overlap of `other`
+                    // projected onto `self`, falling back to the start of `self` if there is none.
+                    let s0 = -c / a;
+                    let s1 = (b - c) / a;
+                    let start = s0.min(s1).max(0.0);
+                    let end = s0.max(s1).min(1.0);
+                    if start <= end {
+                        (start + end) * 0.5
+                    } else {
+                        0.0
+                    }
+                };
+                let t = (b * s + f) / e;
+                if t < 0.0 {
+                    ((-c / a).clamp(0.0, 1.0), 0.0)
+                } else if t > 1.0 {
+                    (((b - c) / a).clamp(0.0, 1.0), 1.0)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+
+        (p1 + d1 * s, p2 + d2 * t)
+    }
+
+    /// Returns the shortest distance between this line segment and `other`.
+    ///
+    /// This is zero if the segments intersect.
+    #[inline]
+    pub fn distance_to_segment(&self, other: &Segment2d) -> f32 {
+        let (a, b) = self.closest_points(other);
+        a.distance(b)
+    }
+
+    /// Computes the intersection of this line segment and `other`, if any.
+    ///
+    /// Crossing or touching segments intersect at a [point](SegmentIntersection2d::Point).
+    /// Collinear segments that overlap intersect along the [overlapping segment](SegmentIntersection2d::Collinear),
+    /// which has the same direction as `self`, or at a [point](SegmentIntersection2d::Point) if they
+    /// only share an endpoint.
+    ///
+    /// Segments are treated as parallel or collinear within a small tolerance relative to their length,
+    /// so nearly collinear segments are reported as overlapping rather than crossing at a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{primitives::{Segment2d, SegmentIntersection2d}, Vec2};
+    /// let segment = Segment2d::new(Vec2::ZERO, Vec2::new(4.0, 0.0));
+    ///
+    /// let crossing = Segment2d::new(Vec2::new(1.0, -1.0), Vec2::new(1.0, 1.0));
+    /// assert_eq!(
+    ///     segment.intersect(&crossing),
+    ///     Some(SegmentIntersection2d::Point(Vec2::new(1.0, 0.0)))
+    /// );
+    ///
+    /// let overlapping = Segment2d::new(Vec2::new(6.0, 0.0), Vec2::new(2.0, 0.0));
+    /// assert_eq!(
+    ///     segment.intersect(&overlapping),
+    ///     Some(SegmentIntersection2d::Collinear(Segment2d::new(
+    ///         Vec2::new(2.0, 0.0),
+    ///         Vec2::new(4.0, 0.0)
+    ///     )))
+    /// );
+    /// ```
+    pub fn intersect(&self, other: &Segment2d) -> Option<SegmentIntersection2d> {
+        let [p1, q1] = self.vertices;
+        let [p2, q2] = other.vertices;
+        let d1 = q1 - p1;
+        let d2 = q2 - p2;
+        let r = p2 - p1;
+        let a = d1.length_squared();
+        let e = d2.length_squared();
+
+        if a == 0.0 || e == 0.0 {
+            // At least one of the segments is a point, which must be on the other segment
+            let (point, closest) = self.closest_points(other);
+            return (point.distance_squared(closest) <= f32::EPSILON * a.max(e))
+                .then_some(SegmentIntersection2d::Point(point));
+        }
+
+        let denom = d1.perp_dot(d2);
+        if denom.squared() > f32::EPSILON * a * e {
+            // The segments are not parallel, so their lines cross at a single point
+            let s = r.perp_dot(d2) / denom;
+            let t = r.perp_dot(d1) / denom;
+            return ((0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&t))
+                .then(|| SegmentIntersection2d::Point(p1 + d1 * s));
+        }
+
+        // The segments are parallel, and only intersect if they are on the same line
+        if r.perp_dot(d1).squared() > f32::EPSILON * a * a {
+            return None;
+        }
+
+        // Project `other` onto `self` and keep the part that overlaps it
+        let s0 = r.dot(d1) / a;
+        let s1 = (q2 - p1).dot(d1) / a;
+        let start = s0.min(s1).max(0.0);
+        let end = s0.max(s1).min(1.0);
+        if start < end {
+            Some(SegmentIntersection2d::Collinear(Segment2d::new(
+                p1 + d1 * start,
+                p1 + d1 * end,
+            )))
+        } else if start == end {
+            Some(SegmentIntersection2d::Point(p1 + d1 * start))
+        } else {
+            None
+        }
+    }
+
     /// Reverses the direction of the line segment by swapping the endpoints.
     #[inline(always)]
     pub fn reverse(&mut self) {
@@ -1485,6 +1800,25 @@ impl From<(Vec2, Vec2)> for Segment2d {
     }
 }
 
+/// The intersection of two [`Segment2d`]s, returned by [`Segment2d::intersect`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum SegmentIntersection2d {
+    /// The segments intersect at a single point.
+    Point(Vec2),
+    /// The segments are collinear and overlap along a segment.
+    Collinear(Segment2d),
+}
+
 /// A series of connected line segments in 2D space.
 ///
 /// For a version without generics: [`BoxedPolyline2d`]
@@ -1608,6 +1942,27 @@ impl Triangle2d {
         }
     }
 
+    /// Get the signed area of the triangle.
+    ///
+    /// The area is positive if the triangle has a [counterclockwise](WindingOrder::CounterClockwise)
+    /// winding order, negative if it is [clockwise](WindingOrder::Clockwise),
+    /// and zero if the vertices are collinear.
+    #[inline(always)]
+    pub fn signed_area(&self) -> f32 {
+        let [a, b, c] = self.vertices;
+        (b - a).perp_dot(c - a) / 2.0
+    }
+
+    /// Checks if the triangle has a [counterclockwise](WindingOrder::CounterClockwise) winding order.
+    ///
+    /// This is equivalent to checking if [`Triangle2d::winding_order`]
+    /// returns [`WindingOrder::CounterClockwise`].
+    #[inline(always)]
+    #[doc(alias = "is_counterclockwise")]
+    pub fn is_ccw(&self) -> bool {
+        self.winding_order() == WindingOrder::CounterClockwise
+    }
+
     /// Compute the circle passing through all three vertices of the triangle.
     /// The vector in the returned tuple is the circumcenter.
     pub fn circumcircle(&self) -> (Circle, Vec2) {
@@ -1691,6 +2046,64 @@ impl Triangle2d {
         side_lengths[0] + side_lengths[1] < side_lengths[2]
     }
 
+    /// Checks if the given `point` is inside the triangle or on its boundary.
+    ///
+    /// This works for triangles of either [`WindingOrder`].
+    /// Degenerate triangles, as determined by [`Triangle2d::is_degenerate`], contain no points.
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        if self.is_degenerate() {
+            return false;
+        }
+
+        let [a, b, c] = self.vertices;
+        let d0 = (b - a).perp_dot(point - a);
+        let d1 = (c - b).perp_dot(point - b);
+        let d2 = (a - c).perp_dot(point - c);
+
+        // The point is inside if it is on the same side of all three edges
+        let has_negative = d0 < 0.0 || d1 < 0.0 || d2 < 0.0;
+        let has_positive = d0 > 0.0 || d1 > 0.0 || d2 > 0.0;
+        !(has_negative && has_positive)
+    }
+
+    /// Computes the barycentric coordinates of the given `point` with respect to the triangle.
+    ///
+    /// The returned coordinates `(u, v, w)` are the weights of the vertices `a`, `b` and `c`,
+    /// such that `point == u * a + v * b + w * c` and `u + v + w == 1.0`.
+    /// All coordinates are within `[0.0, 1.0]` if and only if the point is inside the triangle.
+    ///
+    /// If the triangle is degenerate, as determined by [`Triangle2d::is_degenerate`],
+    /// the coordinates are undefined and `None` is returned.
+    #[inline]
+    pub fn try_barycentric(&self, point: Vec2) -> Option<Vec3> {
+        if self.is_degenerate() {
+            return None;
+        }
+
+        let [a, b, c] = self.vertices;
+        let ab = b - a;
+        let ac = c - a;
+        let ap = point - a;
+
+        let inv_denominator = ab.perp_dot(ac).recip();
+        let v = ap.perp_dot(ac) * inv_denominator;
+        let w = ab.perp_dot(ap) * inv_denominator;
+        Some(Vec3::new(1.0 - v - w, v, w))
+    }
+
+    /// Computes the barycentric coordinates of the given `point` with respect to the triangle.
+    ///
+    /// The returned coordinates `(u, v, w)` are the weights of the vertices `a`, `b` and `c`,
+    /// such that `point == u * a + v * b + w * c` and `u + v + w == 1.0`.
+    ///
+    /// If the triangle is degenerate, as determined by [`Triangle2d::is_degenerate`],
+    /// all coordinates are NaN. See [`Triangle2d::try_barycentric`] for a version returning an [`Option`].
+    #[inline]
+    pub fn barycentric(&self, point: Vec2) -> Vec3 {
+        self.try_barycentric(point).unwrap_or(Vec3::NAN)
+    }
+
     /// Reverse the [`WindingOrder`] of the triangle
     /// by swapping the first and last vertices.
     #[inline(always)]
@@ -1857,6 +2270,17 @@ impl<const N: usize> Polygon<N> {
         Self::from_iter(vertices)
     }
 
+    /// Get the signed area of the polygon, computed with the shoelace formula.
+    ///
+    /// The area is positive if the vertices are in [counterclockwise](WindingOrder::CounterClockwise)
+    /// order and negative if they are [clockwise](WindingOrder::Clockwise).
+    /// For polygons that are not [simple](Self::is_simple), the areas of the parts wound
+    /// in opposite directions cancel out.
+    #[inline]
+    pub fn signed_area(&self) -> f32 {
+        signed_polygon_area(&self.vertices)
+    }
+
     /// Tests if the polygon is simple.
     ///
     /// A polygon is simple if it is not self intersecting and not self tangent.
@@ -1867,6 +2291,21 @@ impl<const N: usize> Polygon<N> {
     }
 }
 
+/// Computes the signed area enclosed by the given `vertices` using the shoelace formula.
+#[inline]
+fn signed_polygon_area(vertices: &[Vec2]) -> f32 {
+    // Summing over a fan of triangles from the first vertex is the same as the shoelace formula,
+    // but keeps the terms small for polygons that are far from the origin.
+    let Some(&origin) = vertices.first() else {
+        return 0.0;
+    };
+    vertices
+        .windows(2)
+        .map(|edge| (edge[0] - origin).perp_dot(edge[1] - origin))
+        .sum::<f32>()
+        / 2.0
+}
+
 impl<const N: usize> From<ConvexPolygon<N>> for Polygon<N> {
     fn from(val: ConvexPolygon<N>) -> Self {
         Polygon {
@@ -1944,6 +2383,15 @@ impl<const N: usize> ConvexPolygon<N> {
     pub fn vertices(&self) -> &[Vec2; N] {
         &self.vertices
     }
+
+    /// Get the signed area of the polygon, computed with the shoelace formula.
+    ///
+    /// The area is positive if the vertices are in [counterclockwise](WindingOrder::CounterClockwise)
+    /// order and negative if they are [clockwise](WindingOrder::Clockwise).
+    #[inline]
+    pub fn signed_area(&self) -> f32 {
+        signed_polygon_area(&self.vertices)
+    }
 }
 
 impl<const N: usize> TryFrom<Polygon<N>> for ConvexPolygon<N> {
@@ -1986,6 +2434,17 @@ impl BoxedPolygon {
         Self::from_iter(vertices)
     }
 
+    /// Get the signed area of the polygon, computed with the shoelace formula.
+    ///
+    /// The area is positive if the vertices are in [counterclockwise](WindingOrder::CounterClockwise)
+    /// order and negative if they are [clockwise](WindingOrder::Clockwise).
+    /// For polygons that are not [simple](Self::is_simple), the areas of the parts wound
+    /// in opposite directions cancel out.
+    #[inline]
+    pub fn signed_area(&self) -> f32 {
+        signed_polygon_area(&self.vertices)
+    }
+
     /// Tests if the polygon is simple.
     ///
     /// A polygon is simple if it is not self intersecting and not self tangent.
@@ -2106,19 +2565,101 @@ impl RegularPolygon {
         2.0 * PI / self.sides as f32
     }
 
+    /// Get the signed area of the regular polygon.
+    ///
+    /// The [vertices](Self::vertices) of a regular polygon are always in
+    /// [counterclockwise](WindingOrder::CounterClockwise) order, so this is the same as its area.
+    /// It exists for consistency with [`Polygon::signed_area`].
+    #[inline(always)]
+    pub fn signed_area(&self) -> f32 {
+        self.area()
+    }
+
+    /// Checks if the regular polygon, transformed by the given `isometry`,
+    /// intersects the given `circle`.
+    ///
+    /// Unlike testing against the [bounding circle](crate::bounding::Bounded2d::bounding_circle)
+    /// of the polygon, this is exact: the circle intersects the polygon if its center
+    /// is inside the polygon, or if any edge of the polygon is within its radius.
+    /// Touching counts as intersecting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{bounding::BoundingCircle, primitives::RegularPolygon, Isometry2d, Vec2};
+    /// let hexagon = RegularPolygon::new(1.0, 6);
+    ///
+    /// // The circle is inside the bounding circle of the hexagon, but misses the hexagon itself
+    /// let circle = BoundingCircle::new(Vec2::new(0.95, 0.0), 0.05);
+    /// assert!(!hexagon.intersects_circle(Isometry2d::IDENTITY, &circle));
+    ///
+    /// // Rotating the hexagon puts a vertex inside the circle
+    /// let rotated = Isometry2d::from_rotation(core::f32::consts::FRAC_PI_6.into());
+    /// assert!(hexagon.intersects_circle(rotated, &circle));
+    /// ```
+    pub fn intersects_circle(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        circle: &BoundingCircle,
+    ) -> bool {
+        let center = isometry.into().inverse_transform_point(circle.center);
+        let radius = circle.radius();
+        let distance_squared = center.length_squared();
+
+        // The polygon is contained in its circumcircle and contains its incircle
+        if distance_squared > (self.circumradius() + radius).squared() {
+            return false;
+        }
+        if distance_squared <= (self.inradius() + radius).squared() {
+            return true;
+        }
+
+        let mut vertices = self.vertices(0.0);
+        let Some(first) = vertices.next() else {
+            return false;
+        };
+        let mut start = first;
+        let mut inside = true;
+        for end in vertices.chain(core::iter::once(first)) {
+            let edge = Segment2d::new(start, end);
+            if edge.closest_point(center).distance_squared(center) <= radius.squared() {
+                return true;
+            }
+            // The vertices are counterclockwise, so the inside is to the left of every edge
+            inside &= (end - start).perp_dot(center - start) >= 0.0;
+            start = end;
+        }
+        inside
+    }
+
     /// Returns an iterator over the vertices of the regular polygon,
     /// rotated counterclockwise by the given angle in radians.
     ///
     /// With a rotation of 0, a vertex will be placed at the top `(0.0, circumradius)`.
-    pub fn vertices(self, rotation: f32) -> impl IntoIterator<Item = Vec2> {
+    ///
+    /// The vertices are computed lazily without allocating. Each vertex is obtained by rotating
+    /// the previous one by the external angle, and only every 16th vertex is computed with a
+    /// sine/cosine evaluation, so that rounding errors don't build up with the number of sides.
+    ///
+    /// The iterator copies what it needs from the polygon instead of borrowing it,
+    /// so it can outlive the polygon.
+    pub fn vertices(&self, rotation: f32) -> impl Iterator<Item = Vec2> + use<> {
         // Add pi/2 so that the polygon has a vertex at the top (sin is 1.0 and cos is 0.0)
-        let start_angle = rotation + FRAC_PI_2;
-        let step = core::f32::consts::TAU / self.sides as f32;
+        let start = rotation + FRAC_PI_2;
+        let step_angle = self.external_angle_radians();
+        let (step_sin, step_cos) = ops::sin_cos(step_angle);
+        let step = Vec2::new(step_cos, step_sin);
+        let radius = self.circumcircle.radius;
 
+        let mut direction = Vec2::ZERO;
         (0..self.sides).map(move |i| {
-            let theta = start_angle + i as f32 * step;
-            let (sin, cos) = ops::sin_cos(theta);
-            Vec2::new(cos, sin) * self.circumcircle.radius
+            direction = if i % 16 == 0 {
+                let (sin, cos) = ops::sin_cos(start + i as f32 * step_angle);
+                Vec2::new(cos, sin)
+            } else {
+                step.rotate(direction)
+            };
+            direction * radius
         })
     }
 }
@@ -2187,6 +2728,53 @@ impl Capsule2d {
     pub fn to_inner_rectangle(&self) -> Rectangle {
         Rectangle::new(self.radius * 2.0, self.half_length * 2.0)
     }
+
+    /// Get the line segment connecting the centers of the semicircular ends of the capsule,
+    /// pointing in the positive Y direction.
+    ///
+    /// The capsule is the set of points within a distance of [`radius`](Self::radius) from this segment.
+    #[inline]
+    pub fn to_segment(&self) -> Segment2d {
+        Segment2d::new(
+            Vec2::new(0.0, -self.half_length),
+            Vec2::new(0.0, self.half_length),
+        )
+    }
+
+    /// Get `n` points on the boundary of the capsule, evenly spaced along its perimeter.
+    ///
+    /// The points are in counterclockwise order, starting from the bottom of the right-hand side
+    /// at `(radius, -half_length)`, which makes them suitable for building a polygon or mesh
+    /// with a [counterclockwise](WindingOrder::CounterClockwise) winding order.
+    #[cfg(feature = "alloc")]
+    pub fn sample_boundary(&self, n: usize) -> Vec<Vec2> {
+        let straight = 2.0 * self.half_length;
+        let arc = PI * self.radius;
+        let perimeter = 2.0 * (straight + arc);
+
+        (0..n)
+            .map(|i| {
+                let distance = perimeter * i as f32 / n as f32;
+                if distance < straight {
+                    // Right-hand side, going up
+                    Vec2::new(self.radius, distance - self.half_length)
+                } else if distance < straight + arc {
+                    // Top semicircle, going counterclockwise from the right
+                    let angle = (distance - straight) / self.radius;
+                    let (sin, cos) = ops::sin_cos(angle);
+                    Vec2::new(cos, sin) * self.radius + Vec2::new(0.0, self.half_length)
+                } else if distance < 2.0 * straight + arc {
+                    // Left-hand side, going down
+                    Vec2::new(-self.radius, self.half_length - (distance - straight - arc))
+                } else {
+                    // Bottom semicircle, going counterclockwise from the left
+                    let angle = PI + (distance - 2.0 * straight - arc) / self.radius;
+                    let (sin, cos) = ops::sin_cos(angle);
+                    Vec2::new(cos, sin) * self.radius - Vec2::new(0.0, self.half_length)
+                }
+            })
+            .collect()
+    }
 }
 
 impl Measured2d for Capsule2d {
@@ -2212,6 +2800,213 @@ mod tests {
     use super::*;
     use approx::{assert_abs_diff_eq, assert_relative_eq};
 
+    #[test]
+    fn segment_closest_point() {
+        let segment = Segment2d::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0));
+        assert_eq!(
+            segment.closest_point(Vec2::new(0.5, 2.0)),
+            Vec2::new(0.5, 0.0)
+        );
+        assert_eq!(
+            segment.closest_point(Vec2::new(3.0, -1.0)),
+            Vec2::new(1.0, 0.0)
+        );
+        assert_eq!(
+            segment.closest_point(Vec2::new(-3.0, 1.0)),
+            Vec2::new(-1.0, 0.0)
+        );
+
+        let point = Segment2d::new(Vec2::ONE, Vec2::ONE);
+        assert_eq!(point.closest_point(Vec2::ZERO), Vec2::ONE);
+    }
+
+    #[test]
+    fn segment_closest_points() {
+        let segment = Segment2d::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0));
+
+        // Crossing segments
+        let crossing = Segment2d::new(Vec2::new(0.5, -1.0), Vec2::new(0.5, 1.0));
+        let (a, b) = segment.closest_points(&crossing);
+        assert_relative_eq!(a, Vec2::new(0.5, 0.0));
+        assert_relative_eq!(b, Vec2::new(0.5, 0.0));
+        assert_eq!(segment.distance_to_segment(&crossing), 0.0);
+
+        // Skewed segments where an endpoint of one is closest to the interior of the other
+        let skewed = Segment2d::new(Vec2::new(0.0, 1.0), Vec2::new(2.0, 3.0));
+        let (a, b) = segment.closest_points(&skewed);
+        assert_relative_eq!(a, Vec2::new(0.0, 0.0));
+        assert_relative_eq!(b, Vec2::new(0.0, 1.0));
+        assert_relative_eq!(segment.distance_to_segment(&skewed), 1.0);
+
+        // Endpoint to endpoint
+        let beyond = Segment2d::new(Vec2::new(2.0, 1.0), Vec2::new(4.0, 1.0));
+        let (a, b) = segment.closest_points(&beyond);
+        assert_relative_eq!(a, Vec2::new(1.0, 0.0));
+        assert_relative_eq!(b, Vec2::new(2.0, 1.0));
+        assert_relative_eq!(
+            segment.distance_to_segment(&beyond),
+            core::f32::consts::SQRT_2
+        );
+
+        // Parallel and overlapping segments return the middle of the overlap,
+        // regardless of the direction of either segment
+        let parallel = Segment2d::new(Vec2::new(0.0, 2.0), Vec2::new(3.0, 2.0));
+        for (s1, s2) in [
+            (segment, parallel),
+            (segment.reversed(), parallel),
+            (segment, parallel.reversed()),
+        ] {
+            let (a, b) = s1.closest_points(&s2);
+            assert_relative_eq!(a, Vec2::new(0.5, 0.0));
+            assert_relative_eq!(b, Vec2::new(0.5, 2.0));
+            assert_relative_eq!(s1.distance_to_segment(&s2), 2.0);
+        }
+
+        // Collinear and overlapping
+        let collinear = Segment2d::new(Vec2::new(-3.0, 0.0), Vec2::new(0.0, 0.0));
+        let (a, b) = segment.closest_points(&collinear);
+        assert_relative_eq!(a, Vec2::new(-0.5, 0.0));
+        assert_relative_eq!(b, Vec2::new(-0.5, 0.0));
+
+        // Collinear and disjoint
+        let disjoint = Segment2d::new(Vec2::new(5.0, 0.0), Vec2::new(3.0, 0.0));
+        let (a, b) = segment.closest_points(&disjoint);
+        assert_relative_eq!(a, Vec2::new(1.0, 0.0));
+        assert_relative_eq!(b, Vec2::new(3.0, 0.0));
+
+        // Degenerate segments
+        let point = Segment2d::new(Vec2::new(0.25, 1.0), Vec2::new(0.25, 1.0));
+        let (a, b) = segment.closest_points(&point);
+        assert_relative_eq!(a, Vec2::new(0.25, 0.0));
+        assert_relative_eq!(b, Vec2::new(0.25, 1.0));
+        let (a, b) = point.closest_points(&segment);
+        assert_relative_eq!(a, Vec2::new(0.25, 1.0));
+        assert_relative_eq!(b, Vec2::new(0.25, 0.0));
+        assert_relative_eq!(point.distance_to_segment(&point), 0.0);
+    }
+
+    #[test]
+    fn segment_intersect() {
+        let segment = Segment2d::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0));
+        let point = |x, y| Some(SegmentIntersection2d::Point(Vec2::new(x, y)));
+        let collinear = |x1, x2| {
+            Some(SegmentIntersection2d::Collinear(Segment2d::new(
+                Vec2::new(x1, 0.0),
+                Vec2::new(x2, 0.0),
+            )))
+        };
+
+        for (test, other, expected) in [
+            (
+                "crossing",
+                Segment2d::new(Vec2::new(0.5, -1.0), Vec2::new(0.5, 1.0)),
+                point(0.5, 0.0),
+            ),
+            (
+                "touching at an endpoint",
+                Segment2d::new(Vec2::new(1.0, 0.0), Vec2::new(2.0, 3.0)),
+                point(1.0, 0.0),
+            ),
+            (
+                "touching the interior",
+                Segment2d::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 2.0)),
+                point(0.0, 0.0),
+            ),
+            (
+                "lines crossing outside the segments",
+                Segment2d::new(Vec2::new(2.0, -1.0), Vec2::new(2.0, 1.0)),
+                None,
+            ),
+            (
+                "parallel",
+                Segment2d::new(Vec2::new(-1.0, 1.0), Vec2::new(1.0, 1.0)),
+                None,
+            ),
+            (
+                "collinear and overlapping",
+                Segment2d::new(Vec2::new(-3.0, 0.0), Vec2::new(0.0, 0.0)),
+                collinear(-1.0, 0.0),
+            ),
+            (
+                "collinear and reversed",
+                Segment2d::new(Vec2::new(0.5, 0.0), Vec2::new(-0.5, 0.0)),
+                collinear(-0.5, 0.5),
+            ),
+            (
+                "collinear and containing",
+                Segment2d::new(Vec2::new(3.0, 0.0), Vec2::new(-3.0, 0.0)),
+                collinear(-1.0, 1.0),
+            ),
+            (
+                "collinear and sharing an endpoint",
+                Segment2d::new(Vec2::new(1.0, 0.0), Vec2::new(3.0, 0.0)),
+                point(1.0, 0.0),
+            ),
+            (
+                "collinear and disjoint",
+                Segment2d::new(Vec2::new(5.0, 0.0), Vec2::new(3.0, 0.0)),
+                None,
+            ),
+            (
+                "a point on the segment",
+                Segment2d::new(Vec2::new(0.25, 0.0), Vec2::new(0.25, 0.0)),
+                point(0.25, 0.0),
+            ),
+            (
+                "a point off the segment",
+                Segment2d::new(Vec2::new(0.25, 1.0), Vec2::new(0.25, 1.0)),
+                None,
+            ),
+        ] {
+            assert_eq!(
+                segment.intersect(&other),
+                expected,
+                "Case:\n  Test: {test:?}"
+            );
+        }
+
+        // Crossing segments intersect at the same point in both orders
+        let skewed = Segment2d::new(Vec2::new(-1.0, -1.0), Vec2::new(2.0, 2.0));
+        let Some(SegmentIntersection2d::Point(a)) = segment.intersect(&skewed) else {
+            panic!("the segments should cross");
+        };
+        let Some(SegmentIntersection2d::Point(b)) = skewed.intersect(&segment) else {
+            panic!("the segments should cross");
+        };
+        assert_relative_eq!(a, Vec2::ZERO);
+        assert_relative_eq!(b, Vec2::ZERO);
+
+        // The overlap follows the direction of `self`
+        assert_eq!(
+            segment.reversed().intersect(&segment),
+            Some(SegmentIntersection2d::Collinear(segment.reversed()))
+        );
+
+        // Points only intersect the same point
+        let single = Segment2d::new(Vec2::ONE, Vec2::ONE);
+        assert_eq!(
+            single.intersect(&single),
+            Some(SegmentIntersection2d::Point(Vec2::ONE))
+        );
+        assert_eq!(
+            single.intersect(&Segment2d::new(Vec2::ZERO, Vec2::ZERO)),
+            None
+        );
+    }
+
+    #[test]
+    fn plane_signed_distance() {
+        let plane = Plane2d::new(Vec2::new(1.0, 1.0));
+        let origin = Vec2::new(1.0, 0.0);
+        assert_eq!(plane.signed_distance(origin, origin), 0.0);
+        assert_eq!(plane.signed_distance(origin, Vec2::new(0.0, 1.0)), 0.0);
+        assert_relative_eq!(
+            plane.signed_distance(origin, Vec2::new(2.0, 1.0)),
+            core::f32::consts::SQRT_2
+        );
+        assert_relative_eq!(plane.signed_distance(origin, Vec2::ZERO), -FRAC_1_SQRT_2);
+    }
+
     #[test]
     fn rectangle_closest_point() {
         let rectangle = Rectangle::new(2.0, 2.0);
@@ -2288,6 +3083,43 @@ mod tests {
         );
         assert_eq!(capsule.area(), 48.566371, "incorrect area");
         assert_eq!(capsule.perimeter(), 30.566371, "incorrect perimeter");
+        assert_eq!(
+            capsule.to_segment(),
+            Segment2d::new(Vec2::new(0.0, -4.5), Vec2::new(0.0, 4.5)),
+            "segment wasn't created correctly from a capsule"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn capsule_sample_boundary() {
+        let capsule = Capsule2d::new(1.0, 2.0);
+        assert!(capsule.sample_boundary(0).is_empty());
+
+        let points = capsule.sample_boundary(64);
+        assert_eq!(points.len(), 64);
+        assert_eq!(points[0], Vec2::new(1.0, -1.0));
+
+        let segment = capsule.to_segment();
+        let spacing = capsule.perimeter() / 64.0;
+        for (i, point) in points.iter().enumerate() {
+            // Every point is on the boundary
+            let closest = point.clamp(segment.point1(), segment.point2());
+            assert_relative_eq!(point.distance(closest), 1.0, epsilon = 1e-5);
+
+            // Consecutive points are evenly spaced, up to the curvature of the arcs
+            let next = points[(i + 1) % points.len()];
+            let distance = point.distance(next);
+            assert!(distance <= spacing + 1e-5 && distance > 0.99 * spacing);
+        }
+
+        // The points are in counterclockwise order, so their signed area is positive
+        let signed_area = (0..points.len())
+            .map(|i| points[i].perp_dot(points[(i + 1) % points.len()]))
+            .sum::<f32>()
+            / 2.0;
+        assert!(signed_area > 0.0);
+        assert_relative_eq!(signed_area, capsule.area(), max_relative = 0.01);
     }
 
     #[test]
@@ -2335,6 +3167,112 @@ mod tests {
         assert_eq!(circle.eccentricity(), 0., "incorrect circle eccentricity");
     }
 
+    #[test]
+    fn annulus_contains_point() {
+        let annulus = Annulus::new(1.0, 2.0);
+        assert!(annulus.contains_point(Vec2::new(1.5, 0.0)));
+        assert!(annulus.contains_point(Vec2::new(0.0, -1.0)));
+        assert!(annulus.contains_point(Vec2::new(-2.0, 0.0)));
+        assert!(!annulus.contains_point(Vec2::ZERO));
+        assert!(!annulus.contains_point(Vec2::new(0.5, 0.5)));
+        assert!(!annulus.contains_point(Vec2::new(1.5, 1.5)));
+    }
+
+    #[test]
+    fn ellipse_contains_point() {
+        let ellipse = Ellipse::new(3.0, 1.0);
+        assert!(ellipse.contains_point(Vec2::ZERO));
+        assert!(ellipse.contains_point(Vec2::new(-3.0, 0.0)));
+        assert!(ellipse.contains_point(Vec2::new(2.0, 0.5)));
+        assert!(!ellipse.contains_point(Vec2::new(2.0, 1.0)));
+        assert!(!ellipse.contains_point(Vec2::new(0.0, -1.1)));
+    }
+
+    #[test]
+    fn ellipse_closest_point() {
+        // Inside points are returned as is
+        let ellipse = Ellipse::new(3.0, 1.0);
+        assert_eq!(ellipse.closest_point(Vec2::ZERO), Vec2::ZERO);
+        assert_eq!(
+            ellipse.closest_point(Vec2::new(-2.0, 0.5)),
+            Vec2::new(-2.0, 0.5)
+        );
+        assert_eq!(
+            ellipse.closest_point(Vec2::new(-5.0, 0.0)),
+            Vec2::new(-3.0, 0.0)
+        );
+        assert_eq!(
+            ellipse.closest_point(Vec2::new(0.0, 4.0)),
+            Vec2::new(0.0, 1.0)
+        );
+
+        for ellipse in [
+            Ellipse::new(3.0, 1.0),
+            Ellipse::new(0.5, 2.0),
+            Ellipse::new(10.0, 0.01),
+        ] {
+            let a = ellipse.half_size;
+            // Move outwards from points on the boundary along the normal,
+            // including near the ends of the major axis, where the curvature is highest.
+            for angle in [
+                0.0,
+                1e-4,
+                1e-3,
+                0.01,
+                0.1,
+                0.5,
+                1.0,
+                FRAC_PI_2 - 1e-3,
+                2.0,
+                4.0,
+            ] {
+                let (sin, cos) = ops::sin_cos(angle);
+                let boundary = a * Vec2::new(cos, sin);
+                let normal = (boundary / (a * a)).normalize();
+                for distance in [1e-3, 0.1, 1.0, 100.0] {
+                    let point = boundary + normal * distance;
+                    let closest = ellipse.closest_point(point);
+                    assert!(
+                        closest.abs_diff_eq(boundary, 1e-4 * (1.0 + distance)),
+                        "Case:
+  Ellipse: {ellipse:?}
+  Angle: {angle}
+  Distance: {distance}
+  Closest: {closest}
+  Expected: {boundary}",
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn ellipse_closest_point_brute_force() {
+        let ellipse = Ellipse::new(4.0, 0.25);
+        let samples: Vec<Vec2> = (0..10_000)
+            .map(|i| {
+                let (sin, cos) = ops::sin_cos(i as f32 / 10_000.0 * 2.0 * PI);
+                ellipse.half_size * Vec2::new(cos, sin)
+            })
+            .collect();
+        for point in [
+            Vec2::new(4.01, 0.001),
+            Vec2::new(4.5, 0.2),
+            Vec2::new(-3.9, 0.3),
+            Vec2::new(1.0, -2.0),
+            Vec2::new(0.0, 0.26),
+        ] {
+            let closest = ellipse.closest_point(point);
+            assert_relative_eq!((closest / ellipse.half_size).length(), 1.0, epsilon = 1e-5);
+            let brute_force = samples
+                .iter()
+                .map(|sample| sample.distance(point))
+                .fold(f32::INFINITY, f32::min);
+            assert!(closest.distance(point) <= brute_force + 1e-5);
+        }
+    }
+
     #[test]
     fn ellipse_perimeter() {
         let circle = Ellipse::new(1., 1.);
@@ -2375,6 +3313,69 @@ mod tests {
         assert!(obtuse_triangle.is_obtuse());
     }
 
+    #[test]
+    fn triangle_contains_point() {
+        let triangle = Triangle2d::new(
+            Vec2::new(-2.0, -1.0),
+            Vec2::new(7.0, 0.0),
+            Vec2::new(1.0, 4.0),
+        );
+
+        // The result doesn't depend on the winding order
+        for triangle in [triangle, triangle.reversed()] {
+            assert!(triangle.contains_point(Vec2::new(1.0, 1.0)));
+            assert!(triangle.contains_point(Vec2::new(-2.0, -1.0)));
+            assert!(triangle.contains_point(Vec2::new(4.0, 2.0)));
+            assert!(!triangle.contains_point(Vec2::new(-2.0, 1.0)));
+            assert!(!triangle.contains_point(Vec2::new(5.0, 3.0)));
+            assert!(!triangle.contains_point(Vec2::new(1.0, -2.0)));
+        }
+
+        let degenerate_triangle =
+            Triangle2d::new(Vec2::new(-1., 0.), Vec2::new(0., 0.), Vec2::new(1., 0.));
+        assert!(!degenerate_triangle.contains_point(Vec2::ZERO));
+        assert!(!degenerate_triangle.contains_point(Vec2::new(5.0, 0.0)));
+    }
+
+    #[test]
+    fn triangle_barycentric() {
+        let triangle = Triangle2d::new(
+            Vec2::new(-2.0, -1.0),
+            Vec2::new(7.0, 0.0),
+            Vec2::new(1.0, 4.0),
+        );
+        let [a, b, c] = triangle.vertices;
+
+        assert_eq!(triangle.barycentric(a), Vec3::X);
+        assert_eq!(triangle.barycentric(b), Vec3::Y);
+        assert_eq!(triangle.barycentric(c), Vec3::Z);
+
+        for point in [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(4.0, 2.0),
+            Vec2::new(-3.0, 5.0),
+            Vec2::new(10.0, -4.0),
+        ] {
+            let weights = triangle.barycentric(point);
+            assert_relative_eq!(weights.x + weights.y + weights.z, 1.0);
+            assert_relative_eq!(
+                weights.x * a + weights.y * b + weights.z * c,
+                point,
+                epsilon = 1e-5
+            );
+            assert_eq!(
+                weights.cmpge(Vec3::ZERO).all(),
+                triangle.contains_point(point),
+                "point {point} has weights {weights}"
+            );
+        }
+
+        let degenerate_triangle =
+            Triangle2d::new(Vec2::new(-1., 0.), Vec2::new(0., 0.), Vec2::new(1., 0.));
+        assert_eq!(degenerate_triangle.try_barycentric(Vec2::ZERO), None);
+        assert!(degenerate_triangle.barycentric(Vec2::ZERO).is_nan());
+    }
+
     #[test]
     fn triangle_winding_order() {
         let mut cw_triangle = Triangle2d::new(
@@ -2404,6 +3405,82 @@ mod tests {
         assert_eq!(invalid_triangle.winding_order(), WindingOrder::Invalid);
     }
 
+    #[test]
+    fn triangle_signed_area() {
+        let ccw_triangle = Triangle2d::new(
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(2.0, -1.0),
+            Vec2::new(0.0, 3.0),
+        );
+        assert_eq!(ccw_triangle.signed_area(), 6.0);
+        assert!(ccw_triangle.is_ccw());
+
+        let cw_triangle = ccw_triangle.reversed();
+        assert_eq!(cw_triangle.signed_area(), -6.0);
+        assert!(!cw_triangle.is_ccw());
+        assert_eq!(cw_triangle.winding_order(), WindingOrder::Clockwise);
+        assert_eq!(ops::abs(cw_triangle.signed_area()), cw_triangle.area());
+
+        // Collinear vertices have no area
+        let degenerate_triangle = Triangle2d::new(
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(0.5, 0.5),
+            Vec2::new(3.0, 3.0),
+        );
+        assert_abs_diff_eq!(degenerate_triangle.signed_area(), 0.0);
+        assert!(!degenerate_triangle.is_ccw());
+    }
+
+    #[test]
+    fn polygon_signed_area() {
+        // An L shape, counterclockwise
+        let vertices = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        let polygon = Polygon { vertices };
+        assert_eq!(polygon.signed_area(), 3.0);
+
+        let mut reversed_vertices = vertices;
+        reversed_vertices.reverse();
+        assert_eq!(Polygon::<6>::new(reversed_vertices).signed_area(), -3.0);
+        #[cfg(feature = "alloc")]
+        assert_eq!(BoxedPolygon::new(vertices).signed_area(), 3.0);
+
+        // The area doesn't depend on the position of the polygon
+        let far_square = Polygon::<4>::new(
+            [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y].map(|vertex| vertex + Vec2::splat(1e4)),
+        );
+        assert_eq!(far_square.signed_area(), 1.0);
+
+        let convex = ConvexPolygon::new([Vec2::ZERO, Vec2::Y, Vec2::X]).unwrap();
+        assert_eq!(convex.signed_area(), -0.5);
+
+        // Collinear vertices have no area
+        let degenerate = Polygon::<4>::new([
+            Vec2::ZERO,
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.5, 2.5),
+            Vec2::ONE,
+        ]);
+        assert_abs_diff_eq!(degenerate.signed_area(), 0.0);
+        assert_eq!(Polygon::<0>::new([]).signed_area(), 0.0);
+
+        // The vertices of a regular polygon are counterclockwise
+        let regular = RegularPolygon::new(3.0, 7);
+        let vertices: Polygon<7> = regular.vertices(0.4).collect();
+        assert!(regular.signed_area() > 0.0);
+        assert_relative_eq!(
+            vertices.signed_area(),
+            regular.signed_area(),
+            epsilon = 1e-4
+        );
+    }
+
     #[test]
     fn rectangle_math() {
         let rectangle = Rectangle::new(3.0, 7.0);
@@ -2444,6 +3521,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn regular_polygon_intersects_circle() {
+        let hexagon = RegularPolygon::new(2.0, 6);
+        let isometry = Isometry2d::new(Vec2::new(1.0, -1.0), Rot2::degrees(10.0));
+        for (test, center, radius, expected) in &[
+            ("far away", Vec2::new(10.0, 0.0), 1.0, false),
+            ("overlapping the center", Vec2::ZERO, 0.5, true),
+            // No edge is within the radius
+            ("entirely inside", Vec2::new(0.3, 0.6), 0.2, true),
+            ("containing the polygon", Vec2::ZERO, 5.0, true),
+            // Outside the hexagon, but inside its circumcircle
+            ("near an edge", Vec2::new(1.9, 0.0), 0.1, false),
+            ("crossing an edge", Vec2::new(1.9, 0.0), 0.25, true),
+            ("touching a vertex", Vec2::new(0.0, 2.5), 0.5, true),
+            ("near a vertex", Vec2::new(0.0, 2.5), 0.45, false),
+        ] {
+            // The center is given in the local space of the polygon
+            let circle = BoundingCircle::new(isometry.transform_point(*center), *radius);
+            assert_eq!(
+                hexagon.intersects_circle(isometry, &circle),
+                *expected,
+                "Case:\n  Test: {test:?}",
+            );
+        }
+    }
+
     #[test]
     fn triangle_circumcenter() {
         let triangle = Triangle2d::new(
@@ -2463,11 +3566,11 @@ mod tests {
         let polygon = RegularPolygon::new(1.0, 4);
 
         // Regular polygons have a vertex at the top by default
-        let mut vertices = polygon.vertices(0.0).into_iter();
+        let mut vertices = polygon.vertices(0.0);
         assert!((vertices.next().unwrap() - Vec2::Y).length() < 1e-7);
 
         // Rotate by 45 degrees, forming an axis-aligned square
-        let mut rotated_vertices = polygon.vertices(core::f32::consts::FRAC_PI_4).into_iter();
+        let mut rotated_vertices = polygon.vertices(core::f32::consts::FRAC_PI_4);
 
         // Distance from the origin to the middle of a side, derived using Pythagorean theorem
         let side_distance = FRAC_1_SQRT_2;
@@ -2475,5 +3578,36 @@ mod tests {
             (rotated_vertices.next().unwrap() - Vec2::new(-side_distance, side_distance)).length()
                 < 1e-7,
         );
+
+        // The incrementally rotated vertices match the ones computed directly from their angles
+        let polygon = RegularPolygon::new(2.5, 37);
+        let rotation = 0.3;
+        assert_eq!(polygon.vertices(rotation).count(), 37);
+        for (i, vertex) in polygon.vertices(rotation).enumerate() {
+            let theta = rotation + FRAC_PI_2 + i as f32 * polygon.external_angle_radians();
+            let (sin, cos) = ops::sin_cos(theta);
+            let expected = Vec2::new(cos, sin) * polygon.circumradius();
+            assert!(
+                (vertex - expected).length() < 1e-5,
+                "vertex {i}: {vertex} != {expected}"
+            );
+        }
+
+        // The iterator doesn't borrow the polygon, so it can outlive a temporary
+        fn hexagon_vertices(radius: f32) -> impl Iterator<Item = Vec2> {
+            RegularPolygon::new(radius, 6).vertices(0.0)
+        }
+        assert_eq!(hexagon_vertices(1.0).count(), 6);
+        let first = RegularPolygon::new(2.0, 6).vertices(0.0).next().unwrap();
+        assert!((first - Vec2::Y * 2.0).length() < 1e-6);
+
+        // Rounding errors don't build up with a large number of sides
+        let polygon = RegularPolygon::new(1.0, 100_000);
+        let last = polygon.vertices(0.0).last().unwrap();
+        let (sin, cos) = ops::sin_cos(FRAC_PI_2 - polygon.external_angle_radians());
+        assert!((last - Vec2::new(cos, sin)).length() < 1e-5, "{last}");
+        assert!(polygon
+            .vertices(0.0)
+            .all(|vertex| ops::abs(vertex.length() - 1.0) < 1e-5));
     }
 }
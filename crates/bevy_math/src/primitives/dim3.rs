@@ -16,7 +16,9 @@ use glam::Quat;
 use alloc::{boxed::Box, vec::Vec};
 
 /// A sphere primitive, representing the set of all points some distance from the origin
+#[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "bevy_reflect",
@@ -785,6 +787,42 @@ impl Cylinder {
     pub fn base_area(&self) -> f32 {
         PI * self.radius.squared()
     }
+
+    /// Finds the point on the cylinder that is closest to the given `point`.
+    ///
+    /// If the point is outside the cylinder, the returned point will be on the surface of the cylinder.
+    /// Otherwise, it will be inside the cylinder and returned as is.
+    #[inline(always)]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        // The cylinder is a disk in the XZ plane extruded along the Y axis,
+        // so the closest points in those two parts can be found independently.
+        let xz = Vec2::new(point.x, point.z);
+        let distance_squared = xz.length_squared();
+        let xz = if distance_squared > self.radius.squared() {
+            xz * (self.radius / ops::sqrt(distance_squared))
+        } else {
+            xz
+        };
+        let y = point.y.clamp(-self.half_height, self.half_height);
+        Vec3::new(xz.x, y, xz.y)
+    }
+
+    /// Computes the signed distance from the given `point` to the surface of the cylinder.
+    ///
+    /// The result is negative inside the cylinder, where it is the distance to the nearest of
+    /// the side and the two caps, and positive outside.
+    #[doc(alias = "sdf")]
+    #[inline(always)]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        // The distances from the side and from the caps
+        let d = Vec2::new(
+            ops::hypot(point.x, point.z) - self.radius,
+            ops::abs(point.y) - self.half_height,
+        );
+        let outside = d.max(Vec2::ZERO).length();
+        let inside = d.max_element().min(0.0);
+        outside + inside
+    }
 }
 
 impl Measured3d for Cylinder {
@@ -1099,6 +1137,20 @@ impl Torus {
             core::cmp::Ordering::Less => TorusKind::Spindle,
         }
     }
+
+    /// Computes the signed distance from the given `point` to the surface of the torus,
+    /// which lies in the XZ plane.
+    ///
+    /// The result is negative inside the tube of the torus, and positive outside.
+    /// For a [spindle torus](TorusKind::Spindle), this is the distance to the surface swept by the tube,
+    /// including its self-intersecting inner part.
+    #[doc(alias("sdf", "distance"))]
+    #[inline(always)]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        // The distance from the circle running through the center of the tube, minus the radius of the tube
+        let radial = ops::hypot(point.x, point.z) - self.major_radius;
+        ops::hypot(radial, point.y) - self.minor_radius
+    }
 }
 
 impl Measured3d for Torus {
@@ -1619,6 +1671,73 @@ mod tests {
         assert_eq!(cylinder.volume(), 113.097336, "incorrect volume");
     }
 
+    #[test]
+    fn cylinder_closest_point() {
+        let cylinder = Cylinder::new(2.0, 4.0);
+        for (test, point, expected) in &[
+            (
+                "inside",
+                Vec3::new(1.0, 1.0, -1.0),
+                Vec3::new(1.0, 1.0, -1.0),
+            ),
+            ("beside", Vec3::new(0.0, 1.0, 5.0), Vec3::new(0.0, 1.0, 2.0)),
+            ("above", Vec3::new(1.0, 3.0, 0.0), Vec3::new(1.0, 2.0, 0.0)),
+            (
+                "below",
+                Vec3::new(0.0, -5.0, 0.5),
+                Vec3::new(0.0, -2.0, 0.5),
+            ),
+            (
+                "beside the rim",
+                Vec3::new(-6.0, 3.0, 8.0),
+                Vec3::new(-1.2, 2.0, 1.6),
+            ),
+        ] {
+            let closest = cylinder.closest_point(*point);
+            assert_relative_eq!(
+                closest.distance(*point),
+                cylinder.signed_distance(*point).max(0.0),
+                epsilon = 1e-6
+            );
+            assert!(
+                closest.abs_diff_eq(*expected, 1e-6),
+                "Case:\n  Test: {test:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn cylinder_signed_distance() {
+        let cylinder = Cylinder::new(2.0, 4.0);
+        // Outside, beside the side, the caps, and the rim
+        assert_relative_eq!(cylinder.signed_distance(Vec3::new(3.0, 1.0, 0.0)), 1.0);
+        assert_relative_eq!(cylinder.signed_distance(Vec3::new(0.0, -2.5, 1.0)), 0.5);
+        assert_relative_eq!(cylinder.signed_distance(Vec3::new(0.0, 6.0, 5.0)), 5.0);
+        // On the surface
+        assert_relative_eq!(cylinder.signed_distance(Vec3::new(0.0, 1.0, 2.0)), 0.0);
+        // Inside, closer to the side than to the caps
+        assert_relative_eq!(cylinder.signed_distance(Vec3::new(1.5, 0.0, 0.0)), -0.5);
+        // Inside, closer to a cap than to the side
+        assert_relative_eq!(cylinder.signed_distance(Vec3::new(0.0, 1.75, 0.0)), -0.25);
+        assert_relative_eq!(cylinder.signed_distance(Vec3::ZERO), -2.0);
+    }
+
+    #[test]
+    fn torus_signed_distance() {
+        let torus = Torus {
+            minor_radius: 0.5,
+            major_radius: 2.0,
+        };
+        // The center of the tube, and points on its surface
+        assert_relative_eq!(torus.signed_distance(Vec3::new(2.0, 0.0, 0.0)), -0.5);
+        assert_relative_eq!(torus.signed_distance(Vec3::new(0.0, 0.0, -2.5)), 0.0);
+        assert_relative_eq!(torus.signed_distance(Vec3::new(0.0, 0.5, 2.0)), 0.0);
+        // The center of the hole, and points above and beside the tube
+        assert_relative_eq!(torus.signed_distance(Vec3::ZERO), 1.5);
+        assert_relative_eq!(torus.signed_distance(Vec3::new(-2.0, 3.0, 0.0)), 2.5);
+        assert_relative_eq!(torus.signed_distance(Vec3::new(3.0, 0.0, 4.0)), 2.5);
+    }
+
     #[test]
     fn capsule_math() {
         let capsule = Capsule3d::new(2.0, 9.0);
@@ -320,7 +320,7 @@ pub fn is_polygon_simple(vertices: &[Vec2]) -> bool {
     true
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::{primitives::polygon::is_polygon_simple, Vec2};
 
@@ -53,6 +53,34 @@ impl Ray2d {
         }
         None
     }
+
+    /// Returns the ray with its direction reflected about a surface with the given `normal`.
+    ///
+    /// The origin is unchanged. To continue the ray after a hit, set the origin to the hit point.
+    #[inline]
+    pub fn reflect(&self, normal: Dir2) -> Ray2d {
+        let direction = *self.direction - 2.0 * self.direction.dot(*normal) * *normal;
+        Ray2d::new(self.origin, Dir2::new_unchecked(direction))
+    }
+
+    /// Returns the ray with its direction refracted through a surface with the given `normal`
+    /// according to Snell's law, where `eta` is the ratio of the indices of refraction
+    /// of the medium the ray is leaving to the medium it is entering.
+    ///
+    /// The `normal` should point against the direction of the ray, towards the medium it is leaving.
+    /// The origin is unchanged. To continue the ray after a hit, set the origin to the hit point.
+    ///
+    /// Returns `None` if total internal reflection occurs.
+    #[inline]
+    pub fn refract(&self, normal: Dir2, eta: f32) -> Option<Ray2d> {
+        let n_dot_i = normal.dot(*self.direction);
+        let k = 1.0 - eta * eta * (1.0 - n_dot_i * n_dot_i);
+        if k < 0.0 {
+            return None;
+        }
+        let direction = eta * *self.direction - (eta * n_dot_i + ops::sqrt(k)) * *normal;
+        Some(Ray2d::new(self.origin, Dir2::new_unchecked(direction)))
+    }
 }
 
 /// An infinite half-line starting at `origin` and going in `direction` in 3D space.
@@ -105,6 +133,53 @@ impl Ray3d {
 mod tests {
     use super::*;
 
+    #[test]
+    fn reflect_2d() {
+        let ray = Ray2d::new(Vec2::ONE, Dir2::new(Vec2::new(1.0, -1.0)).unwrap());
+
+        // Bouncing off a floor flips the vertical component and keeps the origin
+        let reflected = ray.reflect(Dir2::Y);
+        assert_eq!(reflected.origin, Vec2::ONE);
+        assert!(reflected
+            .direction
+            .abs_diff_eq(Vec2::new(1.0, 1.0).normalize(), 1e-6));
+
+        // The side of the surface the normal points to doesn't matter
+        assert_eq!(ray.reflect(Dir2::NEG_Y), reflected);
+
+        // Hitting a wall head-on reverses the direction
+        let ray = Ray2d::new(Vec2::ZERO, Dir2::X);
+        assert!(ray
+            .reflect(Dir2::NEG_X)
+            .direction
+            .abs_diff_eq(Vec2::NEG_X, 1e-6));
+    }
+
+    #[test]
+    fn refract_2d() {
+        // Hitting a surface head-on doesn't bend the ray
+        let ray = Ray2d::new(Vec2::ZERO, Dir2::NEG_Y);
+        let refracted = ray.refract(Dir2::Y, 1.0 / 1.5).unwrap();
+        assert_eq!(refracted.origin, Vec2::ZERO);
+        assert!(refracted.direction.abs_diff_eq(Vec2::NEG_Y, 1e-6));
+
+        // An index ratio of 1 doesn't bend the ray either
+        let ray = Ray2d::new(Vec2::ZERO, Dir2::new(Vec2::new(1.0, -1.0)).unwrap());
+        let refracted = ray.refract(Dir2::Y, 1.0).unwrap();
+        assert!(refracted.direction.abs_diff_eq(*ray.direction, 1e-6));
+
+        // Snell's law: eta * sin(incident angle) = sin(refracted angle)
+        let eta = 1.0 / 1.33;
+        let refracted = ray.refract(Dir2::Y, eta).unwrap();
+        assert!(refracted.direction.is_normalized());
+        assert!(refracted.direction.y < 0.0);
+        assert!(ops::abs(refracted.direction.x - eta * ray.direction.x) < 1e-6);
+
+        // Leaving a denser medium at a shallow angle causes total internal reflection
+        let ray = Ray2d::new(Vec2::ZERO, Dir2::new(Vec2::new(1.0, -0.2)).unwrap());
+        assert_eq!(ray.refract(Dir2::Y, 1.5), None);
+    }
+
     #[test]
     fn intersect_plane_2d() {
         let ray = Ray2d::new(Vec2::ZERO, Dir2::Y);
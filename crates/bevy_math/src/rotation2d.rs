@@ -1,10 +1,11 @@
-use core::f32::consts::TAU;
+use core::f32::consts::{PI, TAU};
 
 use glam::FloatExt;
 
 use crate::{
     ops,
-    prelude::{Mat2, Vec2},
+    prelude::{Mat2, Quat, Vec2, Vec3},
+    Dir2,
 };
 
 #[cfg(feature = "bevy_reflect")]
@@ -200,10 +201,60 @@ impl Rot2 {
         rotation
     }
 
+    /// Creates a [`Rot2`] that rotates the X axis to the given direction.
+    ///
+    /// The components of the direction are used as the cosine and sine of the rotation directly,
+    /// without any trigonometric functions or loss of precision.
+    /// This is the inverse of [`Dir2::from_rot2`].
+    #[inline]
+    pub fn from_dir(dir: Dir2) -> Self {
+        Self {
+            sin: dir.y,
+            cos: dir.x,
+        }
+    }
+
+    /// Creates a [`Rot2`] from the rotation of the given quaternion around the Z axis,
+    /// like the rotation of a 2D object that uses a 3D `Transform`.
+    ///
+    /// The angle is that of the X axis rotated by the quaternion, projected onto the XY plane,
+    /// so any tilt around the X and Y axes is ignored. Unlike extracting an Euler angle, this keeps
+    /// working for tilted rotations. If the X axis is rotated onto the Z axis, or very close to it,
+    /// the rotated Y axis is used instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// # use bevy_math::{Quat, Rot2};
+    /// let tilted = Quat::from_rotation_z(1.0) * Quat::from_rotation_x(0.5);
+    /// assert_relative_eq!(Rot2::from_quat_z(tilted), Rot2::radians(1.0), epsilon = 1e-6);
+    /// ```
+    #[inline]
+    pub fn from_quat_z(quat: Quat) -> Self {
+        let x_axis = (quat * Vec3::X).truncate();
+        // Near the Z axis, the projection of the X axis is too short to have a precise direction
+        let projected = if x_axis.length_squared() > f32::EPSILON {
+            x_axis
+        } else {
+            // The rotated Y axis is then nearly perpendicular to the Z axis, and a quarter turn ahead of the X axis
+            let y_axis = quat * Vec3::Y;
+            Vec2::new(y_axis.y, -y_axis.x)
+        };
+        Dir2::new(projected).map_or(Self::IDENTITY, Self::from_dir)
+    }
+
     /// Returns the rotation in radians in the `(-pi, pi]` range.
     #[inline]
     pub fn as_radians(self) -> f32 {
-        ops::atan2(self.sin, self.cos)
+        let angle = ops::atan2(self.sin, self.cos);
+        // `atan2` returns -pi for a half turn with a negative zero sine,
+        // so map it to pi to stay within the documented range.
+        if angle == -PI {
+            PI
+        } else {
+            angle
+        }
     }
 
     /// Returns the rotation in degrees in the `(-180, 180]` range.
@@ -338,7 +389,11 @@ impl Rot2 {
     }
 
     /// Returns the angle in radians needed to make `self` and `other` coincide.
+    ///
+    /// This is the signed shortest angle from `self` to `other` in the `(-pi, pi]` range,
+    /// where a positive angle is counterclockwise.
     #[inline]
+    #[doc(alias = "angle_between")]
     pub fn angle_to(self, other: Self) -> f32 {
         (other * self.inverse()).as_radians()
     }
@@ -355,6 +410,30 @@ impl Rot2 {
         }
     }
 
+    /// Rotates the given vector by the inverse of the rotation.
+    ///
+    /// This is the same as `self.inverse() * vec`, computed directly as the product of the vector
+    /// and the conjugate of the complex number representing the rotation.
+    /// Like rotating by `self * vec`, it takes four multiplications and no branches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// # use bevy_math::{Rot2, Vec2};
+    /// let rotation = Rot2::degrees(90.0);
+    /// assert_relative_eq!(rotation.inverse_mul_vec(Vec2::Y), Vec2::X);
+    /// assert_relative_eq!(rotation.inverse_mul_vec(rotation * Vec2::new(3.0, -2.0)), Vec2::new(3.0, -2.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn inverse_mul_vec(self, vec: Vec2) -> Vec2 {
+        Vec2::new(
+            vec.x * self.cos + vec.y * self.sin,
+            vec.y * self.cos - vec.x * self.sin,
+        )
+    }
+
     /// Performs a linear interpolation between `self` and `rhs` based on
     /// the value `s`, and normalizes the rotation afterwards.
     ///
@@ -445,8 +524,11 @@ impl From<f32> for Rot2 {
 
 impl From<Rot2> for Mat2 {
     /// Creates a [`Mat2`] rotation matrix from a [`Rot2`].
+    ///
+    /// The matrix rotates vectors in the same direction as the [`Rot2`] itself,
+    /// so `Mat2::from(rot) * vec` is equal to `rot * vec`.
     fn from(rot: Rot2) -> Self {
-        Mat2::from_cols_array(&[rot.cos, -rot.sin, rot.sin, rot.cos])
+        Mat2::from_cols_array(&[rot.cos, rot.sin, -rot.sin, rot.cos])
     }
 }
 
@@ -514,11 +596,11 @@ impl approx::UlpsEq for Rot2 {
 
 #[cfg(test)]
 mod tests {
-    use core::f32::consts::FRAC_PI_2;
+    use core::f32::consts::{FRAC_PI_2, PI};
 
     use approx::assert_relative_eq;
 
-    use crate::{ops, Dir2, Rot2, Vec2};
+    use crate::{ops, Dir2, Mat2, Quat, Rot2, Vec2};
 
     #[test]
     fn creation() {
@@ -547,6 +629,65 @@ mod tests {
 
         assert_relative_eq!(rotation * Vec2::X, Vec2::Y);
         assert_relative_eq!(rotation * Dir2::Y, Dir2::NEG_X);
+
+        // Rotating by the inverse undoes the rotation
+        let vec = Vec2::new(3.0, -2.0);
+        assert_relative_eq!(rotation.inverse_mul_vec(Vec2::Y), Vec2::X);
+        assert_relative_eq!(rotation.inverse_mul_vec(rotation * vec), vec);
+        let arbitrary = Rot2::radians(0.7);
+        assert_relative_eq!(arbitrary.inverse_mul_vec(vec), arbitrary.inverse() * vec);
+
+        // The rotation matrix rotates in the same direction
+        assert_relative_eq!(Mat2::from(rotation) * Vec2::X, Vec2::Y);
+        assert_relative_eq!(Mat2::from(rotation) * Vec2::Y, Vec2::NEG_X);
+
+        let arbitrary = Rot2::radians(-2.1);
+        let vector = Vec2::new(3.0, -0.5);
+        assert_relative_eq!(Mat2::from(arbitrary) * vector, arbitrary * vector);
+    }
+
+    #[test]
+    fn mat2_from_rot2() {
+        for angle in [0.0, 0.3, FRAC_PI_2, 2.5, -1.2, PI] {
+            let rotation = Rot2::radians(angle);
+            let (sin, cos) = ops::sin_cos(angle);
+            let matrix = Mat2::from(rotation);
+
+            // After: the columns are the images of the X and Y axes, rotated counterclockwise
+            assert_relative_eq!(matrix, Mat2::from_angle(angle));
+            assert_relative_eq!(matrix.x_axis, Vec2::new(cos, sin));
+            assert_relative_eq!(matrix.y_axis, Vec2::new(-sin, cos));
+            for vector in [Vec2::X, Vec2::Y, Vec2::new(3.0, -0.5)] {
+                assert_relative_eq!(matrix * vector, rotation * vector);
+            }
+
+            // Before: the matrix was the transpose, which rotates by the inverse
+            let before = Mat2::from_cols_array(&[cos, -sin, sin, cos]);
+            assert_relative_eq!(before, Mat2::from(rotation.inverse()));
+            assert_relative_eq!(before * Vec2::X, rotation.inverse() * Vec2::X);
+        }
+    }
+
+    #[test]
+    fn from_quat_z() {
+        for angle in [0.0, 0.3, -2.0, PI] {
+            let expected = Rot2::radians(angle);
+            assert_relative_eq!(Rot2::from_quat_z(Quat::from_rotation_z(angle)), expected);
+
+            // Tilting around the X and Y axes doesn't change the rotation around the Z axis
+            let tilted = Quat::from_rotation_z(angle) * Quat::from_rotation_y(0.4);
+            assert_relative_eq!(Rot2::from_quat_z(tilted), expected, epsilon = 1e-6);
+            let tilted = Quat::from_rotation_z(angle) * Quat::from_rotation_x(-1.2);
+            assert_relative_eq!(Rot2::from_quat_z(tilted), expected, epsilon = 1e-6);
+        }
+
+        // The X axis rotated onto the Z axis falls back to the Y axis
+        let upright = Quat::from_rotation_z(FRAC_PI_2) * Quat::from_rotation_y(-FRAC_PI_2);
+        assert_relative_eq!(
+            Rot2::from_quat_z(upright),
+            Rot2::radians(FRAC_PI_2),
+            epsilon = 1e-6
+        );
     }
 
     #[test]
@@ -683,6 +824,32 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn angle_to() {
+        let rot1 = Rot2::degrees(30.0);
+        let rot2 = Rot2::degrees(75.0);
+        assert_relative_eq!(rot1.angle_to(rot2), 45_f32.to_radians(), epsilon = 1e-6);
+        assert_relative_eq!(rot2.angle_to(rot1), -45_f32.to_radians(), epsilon = 1e-6);
+
+        // The shortest angle wraps around the `(-pi, pi]` boundary
+        let rot1 = Rot2::degrees(170.0);
+        let rot2 = Rot2::degrees(-170.0);
+        assert_relative_eq!(rot1.angle_to(rot2), 20_f32.to_radians(), epsilon = 1e-5);
+        assert_relative_eq!(rot2.angle_to(rot1), -20_f32.to_radians(), epsilon = 1e-5);
+
+        // Opposite rotations are a positive half turn apart in either order
+        assert_eq!(Rot2::IDENTITY.angle_to(Rot2::PI), PI);
+        assert_eq!(Rot2::PI.angle_to(Rot2::IDENTITY), PI);
+        assert_eq!(Rot2::FRAC_PI_2.angle_to(Rot2::from_sin_cos(-1.0, 0.0)), PI);
+        assert_eq!(Rot2::from_sin_cos(-1.0, 0.0).angle_to(Rot2::FRAC_PI_2), PI);
+        assert_eq!(Rot2::from_sin_cos(-0.0, -1.0).as_radians(), PI);
+
+        // Nearly opposite rotations keep their sign
+        let rot = Rot2::radians(PI - 1e-3);
+        assert!(Rot2::IDENTITY.angle_to(rot) > 0.0);
+        assert!(rot.angle_to(Rot2::IDENTITY) < 0.0);
+    }
+
     #[test]
     fn nlerp() {
         let rot1 = Rot2::IDENTITY;
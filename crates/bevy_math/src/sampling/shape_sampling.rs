@@ -40,7 +40,12 @@
 
 use core::f32::consts::{PI, TAU};
 
-use crate::{ops, primitives::*, NormedVectorSpace, Vec2, Vec3};
+use crate::{
+    bounding::{Aabb2d, BoundingCircle, BoundingVolume},
+    ops,
+    primitives::*,
+    NormedVectorSpace, Vec2, Vec3,
+};
 use rand::{
     distributions::{Distribution, WeightedIndex},
     Rng,
@@ -572,9 +577,39 @@ impl<P: Primitive2d + Measured2d + ShapeSample<Output = Vec2>> ShapeSample for E
     }
 }
 
+/// Samples points of the AABB at its position, rather than centered on 0.
+impl ShapeSample for Aabb2d {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let x = rng.gen_range(self.min.x..=self.max.x);
+        let y = rng.gen_range(self.min.y..=self.max.y);
+        Vec2::new(x, y)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        Rectangle::from_corners(self.min, self.max).sample_boundary(rng) + self.center()
+    }
+}
+
+/// Samples points of the bounding circle at its position, rather than centered on 0.
+impl ShapeSample for BoundingCircle {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.circle.sample_interior(rng) + self.center
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.circle.sample_boundary(rng) + self.center
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ops::FloatPow;
+    use alloc::vec::Vec;
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
@@ -637,4 +672,171 @@ mod tests {
             "samples will occur across all array items at statistically equal chance"
         );
     }
+
+    #[test]
+    fn aabb_sampling() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let aabb = Aabb2d::new(Vec2::new(10.0, -5.0), Vec2::new(2.0, 1.0));
+
+        for _ in 0..1000 {
+            let point = aabb.sample_interior(&mut rng);
+            assert_eq!(aabb.closest_point(point), point);
+
+            // Boundary points are inside the AABB and on one of its edges
+            let point = aabb.sample_boundary(&mut rng);
+            let edge_distance = (point - aabb.min).min(aabb.max - point);
+            assert!(edge_distance.cmpge(Vec2::splat(-1e-5)).all());
+            assert!(edge_distance.min_element() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn bounding_circle_interior_sampling() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let circle = BoundingCircle::new(Vec2::new(10.0, -5.0), 2.0);
+
+        // The inner disk has a quarter of the area of the circle,
+        // so it should get about a quarter of the samples if they're uniform
+        let mut inner_hits = 0;
+        for _ in 0..4000 {
+            let point = circle.sample_interior(&mut rng);
+            let distance = point.distance(circle.center);
+            assert!(distance <= circle.radius() + 1e-5);
+            if distance < circle.radius() / 2.0 {
+                inner_hits += 1;
+            }
+        }
+        assert!((900..1100).contains(&inner_hits), "{inner_hits}");
+
+        for _ in 0..100 {
+            let point = circle.sample_boundary(&mut rng);
+            assert!(ops::abs(point.distance(circle.center) - circle.radius()) < 1e-5);
+        }
+    }
+
+    /// Asserts that the `samples` are distributed over `[min, max]` according to the given `density`,
+    /// using a chi-square test over equally sized bins.
+    fn assert_distributed(samples: &[f32], min: f32, max: f32, density: impl Fn(f32) -> f32) {
+        const BINS: usize = 16;
+        // The critical value of the chi-square distribution with 15 degrees of freedom at p = 0.001
+        const CRITICAL_VALUE: f32 = 37.7;
+        const STEPS: usize = 100;
+
+        let bin_size = (max - min) / BINS as f32;
+        let mut counts = [0; BINS];
+        for &sample in samples {
+            let bin = ops::floor((sample - min) / bin_size) as usize;
+            counts[bin.min(BINS - 1)] += 1;
+        }
+
+        // Integrate the density over each bin using the midpoint rule
+        let weights: [f32; BINS] = core::array::from_fn(|bin| {
+            (0..STEPS)
+                .map(|step| {
+                    let x = min + bin_size * (bin as f32 + (step as f32 + 0.5) / STEPS as f32);
+                    density(x)
+                })
+                .sum()
+        });
+        let total: f32 = weights.iter().sum();
+
+        let chi_square: f32 = counts
+            .iter()
+            .zip(weights)
+            .map(|(&count, weight)| {
+                let expected = samples.len() as f32 * weight / total;
+                (count as f32 - expected).squared() / expected
+            })
+            .sum();
+        assert!(
+            chi_square < CRITICAL_VALUE,
+            "chi-square of {chi_square} for counts {counts:?}"
+        );
+    }
+
+    #[test]
+    fn capsule2d_interior_sampling() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let capsule = Capsule2d::new(1.0, 2.0);
+        let samples: Vec<_> = (0..10000)
+            .map(|_| capsule.sample_interior(&mut rng))
+            .collect();
+
+        // The width of the capsule at a given height
+        let extent = capsule.half_length + capsule.radius;
+        let ys: Vec<_> = samples.iter().map(|point| point.y).collect();
+        assert_distributed(&ys, -extent, extent, |y| {
+            let cap = (ops::abs(y) - capsule.half_length).max(0.0);
+            2.0 * ops::sqrt((capsule.radius.squared() - cap.squared()).max(0.0))
+        });
+
+        // The height of the capsule at a given horizontal position
+        let xs: Vec<_> = samples.iter().map(|point| point.x).collect();
+        assert_distributed(&xs, -capsule.radius, capsule.radius, |x| {
+            2.0 * (capsule.half_length
+                + ops::sqrt((capsule.radius.squared() - x.squared()).max(0.0)))
+        });
+    }
+
+    #[test]
+    fn capsule2d_boundary_sampling() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let capsule = Capsule2d::new(1.0, 2.0);
+        let (radius, half_length) = (capsule.radius, capsule.half_length);
+        let straight = 2.0 * half_length;
+        let arc = PI * radius;
+
+        // The distance along the perimeter, counterclockwise from `(radius, -half_length)`
+        let distances: Vec<_> = (0..10000)
+            .map(|_| {
+                let point = ShapeSample::sample_boundary(&capsule, &mut rng);
+                assert!(
+                    ops::abs(capsule.to_segment().closest_point(point).distance(point) - radius)
+                        < 1e-4
+                );
+                if point.y > half_length {
+                    straight + radius * ops::atan2(point.y - half_length, point.x)
+                } else if point.y < -half_length {
+                    2.0 * straight + arc + radius * ops::atan2(-point.y - half_length, -point.x)
+                } else if point.x > 0.0 {
+                    point.y + half_length
+                } else {
+                    straight + arc + half_length - point.y
+                }
+            })
+            .collect();
+
+        // The perimeter is sampled uniformly
+        assert_distributed(&distances, 0.0, 2.0 * (straight + arc), |_| 1.0);
+    }
+
+    #[test]
+    fn capsule3d_interior_sampling() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let capsule = Capsule3d::new(1.0, 2.0);
+        let ys: Vec<_> = (0..10000)
+            .map(|_| capsule.sample_interior(&mut rng).y)
+            .collect();
+
+        // The area of the cross-section of the capsule at a given height
+        let extent = capsule.half_length + capsule.radius;
+        assert_distributed(&ys, -extent, extent, |y| {
+            let cap = (ops::abs(y) - capsule.half_length).max(0.0);
+            PI * (capsule.radius.squared() - cap.squared()).max(0.0)
+        });
+    }
+
+    #[test]
+    fn capsule3d_boundary_sampling() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let capsule = Capsule3d::new(1.0, 2.0);
+        let ys: Vec<_> = (0..10000)
+            .map(|_| capsule.sample_boundary(&mut rng).y)
+            .collect();
+
+        // By Archimedes' hat-box theorem, the surface area of the hemispheres is distributed
+        // uniformly along the Y axis like that of the cylinder, so the heights are uniform
+        let extent = capsule.half_length + capsule.radius;
+        assert_distributed(&ys, -extent, extent, |_| 1.0);
+    }
 }
@@ -102,8 +102,7 @@ fn render_shapes(mut gizmos: Gizmos, query: Query<(&Shape, &Transform)>) {
     let color = GRAY;
     for (shape, transform) in query.iter() {
         let translation = transform.translation.xy();
-        let rotation = transform.rotation.to_euler(EulerRot::YXZ).2;
-        let isometry = Isometry2d::new(translation, Rot2::radians(rotation));
+        let isometry = Isometry2d::new(translation, Rot2::from_quat_z(transform.rotation));
         match shape {
             Shape::Rectangle(r) => {
                 gizmos.primitive_2d(r, isometry, color);
@@ -148,8 +147,7 @@ fn update_volumes(
 ) {
     for (entity, desired_volume, shape, transform) in query.iter() {
         let translation = transform.translation.xy();
-        let rotation = transform.rotation.to_euler(EulerRot::YXZ).2;
-        let isometry = Isometry2d::new(translation, Rot2::radians(rotation));
+        let isometry = Isometry2d::new(translation, Rot2::from_quat_z(transform.rotation));
         match desired_volume {
             DesiredVolume::Aabb => {
                 let aabb = match shape {
@@ -184,10 +182,10 @@ fn render_volumes(mut gizmos: Gizmos, query: Query<(&CurrentVolume, &Intersects)
         let color = if **intersects { AQUA } else { ORANGE_RED };
         match volume {
             CurrentVolume::Aabb(a) => {
-                gizmos.rect_2d(a.center(), a.half_size() * 2., color);
+                gizmos.aabb_2d(a, color);
             }
             CurrentVolume::Circle(c) => {
-                gizmos.circle_2d(c.center(), c.radius(), color);
+                gizmos.bounding_circle_2d(c, color);
             }
         }
     }
@@ -333,7 +331,7 @@ fn aabb_cast_system(
     for (volume, mut intersects) in volumes.iter_mut() {
         let toi = match *volume {
             CurrentVolume::Aabb(a) => aabb_cast.aabb_collision_at(a),
-            CurrentVolume::Circle(_) => None,
+            CurrentVolume::Circle(c) => aabb_cast.circle_collision_at(&c),
         };
 
         **intersects = toi.is_some();
@@ -360,7 +358,7 @@ fn bounding_circle_cast_system(
 
     for (volume, mut intersects) in volumes.iter_mut() {
         let toi = match *volume {
-            CurrentVolume::Aabb(_) => None,
+            CurrentVolume::Aabb(a) => circle_cast.aabb_collision_at(&a),
             CurrentVolume::Circle(c) => circle_cast.circle_collision_at(c),
         };
 
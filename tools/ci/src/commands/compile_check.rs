@@ -9,9 +9,19 @@ pub struct CompileCheckCommand {}
 
 impl Prepare for CompileCheckCommand {
     fn prepare<'a>(&self, sh: &'a xshell::Shell, _flags: Flag) -> Vec<PreparedCommand<'a>> {
-        vec![PreparedCommand::new::<Self>(
-            cmd!(sh, "cargo check --workspace"),
-            "Please fix compiler errors in output above.",
-        )]
+        vec![
+            PreparedCommand::new::<Self>(
+                cmd!(sh, "cargo check --workspace"),
+                "Please fix compiler errors in output above.",
+            ),
+            // Feature combination not covered by `--workspace`.
+            PreparedCommand::new::<Self>(
+                cmd!(
+                    sh,
+                    "cargo check -p bevy_math --features deterministic,bevy_reflect"
+                ),
+                "Please fix compiler errors in output above for bevy_math with the `deterministic` and `bevy_reflect` features.",
+            ),
+        ]
     }
 }